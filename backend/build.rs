@@ -0,0 +1,5 @@
+// backend/build.rs
+// Recompila quando uma migration muda, já que `sqlx::migrate!` as embute no binário
+fn main() {
+    println!("cargo:rerun-if-changed=migrations");
+}
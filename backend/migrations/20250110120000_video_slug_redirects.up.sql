@@ -0,0 +1,8 @@
+-- Slugs antigos de vídeos (mantidos para redirecionar após mudança de título)
+CREATE TABLE video_slug_redirects (
+    old_slug VARCHAR(100) PRIMARY KEY,
+    video_id UUID NOT NULL REFERENCES videos(id) ON DELETE CASCADE,
+    created_at TIMESTAMP WITH TIME ZONE DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE INDEX idx_video_slug_redirects_video_id ON video_slug_redirects (video_id);
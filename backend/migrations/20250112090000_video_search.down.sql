@@ -0,0 +1,3 @@
+DROP INDEX IF EXISTS idx_video_tags_tag_id;
+DROP INDEX IF EXISTS idx_videos_search_vector;
+ALTER TABLE videos DROP COLUMN IF EXISTS search_vector;
@@ -0,0 +1,9 @@
+-- Busca textual em vídeos (título com peso maior que a descrição)
+ALTER TABLE videos
+    ADD COLUMN search_vector TSVECTOR GENERATED ALWAYS AS (
+        setweight(to_tsvector('portuguese', coalesce(title, '')), 'A') ||
+        setweight(to_tsvector('portuguese', coalesce(description, '')), 'B')
+    ) STORED;
+
+CREATE INDEX idx_videos_search_vector ON videos USING GIN (search_vector);
+CREATE INDEX idx_video_tags_tag_id ON video_tags (tag_id);
@@ -0,0 +1,20 @@
+DROP TRIGGER IF EXISTS logs_no_truncate ON logs;
+DROP TRIGGER IF EXISTS logs_no_update_or_delete ON logs;
+DROP FUNCTION IF EXISTS logs_append_only();
+
+DROP INDEX IF EXISTS idx_logs_timestamp;
+DROP INDEX IF EXISTS idx_logs_user_id;
+DROP INDEX IF EXISTS idx_logs_entity;
+
+-- Autores removidos enquanto a restrição não existia
+UPDATE logs SET user_id = NULL WHERE user_id IS NOT NULL AND user_id NOT IN (SELECT id FROM users);
+ALTER TABLE logs
+    ADD CONSTRAINT logs_user_id_fkey FOREIGN KEY (user_id) REFERENCES users(id) ON DELETE SET NULL;
+
+ALTER TABLE logs
+    DROP COLUMN IF EXISTS request_id,
+    DROP COLUMN IF EXISTS ip_address,
+    DROP COLUMN IF EXISTS after,
+    DROP COLUMN IF EXISTS before,
+    DROP COLUMN IF EXISTS entity_id,
+    DROP COLUMN IF EXISTS entity_type;
@@ -0,0 +1,32 @@
+-- Auditoria: `logs` passa a guardar quem alterou o quê, com o estado antes e
+-- depois, e vira somente-inserção
+ALTER TABLE logs
+    ADD COLUMN entity_type TEXT,
+    ADD COLUMN entity_id UUID,
+    ADD COLUMN before JSONB,
+    ADD COLUMN after JSONB,
+    ADD COLUMN ip_address TEXT,
+    ADD COLUMN request_id TEXT;
+
+-- O autor continua registrado mesmo depois que o usuário é removido
+-- (`ON DELETE SET NULL` seria um UPDATE, proibido abaixo)
+ALTER TABLE logs DROP CONSTRAINT IF EXISTS logs_user_id_fkey;
+
+CREATE INDEX idx_logs_entity ON logs (entity_type, entity_id);
+CREATE INDEX idx_logs_user_id ON logs (user_id);
+CREATE INDEX idx_logs_timestamp ON logs (timestamp);
+
+CREATE FUNCTION logs_append_only() RETURNS trigger AS $$
+BEGIN
+    RAISE EXCEPTION 'logs is append-only (% not allowed)', TG_OP
+        USING ERRCODE = 'insufficient_privilege';
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER logs_no_update_or_delete
+    BEFORE UPDATE OR DELETE ON logs
+    FOR EACH ROW EXECUTE FUNCTION logs_append_only();
+
+CREATE TRIGGER logs_no_truncate
+    BEFORE TRUNCATE ON logs
+    FOR EACH STATEMENT EXECUTE FUNCTION logs_append_only();
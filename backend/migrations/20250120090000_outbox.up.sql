@@ -0,0 +1,16 @@
+-- Outbox: mensagens gravadas na mesma transação da alteração que as gera e
+-- publicadas no RabbitMQ pelo relay do backend (entrega pelo menos uma vez)
+CREATE TABLE outbox (
+    id BIGSERIAL PRIMARY KEY,
+    routing_key TEXT NOT NULL,
+    payload JSONB NOT NULL,
+    request_id TEXT,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    sent_at TIMESTAMPTZ,
+    attempts INTEGER NOT NULL DEFAULT 0,
+    last_error TEXT
+);
+
+-- O relay só lê as pendentes, em ordem de criação
+CREATE INDEX idx_outbox_pending ON outbox (id) WHERE sent_at IS NULL;
+CREATE INDEX idx_outbox_sent_at ON outbox (sent_at) WHERE sent_at IS NOT NULL;
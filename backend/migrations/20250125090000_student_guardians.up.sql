@@ -0,0 +1,17 @@
+-- Responsáveis de cada aluno: um aluno pode ter vários responsáveis e um
+-- responsável pode responder por vários alunos (irmãos)
+CREATE TABLE student_guardians (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    student_id UUID NOT NULL REFERENCES students(id) ON DELETE CASCADE,
+    guardian_id UUID NOT NULL REFERENCES guardians(id) ON DELETE CASCADE,
+    -- Parentesco com este aluno (o mesmo responsável pode ser avô de um e tio de outro)
+    relationship VARCHAR(50) NOT NULL,
+    -- Autorizado a buscar o aluno no fim do treino
+    can_pick_up BOOLEAN NOT NULL DEFAULT FALSE,
+    -- Ordem de contato em emergências (1 = primeiro); NULL se não é contato de emergência
+    emergency_priority INTEGER CHECK (emergency_priority > 0),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE (student_id, guardian_id)
+);
+
+CREATE INDEX idx_student_guardians_guardian_id ON student_guardians (guardian_id);
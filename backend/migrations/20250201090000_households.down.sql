@@ -0,0 +1,6 @@
+ALTER TABLE phones DROP COLUMN IF EXISTS household_id;
+ALTER TABLE addresses DROP COLUMN IF EXISTS household_id;
+ALTER TABLE students DROP COLUMN IF EXISTS household_id;
+ALTER TABLE guardians DROP COLUMN IF EXISTS household_id;
+ALTER TABLE parents DROP COLUMN IF EXISTS household_id;
+DROP TABLE IF EXISTS households;
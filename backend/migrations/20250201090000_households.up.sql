@@ -0,0 +1,31 @@
+-- Famílias: agrupam pais, responsáveis e alunos (irmãos) e guardam os
+-- endereços e telefones compartilhados, em vez de repeti-los em cada pessoa
+CREATE TABLE households (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    name VARCHAR(100) NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+ALTER TABLE parents ADD COLUMN household_id UUID REFERENCES households(id) ON DELETE SET NULL;
+ALTER TABLE guardians ADD COLUMN household_id UUID REFERENCES households(id) ON DELETE SET NULL;
+ALTER TABLE students ADD COLUMN household_id UUID REFERENCES households(id) ON DELETE SET NULL;
+
+-- Contatos da família toda; somem junto com ela
+ALTER TABLE addresses ADD COLUMN household_id UUID REFERENCES households(id) ON DELETE CASCADE;
+ALTER TABLE phones ADD COLUMN household_id UUID REFERENCES households(id) ON DELETE CASCADE;
+
+CREATE INDEX idx_parents_household_id ON parents (household_id);
+CREATE INDEX idx_guardians_household_id ON guardians (household_id);
+CREATE INDEX idx_students_household_id ON students (household_id);
+CREATE INDEX idx_addresses_household_id ON addresses (household_id);
+CREATE INDEX idx_phones_household_id ON phones (household_id);
+
+-- Uma família para cada pai ou mãe que já tem filhos cadastrados, com o mesmo
+-- id do pai, reunindo os irmãos ligados por `parent_id`
+INSERT INTO households (id, name)
+SELECT p.id, p.name
+FROM parents p
+WHERE EXISTS (SELECT 1 FROM students s WHERE s.parent_id = p.id);
+
+UPDATE parents SET household_id = id WHERE id IN (SELECT id FROM households);
+UPDATE students SET household_id = parent_id WHERE parent_id IN (SELECT id FROM households);
@@ -0,0 +1,15 @@
+ALTER TABLE users DROP CONSTRAINT users_role_check;
+ALTER TABLE users ADD CONSTRAINT users_role_check
+    CHECK (role IN ('admin', 'parent', 'student', 'guardian'));
+
+DROP INDEX IF EXISTS idx_students_group_id;
+
+ALTER TABLE groups
+    DROP COLUMN IF EXISTS capacity,
+    DROP COLUMN IF EXISTS coach_id,
+    DROP COLUMN IF EXISTS max_age,
+    DROP COLUMN IF EXISTS min_age,
+    DROP COLUMN IF EXISTS season;
+
+ALTER TABLE groups ALTER COLUMN name TYPE VARCHAR(50);
+ALTER TABLE groups ADD CONSTRAINT groups_name_check CHECK (name IN ('pequenos', 'medios', 'grandes'));
@@ -0,0 +1,24 @@
+-- Turmas configuráveis: o nome deixa de ser uma das três categorias fixas e a
+-- turma ganha temporada, faixa etária, técnico e número de vagas
+ALTER TABLE groups DROP CONSTRAINT groups_name_check;
+ALTER TABLE groups ALTER COLUMN name TYPE VARCHAR(100);
+
+ALTER TABLE groups
+    -- Ano da temporada; NULL para turmas sem temporada
+    ADD COLUMN season INTEGER,
+    -- Idade que o aluno completa no ano da temporada (categorias sub-N)
+    ADD COLUMN min_age INTEGER CHECK (min_age >= 0),
+    ADD COLUMN max_age INTEGER CHECK (max_age >= 0),
+    ADD COLUMN coach_id UUID REFERENCES users(id) ON DELETE SET NULL,
+    -- Máximo de alunos; NULL para sem limite
+    ADD COLUMN capacity INTEGER CHECK (capacity > 0),
+    ADD CONSTRAINT groups_age_range_check CHECK (min_age <= max_age);
+
+CREATE INDEX idx_groups_season ON groups (season);
+CREATE INDEX idx_groups_coach_id ON groups (coach_id);
+CREATE INDEX idx_students_group_id ON students (group_id);
+
+-- Técnicos entram como usuários com o papel `coach`
+ALTER TABLE users DROP CONSTRAINT users_role_check;
+ALTER TABLE users ADD CONSTRAINT users_role_check
+    CHECK (role IN ('admin', 'parent', 'student', 'guardian', 'coach'));
@@ -0,0 +1,6 @@
+DROP INDEX IF EXISTS idx_schedule_changes_group_id;
+DROP INDEX IF EXISTS idx_schedule_changes_occurrence;
+
+ALTER TABLE schedule_changes DROP COLUMN IF EXISTS schedule_id;
+
+DROP TABLE IF EXISTS group_schedules;
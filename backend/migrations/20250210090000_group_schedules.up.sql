@@ -0,0 +1,27 @@
+-- Horário fixo de treino de cada turma: um registro por dia da semana, válido
+-- entre `valid_from` e `valid_until` (sem fim quando NULL)
+CREATE TABLE group_schedules (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    group_id UUID NOT NULL REFERENCES groups(id) ON DELETE CASCADE,
+    -- Dia da semana ISO: 1 = segunda ... 7 = domingo
+    weekday INTEGER NOT NULL CHECK (weekday BETWEEN 1 AND 7),
+    start_time TIME NOT NULL,
+    end_time TIME NOT NULL,
+    location VARCHAR(200),
+    valid_from DATE NOT NULL,
+    valid_until DATE,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CONSTRAINT group_schedules_time_check CHECK (start_time < end_time),
+    CONSTRAINT group_schedules_valid_range_check CHECK (valid_from <= valid_until)
+);
+
+CREATE INDEX idx_group_schedules_group_id ON group_schedules (group_id);
+
+-- Uma mudança de treino passa a ser exceção de uma sessão do horário:
+-- `old_date` é o início previsto da sessão e `new_date` NULL a cancela.
+-- Mudanças antigas, sem horário, continuam valendo pela turma e `old_date`.
+ALTER TABLE schedule_changes
+    ADD COLUMN schedule_id UUID REFERENCES group_schedules(id) ON DELETE SET NULL;
+
+CREATE UNIQUE INDEX idx_schedule_changes_occurrence ON schedule_changes (schedule_id, old_date);
+CREATE INDEX idx_schedule_changes_group_id ON schedule_changes (group_id);
@@ -0,0 +1,10 @@
+-- Feeds .ics dos treinos: o token secreto na URL é a única credencial, então
+-- cada turma ou família tem no máximo um feed e gerar outro invalida o antigo
+CREATE TABLE calendar_feeds (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    token VARCHAR(64) NOT NULL UNIQUE,
+    group_id UUID UNIQUE REFERENCES groups(id) ON DELETE CASCADE,
+    household_id UUID UNIQUE REFERENCES households(id) ON DELETE CASCADE,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CONSTRAINT calendar_feeds_owner_check CHECK ((group_id IS NULL) <> (household_id IS NULL))
+);
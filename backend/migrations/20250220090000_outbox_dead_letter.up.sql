@@ -0,0 +1,11 @@
+-- Relay: as mensagens são reservadas (`claimed_until`) e a reserva é gravada
+-- antes da publicação, sem segurar locks durante a chamada ao broker. Depois
+-- de `outbox.max_attempts` falhas a mensagem é estacionada (`parked_at`) e
+-- deixa de bloquear as seguintes.
+ALTER TABLE outbox
+    ADD COLUMN claimed_until TIMESTAMPTZ,
+    ADD COLUMN parked_at TIMESTAMPTZ;
+
+DROP INDEX idx_outbox_pending;
+CREATE INDEX idx_outbox_pending ON outbox (id) WHERE sent_at IS NULL AND parked_at IS NULL;
+CREATE INDEX idx_outbox_parked ON outbox (id) WHERE parked_at IS NOT NULL;
@@ -0,0 +1,6 @@
+DROP TRIGGER IF EXISTS schedule_changes_touch_schedules ON schedule_changes;
+DROP FUNCTION IF EXISTS schedule_changes_touch_schedules();
+DROP TRIGGER IF EXISTS group_schedules_touch ON group_schedules;
+DROP FUNCTION IF EXISTS group_schedules_touch();
+
+ALTER TABLE group_schedules DROP COLUMN IF EXISTS updated_at;
@@ -0,0 +1,35 @@
+-- Revisão dos horários para o SEQUENCE/LAST-MODIFIED dos feeds .ics.
+-- `updated_at` fica em segundos e sobe pelo menos um segundo a cada alteração
+-- do horário ou de uma mudança de treino dele, então cresce a cada edição.
+ALTER TABLE group_schedules
+    ADD COLUMN updated_at TIMESTAMPTZ NOT NULL DEFAULT date_trunc('second', NOW());
+
+UPDATE group_schedules SET updated_at = date_trunc('second', created_at);
+
+CREATE FUNCTION group_schedules_touch() RETURNS trigger AS $$
+BEGIN
+    NEW.updated_at := GREATEST(date_trunc('second', NOW()), OLD.updated_at + INTERVAL '1 second');
+    RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER group_schedules_touch
+    BEFORE UPDATE ON group_schedules
+    FOR EACH ROW EXECUTE FUNCTION group_schedules_touch();
+
+-- Criar, alterar ou remover uma mudança de treino revisa o horário dela (as
+-- antigas, sem horário, revisam os da turma), inclusive o de antes da edição
+CREATE FUNCTION schedule_changes_touch_schedules() RETURNS trigger AS $$
+BEGIN
+    UPDATE group_schedules SET updated_at = updated_at
+    WHERE id = OLD.schedule_id
+       OR id = NEW.schedule_id
+       OR (OLD.schedule_id IS NULL AND group_id = OLD.group_id)
+       OR (NEW.schedule_id IS NULL AND group_id = NEW.group_id);
+    RETURN NULL;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER schedule_changes_touch_schedules
+    AFTER INSERT OR UPDATE OR DELETE ON schedule_changes
+    FOR EACH ROW EXECUTE FUNCTION schedule_changes_touch_schedules();
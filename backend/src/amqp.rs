@@ -0,0 +1,243 @@
+// backend/src/amqp.rs
+//! Conexão do backend com o RabbitMQ, mantida por uma tarefa em segundo plano:
+//!
+//! - conecta com espera exponencial (de `amqp.reconnect_initial_ms` até
+//!   `amqp.reconnect_max_ms`) e recomeça sempre que a conexão ou o canal caem;
+//! - a cada conexão recria o canal, liga as confirmações do publisher e declara
+//!   a topologia: a exchange, a fila do transcoder e o binding entre elas;
+//! - enquanto desconectado, `publish` falha na hora com `Unavailable`; quem
+//!   precisa de entrega garantida grava antes na `outbox` (ver `crate::outbox`);
+//! - a fila é durável e as mensagens persistentes (`delivery_mode = 2`), para
+//!   que um job confirmado pelo broker sobreviva a um restart dele.
+//!
+//! É o [`JobPublisher`] usado em produção.
+use app_config::AmqpConfig;
+use async_trait::async_trait;
+use lapin::{
+    options::{
+        BasicPublishOptions, ConfirmSelectOptions, ExchangeDeclareOptions, QueueBindOptions, QueueDeclareOptions,
+    },
+    publisher_confirm::Confirmation,
+    types::{AMQPValue, FieldTable},
+    BasicProperties, Channel, Connection, ConnectionProperties, ExchangeKind,
+};
+use std::{
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Mutex, RwLock,
+    },
+    time::Duration,
+};
+use telemetry::REQUEST_ID_HEADER;
+use tokio::{sync::Notify, task::JoinHandle};
+
+use crate::publisher::{Job, JobPublisher, PublishError};
+
+/// Tempo máximo esperando a tarefa de conexão terminar no desligamento
+const CLOSE_TIMEOUT: Duration = Duration::from_secs(5);
+/// `delivery_mode` das mensagens gravadas em disco pelo broker
+const PERSISTENT: u8 = 2;
+/// Intervalo da verificação do estado da conexão e do canal
+const WATCH_INTERVAL: Duration = Duration::from_secs(1);
+
+pub struct ConnectionManager {
+    config: AmqpConfig,
+    /// Canal atual; `None` enquanto desconectado
+    channel: RwLock<Option<Channel>>,
+    /// Acorda a tarefa de conexão (erro na conexão ou desligamento)
+    wake: Arc<Notify>,
+    shutting_down: AtomicBool,
+    task: Mutex<Option<JoinHandle<()>>>,
+}
+
+impl ConnectionManager {
+    /// Cria o gerenciador e inicia a tarefa de conexão; não espera conectar
+    pub fn start(config: AmqpConfig) -> Arc<Self> {
+        let manager = Arc::new(ConnectionManager {
+            config,
+            channel: RwLock::new(None),
+            wake: Arc::new(Notify::new()),
+            shutting_down: AtomicBool::new(false),
+            task: Mutex::new(None),
+        });
+        let task = tokio::spawn(Arc::clone(&manager).run());
+        *manager.task.lock().expect("task lock poisoned") = Some(task);
+        manager
+    }
+
+    fn current_channel(&self) -> Option<Channel> {
+        let channel = self.channel.read().expect("channel lock poisoned").clone();
+        channel.filter(|channel| channel.status().connected())
+    }
+
+    /// Publica na exchange configurada e espera a confirmação do broker
+    async fn send(
+        &self,
+        routing_key: &str,
+        payload: Vec<u8>,
+        properties: BasicProperties,
+    ) -> Result<(), PublishError> {
+        let channel = self.current_channel().ok_or(PublishError::Unavailable)?;
+
+        // `mandatory`: mensagens sem fila de destino voltam em vez de sumirem
+        let options = BasicPublishOptions { mandatory: true, ..BasicPublishOptions::default() };
+        let publish = async {
+            channel
+                .basic_publish(&self.config.exchange, routing_key, options, payload, properties)
+                .await?
+                .await
+        };
+
+        match tokio::time::timeout(Duration::from_millis(self.config.publish_timeout_ms), publish).await {
+            Err(_) => Err(PublishError::Timeout),
+            Ok(Err(error)) => {
+                // Provavelmente o canal caiu; a tarefa de conexão confere
+                self.wake.notify_one();
+                Err(PublishError::Amqp(error))
+            }
+            Ok(Ok(Confirmation::Ack(None))) | Ok(Ok(Confirmation::NotRequested)) => Ok(()),
+            Ok(Ok(_)) => Err(PublishError::Rejected),
+        }
+    }
+
+    fn is_shutting_down(&self) -> bool {
+        self.shutting_down.load(Ordering::SeqCst)
+    }
+
+    async fn run(self: Arc<Self>) {
+        let initial = Duration::from_millis(self.config.reconnect_initial_ms);
+        let max = Duration::from_millis(self.config.reconnect_max_ms);
+        let mut delay = initial;
+
+        while !self.is_shutting_down() {
+            match self.connect().await {
+                Ok((connection, channel)) => {
+                    tracing::info!("Connected to RabbitMQ");
+                    delay = initial;
+                    *self.channel.write().expect("channel lock poisoned") = Some(channel.clone());
+
+                    self.watch(&connection, &channel).await;
+
+                    *self.channel.write().expect("channel lock poisoned") = None;
+                    if self.is_shutting_down() {
+                        if let Err(error) = connection.close(200, "shutting down").await {
+                            tracing::warn!(error = %error, "Failed to close the RabbitMQ connection");
+                        }
+                        break;
+                    }
+                    tracing::warn!("Lost connection to RabbitMQ; reconnecting");
+                }
+                Err(error) => {
+                    tracing::warn!(
+                        error = %error,
+                        retry_in_ms = delay.as_millis() as u64,
+                        "Failed to connect to RabbitMQ"
+                    );
+                    tokio::select! {
+                        _ = tokio::time::sleep(delay) => {}
+                        _ = self.wake.notified() => {}
+                    }
+                    delay = (delay * 2).min(max);
+                }
+            }
+        }
+        tracing::info!("RabbitMQ connection closed");
+    }
+
+    /// Conecta, liga as confirmações e declara a topologia
+    async fn connect(&self) -> Result<(Connection, Channel), lapin::Error> {
+        let config = &self.config;
+        let connection = Connection::connect(&config.url, ConnectionProperties::default()).await?;
+
+        let wake = Arc::clone(&self.wake);
+        connection.on_error(move |error| {
+            tracing::error!(error = %error, "RabbitMQ connection error");
+            wake.notify_one();
+        });
+
+        let channel = connection.create_channel().await?;
+        channel.confirm_select(ConfirmSelectOptions::default()).await?;
+        channel
+            .exchange_declare(
+                &config.exchange,
+                ExchangeKind::Direct,
+                ExchangeDeclareOptions { durable: true, ..ExchangeDeclareOptions::default() },
+                FieldTable::default(),
+            )
+            .await?;
+        // Mesmas opções usadas pelo transcoder ao declarar a fila. Uma fila já
+        // criada sem `durable` precisa ser apagada antes: o broker recusa
+        // declará-la de novo com outras opções.
+        channel
+            .queue_declare(
+                &config.transcode_queue,
+                QueueDeclareOptions { durable: true, ..QueueDeclareOptions::default() },
+                FieldTable::default(),
+            )
+            .await?;
+        channel
+            .queue_bind(
+                &config.transcode_queue,
+                &config.exchange,
+                &config.routing_key,
+                QueueBindOptions::default(),
+                FieldTable::default(),
+            )
+            .await?;
+
+        Ok((connection, channel))
+    }
+
+    /// Retorna quando a conexão ou o canal caem, ou no desligamento
+    async fn watch(&self, connection: &Connection, channel: &Channel) {
+        let mut interval = tokio::time::interval(WATCH_INTERVAL);
+        loop {
+            if self.is_shutting_down() || !connection.status().connected() || !channel.status().connected() {
+                return;
+            }
+            tokio::select! {
+                _ = interval.tick() => {}
+                _ = self.wake.notified() => {}
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl JobPublisher for ConnectionManager {
+    async fn publish(&self, job: &Job) -> Result<(), PublishError> {
+        // O worker continua o log da requisição que gerou o job
+        let mut headers = FieldTable::default();
+        if let Some(request_id) = &job.request_id {
+            headers.insert(REQUEST_ID_HEADER.into(), AMQPValue::LongString(request_id.as_str().into()));
+        }
+
+        self.send(
+            &job.routing_key,
+            job.payload.to_string().into_bytes(),
+            BasicProperties::default().with_headers(headers).with_delivery_mode(PERSISTENT),
+        )
+        .await
+    }
+
+    fn is_connected(&self) -> bool {
+        self.current_channel().is_some()
+    }
+
+    fn name(&self) -> &'static str {
+        "rabbitmq"
+    }
+
+    /// Fecha a conexão; chamado depois que o servidor HTTP drenou as requisições
+    async fn close(&self) {
+        self.shutting_down.store(true, Ordering::SeqCst);
+        self.wake.notify_one();
+
+        let task = self.task.lock().expect("task lock poisoned").take();
+        if let Some(task) = task {
+            if tokio::time::timeout(CLOSE_TIMEOUT, task).await.is_err() {
+                tracing::warn!("Timed out closing the RabbitMQ connection");
+            }
+        }
+    }
+}
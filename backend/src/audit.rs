@@ -0,0 +1,253 @@
+// backend/src/audit.rs
+//! Auditoria das alterações feitas pela API, gravada em `logs` na mesma
+//! transação da alteração (se a auditoria falhar, a alteração é desfeita).
+//!
+//! Cada linha guarda o autor declarado, a entidade, o IP, o `request_id` e o
+//! estado dos campos alterados antes e depois. O autor vem de `X-User-Id`, que
+//! o cliente envia sem autenticação; o IP é o da conexão, salvo quando ela vem
+//! de um proxy de `server.trusted_proxies`. A tabela é somente-inserção: um trigger
+//! recusa UPDATE, DELETE e TRUNCATE.
+//!
+//! Fluxo nos handlers:
+//!
+//! 1. `snapshot` do registro antes de alterá-lo ou removê-lo (trava a linha);
+//! 2. a alteração, na mesma transação;
+//! 3. `created`, `updated` ou `deleted`.
+use actix_web::{dev::Payload, web::Data, FromRequest, HttpMessage, HttpRequest};
+use futures_util::future::{ready, Ready};
+use serde_json::{Map, Value};
+use sqlx::{Postgres, Transaction};
+use std::net::IpAddr;
+use uuid::Uuid;
+
+use crate::{error::ApiError, request_id::RequestId, AppState};
+
+/// Cabeçalho com o usuário que diz ter feito a requisição, enquanto a API não
+/// tem autenticação. O cliente o envia livremente: serve para atribuir a
+/// auditoria, nunca para decidir o que a requisição pode ver ou alterar.
+pub const USER_ID_HEADER: &str = "x-user-id";
+
+/// Campos que nunca vão para a auditoria; só se registra que mudaram
+const REDACTED_FIELDS: &[&str] = &["password_hash"];
+const REDACTED: &str = "[redacted]";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Action {
+    Create,
+    Update,
+    Delete,
+}
+
+impl Action {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Action::Create => "create",
+            Action::Update => "update",
+            Action::Delete => "delete",
+        }
+    }
+}
+
+/// Entidades auditadas
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Entity {
+    Student,
+    Parent,
+    Document,
+    Warning,
+    User,
+    StudentGuardian,
+    Household,
+}
+
+impl Entity {
+    /// Valor de `entity_type`
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Entity::Student => "student",
+            Entity::Parent => "parent",
+            Entity::Document => "document",
+            Entity::Warning => "warning",
+            Entity::User => "user",
+            Entity::StudentGuardian => "student_guardian",
+            Entity::Household => "household",
+        }
+    }
+
+    fn table(self) -> &'static str {
+        match self {
+            Entity::Student => "students",
+            Entity::Parent => "parents",
+            Entity::Document => "documents",
+            Entity::Warning => "warnings",
+            Entity::User => "users",
+            Entity::StudentGuardian => "student_guardians",
+            Entity::Household => "households",
+        }
+    }
+}
+
+/// Autor declarado e origem da requisição
+#[derive(Debug, Clone)]
+pub struct Actor {
+    /// Valor de `X-User-Id`, quando enviado; declarado pelo cliente, não autenticado
+    pub user_id: Option<Uuid>,
+    /// IP do cliente (ver `client_ip`)
+    pub ip: Option<String>,
+    pub request_id: Option<String>,
+}
+
+/// IP da conexão. Se ela vem de um proxy de `server.trusted_proxies`, segue o
+/// `X-Forwarded-For` da direita para a esquerda (cada proxy acrescenta o
+/// endereço de quem o chamou) até o primeiro endereço que não é de um deles;
+/// o que está antes dele pode ter sido inventado pelo cliente.
+fn client_ip(req: &HttpRequest) -> Option<IpAddr> {
+    let peer = req.peer_addr()?.ip();
+    let Some(state) = req.app_data::<Data<AppState>>() else {
+        return Some(peer);
+    };
+    let server = &state.config.server;
+
+    let hops: Vec<&str> = req
+        .headers()
+        .get_all("x-forwarded-for")
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(','))
+        .map(str::trim)
+        .collect();
+    let mut client = peer;
+    for hop in hops.into_iter().rev() {
+        if !server.is_trusted_proxy(client) {
+            break;
+        }
+        match hop.parse() {
+            Ok(ip) => client = ip,
+            Err(_) => break,
+        }
+    }
+    Some(client)
+}
+
+impl FromRequest for Actor {
+    type Error = ApiError;
+    type Future = Ready<Result<Self, Self::Error>>;
+
+    fn from_request(req: &HttpRequest, _: &mut Payload) -> Self::Future {
+        let user_id = match req.headers().get(USER_ID_HEADER) {
+            None => None,
+            Some(value) => match value.to_str().ok().and_then(|v| Uuid::parse_str(v.trim()).ok()) {
+                Some(user_id) => Some(user_id),
+                None => {
+                    return ready(Err(ApiError::BadRequest("X-User-Id must be a UUID".to_string())));
+                }
+            },
+        };
+        let ip = client_ip(req).map(|ip| ip.to_string());
+        let request_id = req.extensions().get::<RequestId>().map(|id| id.as_str().to_string());
+
+        ready(Ok(Actor { user_id, ip, request_id }))
+    }
+}
+
+/// Estado atual do registro como JSON (todas as colunas), travando a linha até
+/// o fim da transação. `None` se o registro não existe.
+pub async fn snapshot(
+    tx: &mut Transaction<'_, Postgres>,
+    entity: Entity,
+    id: Uuid,
+) -> Result<Option<Value>, ApiError> {
+    let query = format!("SELECT to_jsonb(t) FROM {} t WHERE t.id = $1 FOR UPDATE", entity.table());
+    let row: Option<(Value,)> = sqlx::query_as(&query).bind(id).fetch_optional(&mut *tx).await?;
+    Ok(row.map(|(value,)| value))
+}
+
+/// Registra a criação; o estado "depois" é lido do banco
+pub async fn created(
+    tx: &mut Transaction<'_, Postgres>,
+    actor: &Actor,
+    entity: Entity,
+    id: Uuid,
+) -> Result<(), ApiError> {
+    let after = snapshot(tx, entity, id).await?;
+    record(tx, actor, Action::Create, entity, id, None, after).await
+}
+
+/// Registra a alteração a partir do `snapshot` tirado antes dela. Alterações
+/// que não mudaram nenhum campo não são registradas.
+pub async fn updated(
+    tx: &mut Transaction<'_, Postgres>,
+    actor: &Actor,
+    entity: Entity,
+    id: Uuid,
+    before: Value,
+) -> Result<(), ApiError> {
+    let after = snapshot(tx, entity, id).await?;
+    let (before, after) = diff(&before, &after.unwrap_or(Value::Null));
+    if before.is_empty() && after.is_empty() {
+        return Ok(());
+    }
+    record(tx, actor, Action::Update, entity, id, Some(Value::Object(before)), Some(Value::Object(after))).await
+}
+
+/// Registra a remoção a partir do `snapshot` tirado antes dela
+pub async fn deleted(
+    tx: &mut Transaction<'_, Postgres>,
+    actor: &Actor,
+    entity: Entity,
+    id: Uuid,
+    before: Value,
+) -> Result<(), ApiError> {
+    record(tx, actor, Action::Delete, entity, id, Some(before), None).await
+}
+
+async fn record(
+    tx: &mut Transaction<'_, Postgres>,
+    actor: &Actor,
+    action: Action,
+    entity: Entity,
+    id: Uuid,
+    before: Option<Value>,
+    after: Option<Value>,
+) -> Result<(), ApiError> {
+    let query = r#"
+        INSERT INTO logs (user_id, action, description, entity_type, entity_id, before, after, ip_address, request_id)
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+    "#;
+
+    sqlx::query(query)
+        .bind(actor.user_id)
+        .bind(action.as_str())
+        .bind(format!("{} {} {}", action.as_str(), entity.as_str(), id))
+        .bind(entity.as_str())
+        .bind(id)
+        .bind(before.map(redact))
+        .bind(after.map(redact))
+        .bind(&actor.ip)
+        .bind(&actor.request_id)
+        .execute(&mut *tx)
+        .await?;
+    Ok(())
+}
+
+/// Só os campos que mudaram, com o valor antes e depois
+fn diff(before: &Value, after: &Value) -> (Map<String, Value>, Map<String, Value>) {
+    let empty = Map::new();
+    let before = before.as_object().unwrap_or(&empty);
+    let after = after.as_object().unwrap_or(&empty);
+
+    let changed = |key: &String| before.get(key) != after.get(key);
+    let old = before.iter().filter(|(key, _)| changed(key)).map(|(k, v)| (k.clone(), v.clone())).collect();
+    let new = after.iter().filter(|(key, _)| changed(key)).map(|(k, v)| (k.clone(), v.clone())).collect();
+    (old, new)
+}
+
+fn redact(mut value: Value) -> Value {
+    if let Value::Object(fields) = &mut value {
+        for field in REDACTED_FIELDS {
+            if let Some(v) = fields.get_mut(*field) {
+                *v = Value::String(REDACTED.to_string());
+            }
+        }
+    }
+    value
+}
@@ -0,0 +1,115 @@
+// backend/src/calendar.rs
+//! Expansão dos horários fixos das turmas em sessões concretas, com as
+//! mudanças de treino (`schedule_changes`) aplicadas
+use std::collections::{BTreeSet, HashMap};
+
+use chrono::{Datelike, Duration, NaiveDate, NaiveDateTime, NaiveTime};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::{
+    models::{CalendarSession, GroupScheduleModel, ScheduleChangeModel, SessionStatus},
+    repo::GroupScheduleRepo,
+};
+
+fn iso_weekday(date: NaiveDate) -> i32 {
+    date.weekday().number_from_monday() as i32
+}
+
+/// Se `start` é o início previsto de uma sessão do horário
+pub fn is_occurrence(schedule: &GroupScheduleModel, start: NaiveDateTime) -> bool {
+    let date = start.date();
+    start.time() == schedule.start_time
+        && iso_weekday(date) == schedule.weekday
+        && date >= schedule.valid_from
+        && schedule.valid_until.is_none_or(|until| date <= until)
+}
+
+// Inícios previstos das sessões do horário entre `from` e `to`, inclusive
+fn occurrences(schedule: &GroupScheduleModel, from: NaiveDate, to: NaiveDate) -> impl Iterator<Item = NaiveDateTime> + '_ {
+    let first = from.max(schedule.valid_from);
+    let last = schedule.valid_until.map_or(to, |until| until.min(to));
+    let offset = (schedule.weekday - iso_weekday(first)).rem_euclid(7);
+
+    std::iter::successors(Some(first + Duration::days(offset.into())), |date| Some(*date + Duration::weeks(1)))
+        .take_while(move |date| *date <= last)
+        .map(move |date| date.and_time(schedule.start_time))
+}
+
+/// Sessões dos horários com início entre `from` e `to` (inclusive), em ordem.
+/// Uma sessão remarcada aparece na data nova, mesmo que a prevista esteja fora
+/// do período; uma cancelada continua na data prevista, com `status` `cancelled`.
+pub fn expand(
+    schedules: &[GroupScheduleModel],
+    changes: &[ScheduleChangeModel],
+    from: NaiveDate,
+    to: NaiveDate,
+) -> Vec<CalendarSession> {
+    // Mudança de cada sessão, pelo horário e início previsto. As antigas, sem
+    // horário, valem para o horário da turma que tem sessão em `old_date`; na
+    // repetição, vale a última.
+    let mut by_occurrence: HashMap<(Uuid, NaiveDateTime), &ScheduleChangeModel> = HashMap::new();
+    for change in changes {
+        let Some(old_date) = change.old_date else {
+            continue;
+        };
+        let schedule = schedules
+            .iter()
+            .filter(|schedule| change.schedule_id.is_none_or(|id| id == schedule.id))
+            .find(|schedule| is_occurrence(schedule, old_date));
+        if let Some(schedule) = schedule {
+            by_occurrence.insert((schedule.id, old_date), change);
+        }
+    }
+
+    let mut candidates: BTreeSet<(NaiveDateTime, Uuid)> = schedules
+        .iter()
+        .flat_map(|schedule| occurrences(schedule, from, to).map(|occurrence| (occurrence, schedule.id)))
+        .collect();
+    candidates.extend(by_occurrence.keys().map(|(schedule_id, occurrence)| (*occurrence, *schedule_id)));
+
+    let mut sessions: Vec<CalendarSession> = candidates
+        .into_iter()
+        .filter_map(|(occurrence, schedule_id)| {
+            let schedule = schedules.iter().find(|schedule| schedule.id == schedule_id)?;
+            let change = by_occurrence.get(&(schedule_id, occurrence));
+            let (starts_at, status) = match change.map(|change| change.new_date) {
+                None => (occurrence, SessionStatus::Scheduled),
+                Some(None) => (occurrence, SessionStatus::Cancelled),
+                Some(Some(new_date)) => (new_date, SessionStatus::Rescheduled),
+            };
+            if starts_at.date() < from || starts_at.date() > to {
+                return None;
+            }
+
+            Some(CalendarSession {
+                schedule_id,
+                occurrence,
+                starts_at,
+                ends_at: starts_at + (schedule.end_time - schedule.start_time),
+                location: schedule.location.clone(),
+                status,
+                change_id: change.map(|change| change.id),
+                reason: change.and_then(|change| change.reason.clone()),
+                updated_at: schedule.updated_at,
+            })
+        })
+        .collect();
+    sessions.sort_by_key(|session| (session.starts_at, session.occurrence, session.schedule_id));
+    sessions
+}
+
+/// Sessões da turma entre `from` e `to` (inclusive), lendo os horários e as
+/// mudanças de treino do banco
+pub async fn sessions(db: &PgPool, group_id: Uuid, from: NaiveDate, to: NaiveDate) -> sqlx::Result<Vec<CalendarSession>> {
+    let (schedules, changes) = tokio::try_join!(
+        GroupScheduleRepo::for_group(db, group_id),
+        GroupScheduleRepo::changes_between(
+            db,
+            group_id,
+            from.and_time(NaiveTime::MIN),
+            (to + Duration::days(1)).and_time(NaiveTime::MIN),
+        ),
+    )?;
+    Ok(expand(&schedules, &changes, from, to))
+}
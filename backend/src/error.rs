@@ -0,0 +1,216 @@
+// backend/src/error.rs
+use actix_web::{http::StatusCode, web, HttpResponse, ResponseError};
+use serde::Serialize;
+use std::fmt;
+use utoipa::ToSchema;
+
+/// Erro padrão dos handlers. Toda resposta de erro segue o envelope
+/// `{"status": "error", "code": "...", "message": "..."}`, onde `code` é estável
+/// e pode ser usado pelo frontend; detalhes internos (sqlx, io) só vão para o log.
+#[derive(Debug)]
+pub enum ApiError {
+    /// 400 - requisição malformada
+    BadRequest(String),
+    /// 400 - dados recusados por uma regra de validação
+    Validation(String),
+    /// 400 - corpo recusado pela validação do schema, com a lista de erros por campo
+    Fields(Vec<FieldError>),
+    /// 404 - recurso inexistente
+    NotFound(String),
+    /// 409 - conflito com um registro existente (ex.: chave única)
+    Conflict(String),
+    /// 413 - arquivo enviado maior que o limite configurado
+    PayloadTooLarge(String),
+    /// 422 - referência a um registro inexistente (chave estrangeira)
+    Unprocessable(String),
+    /// 500 - erro inesperado; a mensagem não é enviada ao cliente
+    Internal(String),
+}
+
+/// Erro de um campo do corpo da requisição (`errors` no envelope de `ApiError::Fields`)
+#[derive(Debug, Serialize, ToSchema)]
+pub struct FieldError {
+    /// Ausente quando a regra envolve mais de um campo
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub field: Option<String>,
+    pub code: String,
+    pub message: String,
+}
+
+/// Corpo das respostas de erro
+#[derive(Serialize, ToSchema)]
+pub struct ErrorBody<'a> {
+    /// Sempre `"error"`
+    pub status: &'a str,
+    /// Código estável (`bad_request`, `validation_failed`, `not_found`, ...)
+    pub code: &'a str,
+    pub message: &'a str,
+    /// Só presente nos erros de validação do corpo
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub errors: Option<&'a [FieldError]>,
+}
+
+impl ApiError {
+    pub fn not_found(what: &str) -> Self {
+        ApiError::NotFound(format!("{} not found", what))
+    }
+
+    pub fn internal<E: fmt::Debug>(context: &str, error: E) -> Self {
+        ApiError::Internal(format!("{}: {:?}", context, error))
+    }
+
+    /// Código estável exposto no campo `code`
+    pub fn code(&self) -> &'static str {
+        match self {
+            ApiError::BadRequest(_) => "bad_request",
+            ApiError::Validation(_) | ApiError::Fields(_) => "validation_failed",
+            ApiError::NotFound(_) => "not_found",
+            ApiError::Conflict(_) => "conflict",
+            ApiError::PayloadTooLarge(_) => "payload_too_large",
+            ApiError::Unprocessable(_) => "unprocessable_entity",
+            ApiError::Internal(_) => "internal_error",
+        }
+    }
+
+    fn client_message(&self) -> &str {
+        match self {
+            ApiError::BadRequest(message)
+            | ApiError::Validation(message)
+            | ApiError::NotFound(message)
+            | ApiError::Conflict(message)
+            | ApiError::PayloadTooLarge(message)
+            | ApiError::Unprocessable(message) => message,
+            ApiError::Fields(_) => "Request body failed validation",
+            ApiError::Internal(_) => "Internal server error",
+        }
+    }
+}
+
+impl fmt::Display for ApiError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ApiError::Internal(message) => write!(f, "{}: {}", self.code(), message),
+            _ => write!(f, "{}: {}", self.code(), self.client_message()),
+        }
+    }
+}
+
+impl ResponseError for ApiError {
+    fn status_code(&self) -> StatusCode {
+        match self {
+            ApiError::BadRequest(_) | ApiError::Validation(_) | ApiError::Fields(_) => StatusCode::BAD_REQUEST,
+            ApiError::NotFound(_) => StatusCode::NOT_FOUND,
+            ApiError::Conflict(_) => StatusCode::CONFLICT,
+            ApiError::PayloadTooLarge(_) => StatusCode::PAYLOAD_TOO_LARGE,
+            ApiError::Unprocessable(_) => StatusCode::UNPROCESSABLE_ENTITY,
+            ApiError::Internal(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+
+    fn error_response(&self) -> HttpResponse {
+        if let ApiError::Internal(message) = self {
+            log::error!("{}", message);
+        }
+
+        let errors = match self {
+            ApiError::Fields(errors) => Some(errors.as_slice()),
+            _ => None,
+        };
+
+        HttpResponse::build(self.status_code()).json(ErrorBody {
+            status: "error",
+            code: self.code(),
+            message: self.client_message(),
+            errors,
+        })
+    }
+}
+
+/// Erros de extração (JSON, path, query) usam o mesmo envelope de erro dos handlers
+pub fn extractors(cfg: &mut web::ServiceConfig, max_json_bytes: usize) {
+    cfg.app_data(web::JsonConfig::default().limit(max_json_bytes).error_handler(|err, _req| {
+        ApiError::BadRequest(format!("Invalid JSON body: {}", err)).into()
+    }))
+    .app_data(web::PathConfig::default().error_handler(|err, _req| {
+        ApiError::BadRequest(format!("Invalid path parameter: {}", err)).into()
+    }))
+    .app_data(web::QueryConfig::default().error_handler(|err, _req| {
+        ApiError::BadRequest(format!("Invalid query string: {}", err)).into()
+    }));
+}
+
+// Códigos SQLSTATE do Postgres
+const UNIQUE_VIOLATION: &str = "23505";
+const FOREIGN_KEY_VIOLATION: &str = "23503";
+const NOT_NULL_VIOLATION: &str = "23502";
+const CHECK_VIOLATION: &str = "23514";
+const INVALID_TEXT_REPRESENTATION: &str = "22P02";
+const STRING_DATA_RIGHT_TRUNCATION: &str = "22001";
+const INVALID_DATETIME_FORMAT: &str = "22007";
+const DATETIME_FIELD_OVERFLOW: &str = "22008";
+
+impl From<sqlx::Error> for ApiError {
+    fn from(error: sqlx::Error) -> Self {
+        match &error {
+            sqlx::Error::RowNotFound => ApiError::NotFound("Resource not found".to_string()),
+            sqlx::Error::Database(db_error) => {
+                let constraint = db_error.constraint().unwrap_or("unknown");
+                match db_error.code().as_deref() {
+                    Some(UNIQUE_VIOLATION) => {
+                        ApiError::Conflict(format!("A record with the same value already exists ({})", constraint))
+                    }
+                    Some(FOREIGN_KEY_VIOLATION) => {
+                        ApiError::Unprocessable(format!("Referenced record does not exist ({})", constraint))
+                    }
+                    Some(CHECK_VIOLATION) => {
+                        ApiError::Validation(format!("Value not allowed ({})", constraint))
+                    }
+                    Some(NOT_NULL_VIOLATION) => ApiError::Validation("Missing required field".to_string()),
+                    Some(INVALID_TEXT_REPRESENTATION) => ApiError::BadRequest("Invalid value format".to_string()),
+                    Some(INVALID_DATETIME_FORMAT) | Some(DATETIME_FIELD_OVERFLOW) => {
+                        ApiError::BadRequest("Invalid date or time".to_string())
+                    }
+                    Some(STRING_DATA_RIGHT_TRUNCATION) => ApiError::Validation("Value too long".to_string()),
+                    _ => ApiError::internal("Database error", &error),
+                }
+            }
+            _ => ApiError::internal("Database error", &error),
+        }
+    }
+}
+
+impl From<std::io::Error> for ApiError {
+    fn from(error: std::io::Error) -> Self {
+        ApiError::internal("I/O error", error)
+    }
+}
+
+impl From<actix_multipart::MultipartError> for ApiError {
+    fn from(error: actix_multipart::MultipartError) -> Self {
+        ApiError::BadRequest(format!("Invalid multipart payload: {}", error))
+    }
+}
+
+impl From<validator::ValidationErrors> for ApiError {
+    fn from(errors: validator::ValidationErrors) -> Self {
+        let mut fields: Vec<FieldError> = errors
+            .field_errors()
+            .into_iter()
+            .flat_map(|(field, errors)| {
+                // `__all__` guarda os erros das validações de schema (mais de um campo)
+                let field = (field != "__all__").then(|| field.to_string());
+                errors.iter().map(move |error| FieldError {
+                    field: field.clone(),
+                    code: error.code.to_string(),
+                    message: error
+                        .message
+                        .as_ref()
+                        .map(|message| message.to_string())
+                        .unwrap_or_else(|| format!("invalid value ({})", error.code)),
+                })
+            })
+            .collect();
+        fields.sort_by(|a, b| a.field.cmp(&b.field));
+        ApiError::Fields(fields)
+    }
+}
@@ -0,0 +1,113 @@
+// backend/src/ics.rs
+//! Calendários iCalendar (RFC 5545) para os feeds de treino.
+//!
+//! Cada sessão vira um VEVENT com UID fixo (horário + início previsto), então
+//! uma sessão remarcada ou cancelada atualiza o evento que o app de calendário
+//! já tem, em vez de criar outro. Os horários saem sem fuso ("floating"), como
+//! estão no banco: o app mostra no fuso do próprio aparelho.
+use chrono::{DateTime, NaiveDateTime, Utc};
+
+use crate::models::{CalendarSession, SessionStatus};
+
+const PRODID: &str = "-//Escolinha//Treinos//PT";
+const UID_DOMAIN: &str = "treinos.escolinha";
+/// Origem do SEQUENCE (2025-01-01T00:00:00Z): os segundos desde ela até a
+/// última alteração cabem no INTEGER do RFC 5545 e crescem a cada edição
+const SEQUENCE_EPOCH: i64 = 1_735_689_600;
+/// Tamanho máximo de uma linha, em bytes, antes de dobrá-la
+const MAX_LINE_OCTETS: usize = 75;
+
+/// Tipo MIME do corpo
+pub const CONTENT_TYPE: &str = "text/calendar; charset=utf-8";
+
+// Escapa um valor TEXT (`\`, `;`, `,` e quebras de linha)
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            ';' => escaped.push_str("\\;"),
+            ',' => escaped.push_str("\\,"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => {}
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+// Acrescenta a linha terminada em CRLF, dobrando-a em pedaços de até 75 bytes
+// sem partir caracteres UTF-8 (as continuações começam com um espaço)
+fn push_line(out: &mut String, line: &str) {
+    let mut limit = MAX_LINE_OCTETS;
+    let mut used = 0;
+    for c in line.chars() {
+        if used + c.len_utf8() > limit {
+            out.push_str("\r\n ");
+            // O espaço da continuação conta no limite
+            limit = MAX_LINE_OCTETS - 1;
+            used = 0;
+        }
+        out.push(c);
+        used += c.len_utf8();
+    }
+    out.push_str("\r\n");
+}
+
+fn local_time(time: NaiveDateTime) -> String {
+    time.format("%Y%m%dT%H%M%S").to_string()
+}
+
+fn utc_time(time: DateTime<Utc>) -> String {
+    time.format("%Y%m%dT%H%M%SZ").to_string()
+}
+
+/// UID estável da sessão: não muda quando ela é remarcada ou cancelada
+fn uid(session: &CalendarSession) -> String {
+    format!("{}-{}@{}", session.schedule_id, local_time(session.occurrence), UID_DOMAIN)
+}
+
+/// Calendário `name` com as sessões, cada uma com o seu título. `stamp` é o
+/// DTSTAMP de todos os eventos (o momento em que o feed foi gerado).
+pub fn render<'a>(
+    name: &str,
+    sessions: impl IntoIterator<Item = (&'a str, &'a CalendarSession)>,
+    stamp: DateTime<Utc>,
+) -> String {
+    let stamp = utc_time(stamp);
+    let mut out = String::new();
+    push_line(&mut out, "BEGIN:VCALENDAR");
+    push_line(&mut out, "VERSION:2.0");
+    push_line(&mut out, &format!("PRODID:{}", PRODID));
+    push_line(&mut out, "CALSCALE:GREGORIAN");
+    push_line(&mut out, "METHOD:PUBLISH");
+    push_line(&mut out, &format!("X-WR-CALNAME:{}", escape(name)));
+
+    for (summary, session) in sessions {
+        push_line(&mut out, "BEGIN:VEVENT");
+        push_line(&mut out, &format!("UID:{}", uid(session)));
+        push_line(&mut out, &format!("DTSTAMP:{}", stamp));
+        push_line(&mut out, &format!("DTSTART:{}", local_time(session.starts_at)));
+        push_line(&mut out, &format!("DTEND:{}", local_time(session.ends_at)));
+        push_line(&mut out, &format!("SUMMARY:{}", escape(summary)));
+        if let Some(location) = &session.location {
+            push_line(&mut out, &format!("LOCATION:{}", escape(location)));
+        }
+        if let Some(reason) = &session.reason {
+            push_line(&mut out, &format!("DESCRIPTION:{}", escape(reason)));
+        }
+        let status = match session.status {
+            SessionStatus::Cancelled => "CANCELLED",
+            SessionStatus::Scheduled | SessionStatus::Rescheduled => "CONFIRMED",
+        };
+        push_line(&mut out, &format!("STATUS:{}", status));
+        // Cada edição do horário ou das mudanças dele é uma nova versão do evento
+        let sequence = (session.updated_at.timestamp() - SEQUENCE_EPOCH).max(0);
+        push_line(&mut out, &format!("SEQUENCE:{}", sequence));
+        push_line(&mut out, &format!("LAST-MODIFIED:{}", utc_time(session.updated_at)));
+        push_line(&mut out, "END:VEVENT");
+    }
+
+    push_line(&mut out, "END:VCALENDAR");
+    out
+}
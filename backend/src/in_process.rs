@@ -0,0 +1,119 @@
+// backend/src/in_process.rs
+//! Jobs rodados pelo próprio backend (`jobs.publisher = "in_process"`), para
+//! instalações em uma máquina só, sem RabbitMQ nem transcoder. Os jobs entram
+//! numa fila em memória e uma única tarefa os executa em ordem: os que ainda
+//! estavam na fila quando o processo cai se perdem, já que a outbox os marcou
+//! como enviados.
+use app_config::Config;
+use async_trait::async_trait;
+use std::{
+    path::Path,
+    process::Stdio,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+use tokio::{process::Command, sync::mpsc, task::JoinHandle};
+use tracing::Instrument;
+
+use crate::publisher::{Job, JobPublisher, PublishError};
+
+/// Quanto o desligamento espera o job em andamento; depois disso o ffmpeg é morto
+const CLOSE_TIMEOUT: Duration = Duration::from_secs(10);
+
+pub struct InProcessPublisher {
+    /// `None` depois do `close`
+    sender: Mutex<Option<mpsc::UnboundedSender<Job>>>,
+    task: Mutex<Option<JoinHandle<()>>>,
+}
+
+impl InProcessPublisher {
+    pub fn start(config: Arc<Config>) -> Arc<Self> {
+        let (sender, mut receiver) = mpsc::unbounded_channel::<Job>();
+        let task = tokio::spawn(async move {
+            while let Some(job) = receiver.recv().await {
+                let span = tracing::info_span!("job", request_id = job.request_id.as_deref().unwrap_or(""));
+                run(&job, &config).instrument(span).await;
+            }
+        });
+
+        Arc::new(InProcessPublisher {
+            sender: Mutex::new(Some(sender)),
+            task: Mutex::new(Some(task)),
+        })
+    }
+}
+
+#[async_trait]
+impl JobPublisher for InProcessPublisher {
+    async fn publish(&self, job: &Job) -> Result<(), PublishError> {
+        let sender = self.sender.lock().expect("sender lock poisoned");
+        match sender.as_ref() {
+            Some(sender) => sender.send(job.clone()).map_err(|_| PublishError::Unavailable),
+            None => Err(PublishError::Unavailable),
+        }
+    }
+
+    fn is_connected(&self) -> bool {
+        self.sender.lock().expect("sender lock poisoned").is_some()
+    }
+
+    fn name(&self) -> &'static str {
+        "in_process"
+    }
+
+    /// Para de aceitar jobs e espera a fila esvaziar, até `CLOSE_TIMEOUT`
+    async fn close(&self) {
+        self.sender.lock().expect("sender lock poisoned").take();
+        let task = self.task.lock().expect("task lock poisoned").take();
+        if let Some(mut task) = task {
+            if tokio::time::timeout(CLOSE_TIMEOUT, &mut task).await.is_err() {
+                tracing::warn!("In-process jobs still running after {:?}; aborting", CLOSE_TIMEOUT);
+                task.abort();
+            }
+        }
+    }
+}
+
+async fn run(job: &Job, config: &Config) {
+    match job.payload["action"].as_str() {
+        Some("transcode") => match job.payload["video_id"].as_str() {
+            Some(video_id) => transcode(video_id, config).await,
+            None => tracing::warn!(payload = %job.payload, "Transcode job without video_id; discarding"),
+        },
+        _ => tracing::warn!(payload = %job.payload, routing_key = %job.routing_key, "Unknown job; discarding"),
+    }
+}
+
+/// Faz o que o transcoder faria: passa o vídeo enviado em pedaços
+/// (`{chunks_dir}/{video_id}.mp4`, já consolidado quando o job é gravado) pelo ffmpeg, gravando o resultado em
+/// `{uploads_dir}/{video_id}.mp4`, servido em `/uploads`
+async fn transcode(video_id: &str, config: &Config) {
+    let input = Path::new(&config.storage.chunks_dir).join(format!("{}.mp4", video_id));
+    let output = Path::new(&config.storage.uploads_dir).join(format!("{}.mp4", video_id));
+    if !input.exists() {
+        tracing::warn!("No uploaded file for video {} at {}; skipping transcode", video_id, input.display());
+        return;
+    }
+
+    let result = Command::new(&config.transcoder.ffmpeg_path)
+        .arg("-y")
+        .arg("-i")
+        .arg(&input)
+        .arg(&output)
+        .stdin(Stdio::null())
+        .kill_on_drop(true)
+        .output()
+        .await;
+
+    match result {
+        Ok(result) if result.status.success() => {
+            tracing::info!("Transcoded video {} to {}", video_id, output.display());
+        }
+        Ok(result) => tracing::error!(
+            stderr = %String::from_utf8_lossy(&result.stderr),
+            "ffmpeg failed to transcode {}",
+            input.display()
+        ),
+        Err(error) => tracing::error!("Failed to run {}: {}", config.transcoder.ffmpeg_path, error),
+    }
+}
@@ -1,80 +1,168 @@
-use actix_web::{web, App, HttpServer, middleware::Logger};
+use actix_web::{web, App, HttpServer};
 use actix_cors::Cors;
 use dotenv::dotenv;
 use sqlx::{postgres::PgPoolOptions, Pool, Postgres};
-use std::env;
 use actix_files::Files;
-use lapin::{Connection, ConnectionProperties, Channel}; // RabbitMQ
-use std::sync::Arc;
+use app_config::{Config, PublisherKind, ServerConfig};
+use std::{sync::Arc, time::Duration};
+use publisher::{JobPublisher, NoopPublisher};
 
+mod amqp;
+mod audit;
+mod calendar;
+mod error;
+mod ics;
+mod in_process;
+mod metrics;
+mod migrate;
 mod services;
 mod models;
+mod openapi;
+mod outbox;
+mod pagination;
+mod publisher;
+mod repo;
+mod request_id;
 mod schema;
+mod slug;
+mod storage;
+mod validation;
+
+#[cfg(test)]
+mod tests;
 
 pub struct AppState {
     db: Pool<Postgres>,
-    rabbitmq_channel: Arc<Channel>, // Adicionando o canal do RabbitMQ
+    publisher: Arc<dyn JobPublisher>, // Escolhido em `jobs.publisher` (RabbitMQ por padrão)
+    outbox: Arc<outbox::Relay>, // Publica as mensagens gravadas na tabela `outbox`
+    config: Arc<Config>,
+}
+
+// CORS a partir de `server.cors_origins` ("*" libera qualquer origem)
+fn cors(server: &ServerConfig) -> Cors {
+    let cors = if server.allows_any_origin() {
+        Cors::default().allow_any_origin()
+    } else {
+        server
+            .cors_origins
+            .iter()
+            .fold(Cors::default(), |cors, origin| cors.allowed_origin(origin))
+    };
+    cors.allow_any_method().allow_any_header()
 }
 
+const USAGE: &str = "Usage: backend [serve|migrate]";
+
 #[actix_web::main]
 async fn main() -> std::io::Result<()> {
-    println!("Server starting...");
+    // `backend migrate` só aplica as migrations e sai; sem argumento, sobe o servidor
+    let migrate_only = match std::env::args().nth(1).as_deref() {
+        None | Some("serve") => false,
+        Some("migrate") => true,
+        Some(other) => {
+            eprintln!("Unknown command '{}'. {}", other, USAGE);
+            std::process::exit(2);
+        }
+    };
 
-    // Configure logging
-    if std::env::var_os("RUST_LOG").is_none() {
-        std::env::set_var("RUST_LOG", "actix_web=info");
-    }
     dotenv().ok();
-    env_logger::init();
 
-    // Retrieve database URL from environment variables
-    let database_url = env::var("DATABASE_URL").expect("DATABASE_URL must be set");
+    // `config.toml` (ou `APP_CONFIG`) + variáveis de ambiente
+    let config = match Config::load().and_then(Config::require_database) {
+        Ok(config) => Arc::new(config),
+        Err(error) => {
+            eprintln!("{}", error);
+            std::process::exit(1);
+        }
+    };
+
+    // Logs (texto ou JSON) e, se configurado, exportação OTLP; o guard envia
+    // os spans pendentes quando o servidor termina
+    let _telemetry = match telemetry::init("backend", &config.telemetry) {
+        Ok(telemetry) => telemetry,
+        Err(error) => {
+            eprintln!("Failed to initialise logging: {}", error);
+            std::process::exit(1);
+        }
+    };
+    tracing::info!("Server starting...");
 
     // Create the database connection pool
-    let pool = match PgPoolOptions::new().max_connections(10).connect(&database_url).await {
+    let pool = PgPoolOptions::new()
+        .max_connections(config.database.max_connections)
+        .min_connections(config.database.min_connections)
+        .acquire_timeout(Duration::from_secs(config.database.acquire_timeout_secs))
+        .connect(&config.database.url)
+        .await;
+    let pool = match pool {
         Ok(pool) => {
-            println!("Connected to the database successfully.");
+            tracing::info!("Connected to the database successfully.");
             pool
         }
         Err(error) => {
-            eprintln!("Failed to connect to the database: {:?}", error);
+            tracing::error!("Failed to connect to the database: {:?}", error);
             std::process::exit(1);
         }
     };
 
-    // Configurar conexão com o RabbitMQ
-    let amqp_url = env::var("AMQP_URL").expect("AMQP_URL deve ser definido");
-    let rabbitmq_connection = Connection::connect(&amqp_url, ConnectionProperties::default())
-        .await
-        .expect("Falha ao conectar ao RabbitMQ");
-    let rabbitmq_channel = Arc::new(
-        rabbitmq_connection
-            .create_channel()
-            .await
-            .expect("Falha ao criar canal no RabbitMQ"),
-    );
+    if migrate_only || config.database.run_migrations {
+        if let Err(error) = migrate::run(&pool).await {
+            tracing::error!("Failed to run migrations: {}", error);
+            std::process::exit(1);
+        }
+        if let Some((version, description)) = migrate::latest() {
+            tracing::info!("Database schema is up to date (migration {}: {}).", version, description);
+        }
+        if migrate_only {
+            return Ok(());
+        }
+    }
+
+    let publisher: Arc<dyn JobPublisher> = match config.jobs.publisher {
+        // RabbitMQ: conecta em segundo plano; o servidor sobe mesmo com o broker fora
+        PublisherKind::Amqp => amqp::ConnectionManager::start(config.amqp.clone()),
+        PublisherKind::InProcess => in_process::InProcessPublisher::start(config.clone()),
+        PublisherKind::Noop => Arc::new(NoopPublisher),
+    };
+    tracing::info!("Publishing jobs with the '{}' publisher", publisher.name());
+    // Jobs gravados na `outbox` junto com a alteração; o relay os publica
+    let relay = outbox::Relay::start(pool.clone(), publisher.clone(), config.outbox.clone());
 
     // Start the HTTP server
-    HttpServer::new(move || {
+    let bind = config.server.bind.clone();
+    let shutdown_timeout = config.server.shutdown_timeout_secs;
+    let state_pool = pool.clone();
+    let state_publisher = publisher.clone();
+    let state_relay = relay.clone();
+    let server = HttpServer::new(move || {
         App::new()
             .app_data(web::Data::new(AppState { 
-                db: pool.clone(),
-                rabbitmq_channel: rabbitmq_channel.clone(),
-            })) // Share the database pool and RabbitMQ channel across handlers
-            .configure(services::config) // Register routes and services
-            .wrap(Logger::default()) // Enable request logging
-            .wrap(
-                Cors::default()
-                    .allow_any_origin() // Allow requests from any origin
-                    .allow_any_method() // Allow any HTTP method
-                    .allow_any_header() // Allow any headers
-            )
-            .service(Files::new("/thumbnails", "./media/thumbnails").show_files_listing()) // Servindo arquivos de thumbnails
-            .service(Files::new("/uploads", "./static/uploads").show_files_listing()) // Serve arquivos da pasta uploads
-         //   .service(Files::new("/static", "./static").show_files_listing()) // Serve arquivos estáticos
-           // .service(Files::new("/uploads", "./uploads").show_files_listing()) // Servir arquivos estáticos do diretório de uploads
+                db: state_pool.clone(),
+                publisher: state_publisher.clone(),
+                outbox: state_relay.clone(),
+                config: config.clone(),
+            })) // Share the database pool, job publisher and config across handlers
+            .configure(|cfg| error::extractors(cfg, config.server.max_json_bytes))
+            .configure(openapi::config) // `/api/openapi.json` e Swagger UI em `/api/docs/`
+            .configure(metrics::config) // `/metrics` para o Prometheus
+            .configure(|cfg| services::config(cfg, &config.storage)) // Register routes and services
+            .wrap_fn(metrics::track) // Contagem e latência por rota
+            .wrap_fn(request_id::propagate) // Span `http_request` com o `X-Request-Id` e log de cada requisição
+            .wrap(cors(&config.server))
+            .service(Files::new("/thumbnails", &config.storage.thumbnails_dir).show_files_listing()) // Servindo arquivos de thumbnails
+            .service(Files::new("/uploads", &config.storage.uploads_dir).show_files_listing()) // Serve arquivos da pasta uploads
         })
-    .bind("0.0.0.0:8080")?  // <-- AQUI ESTÁ A CORREÇÃO
-    .run()
-    .await
+    // Em SIGTERM/SIGINT para de aceitar conexões e espera as requisições em andamento
+    .shutdown_timeout(shutdown_timeout)
+    .bind(bind)?
+    .run();
+    server.await?;
+
+    // Só depois de drenar as requisições: ninguém mais publica nem consulta
+    tracing::info!("Server stopped; closing connections");
+    relay.close().await;
+    publisher.close().await;
+    pool.close().await;
+    tracing::info!("Shutdown complete");
+    Ok(())
 }
@@ -0,0 +1,142 @@
+// backend/src/metrics.rs
+//! Métricas Prometheus do backend, expostas em `GET /metrics`.
+//!
+//! - `http_requests_total` e `http_request_duration_seconds`, por rota (o padrão
+//!   registrado, ex. `/api/students/{id}`), método e status
+//! - `db_pool_connections`, atualizado a cada coleta
+//! - `upload_bytes_total`, bytes gravados pelos uploads
+//! - `queue_publish_failures_total`, falhas ao publicar no RabbitMQ
+//! - `outbox_pending`, mensagens da outbox ainda não publicadas
+//! - `outbox_parked`, mensagens estacionadas depois de falhas seguidas
+use actix_web::{
+    body::MessageBody,
+    dev::{ServiceRequest, ServiceResponse},
+    get,
+    web::Data,
+    Error, HttpResponse,
+};
+use prometheus::{
+    Encoder, HistogramOpts, HistogramVec, IntCounter, IntCounterVec, IntGauge, IntGaugeVec, Opts, Registry, TextEncoder,
+};
+use std::{future::Future, sync::OnceLock, time::Instant};
+
+use crate::AppState;
+
+pub struct Metrics {
+    registry: Registry,
+    pub http_requests: IntCounterVec,
+    pub http_duration: HistogramVec,
+    pub db_pool: IntGaugeVec,
+    pub upload_bytes: IntCounter,
+    pub queue_publish_failures: IntCounter,
+    pub outbox_pending: IntGauge,
+    pub outbox_parked: IntGauge,
+}
+
+impl Metrics {
+    fn new() -> Self {
+        let registry = Registry::new();
+
+        let http_requests = IntCounterVec::new(
+            Opts::new("http_requests_total", "HTTP requests by route, method and status"),
+            &["method", "route", "status"],
+        )
+        .expect("valid metric");
+        let http_duration = HistogramVec::new(
+            HistogramOpts::new("http_request_duration_seconds", "HTTP request latency by route and method"),
+            &["method", "route"],
+        )
+        .expect("valid metric");
+        let db_pool = IntGaugeVec::new(
+            Opts::new("db_pool_connections", "Postgres pool connections by state"),
+            &["state"],
+        )
+        .expect("valid metric");
+        let upload_bytes = IntCounter::new("upload_bytes_total", "Bytes written by file uploads").expect("valid metric");
+        let queue_publish_failures = IntCounter::new(
+            "queue_publish_failures_total",
+            "Messages that could not be published to RabbitMQ",
+        )
+        .expect("valid metric");
+        let outbox_pending =
+            IntGauge::new("outbox_pending", "Outbox messages not yet published to RabbitMQ").expect("valid metric");
+        let outbox_parked =
+            IntGauge::new("outbox_parked", "Outbox messages parked after repeated publish failures").expect("valid metric");
+
+        registry.register(Box::new(http_requests.clone())).expect("unique metric");
+        registry.register(Box::new(http_duration.clone())).expect("unique metric");
+        registry.register(Box::new(db_pool.clone())).expect("unique metric");
+        registry.register(Box::new(upload_bytes.clone())).expect("unique metric");
+        registry.register(Box::new(queue_publish_failures.clone())).expect("unique metric");
+        registry.register(Box::new(outbox_pending.clone())).expect("unique metric");
+        registry.register(Box::new(outbox_parked.clone())).expect("unique metric");
+
+        Metrics {
+            registry,
+            http_requests,
+            http_duration,
+            db_pool,
+            upload_bytes,
+            queue_publish_failures,
+            outbox_pending,
+            outbox_parked,
+        }
+    }
+}
+
+/// Métricas do processo, criadas no primeiro uso
+pub fn metrics() -> &'static Metrics {
+    static METRICS: OnceLock<Metrics> = OnceLock::new();
+    METRICS.get_or_init(Metrics::new)
+}
+
+/// Middleware (`App::wrap_fn`) que conta e mede cada requisição. Rotas sem
+/// padrão registrado entram como `unmatched`, para não criar uma série por URL.
+pub fn track<S, B>(req: ServiceRequest, service: &S) -> impl Future<Output = Result<ServiceResponse<B>, Error>>
+where
+    S: actix_web::dev::Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error>,
+    B: MessageBody,
+{
+    let started = Instant::now();
+    let method = req.method().to_string();
+    let response = service.call(req);
+
+    async move {
+        let response = response.await?;
+        let route = response.request().match_pattern().unwrap_or_else(|| "unmatched".to_string());
+        let status = response.status().as_u16().to_string();
+
+        let metrics = metrics();
+        metrics.http_requests.with_label_values(&[&method, &route, &status]).inc();
+        metrics
+            .http_duration
+            .with_label_values(&[&method, &route])
+            .observe(started.elapsed().as_secs_f64());
+
+        Ok(response)
+    }
+}
+
+/// Métricas no formato texto do Prometheus
+#[get("/metrics")]
+async fn metrics_endpoint(data: Data<AppState>) -> HttpResponse {
+    let metrics = metrics();
+    let size = i64::from(data.db.size());
+    let idle = data.db.num_idle() as i64;
+    metrics.db_pool.with_label_values(&["idle"]).set(idle);
+    metrics.db_pool.with_label_values(&["in_use"]).set(size - idle);
+    metrics.db_pool.with_label_values(&["max"]).set(i64::from(data.config.database.max_connections));
+
+    let encoder = TextEncoder::new();
+    let mut buffer = Vec::new();
+    if let Err(error) = encoder.encode(&metrics.registry.gather(), &mut buffer) {
+        log::error!("Failed to encode metrics: {}", error);
+        return HttpResponse::InternalServerError().finish();
+    }
+
+    HttpResponse::Ok().content_type(encoder.format_type()).body(buffer)
+}
+
+pub fn config(cfg: &mut actix_web::web::ServiceConfig) {
+    cfg.service(metrics_endpoint);
+}
@@ -0,0 +1,22 @@
+// backend/src/migrate.rs
+//! Migrations do banco, embutidas no binário a partir de `backend/migrations`.
+//!
+//! São aplicadas por `backend migrate` ou, com `database.run_migrations = true`,
+//! antes de o servidor subir. Os arquivos em `migrations/` são a única fonte do
+//! schema: uma mudança de tabela entra sempre como uma nova migration.
+use sqlx::{migrate::{MigrateError, Migrator}, PgPool};
+
+static MIGRATOR: Migrator = sqlx::migrate!();
+
+/// Aplica as migrations pendentes, em ordem de versão
+pub async fn run(db: &PgPool) -> Result<(), MigrateError> {
+    MIGRATOR.run(db).await
+}
+
+/// Versão e descrição da última migration embutida
+pub fn latest() -> Option<(i64, String)> {
+    MIGRATOR
+        .iter()
+        .last()
+        .map(|migration| (migration.version, migration.description.to_string()))
+}
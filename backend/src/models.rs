@@ -2,9 +2,10 @@
 use serde::{Serialize, Deserialize};
 use sqlx::FromRow;
 use uuid::Uuid;
-use chrono::{NaiveDate, NaiveDateTime, DateTime, Utc};
+use utoipa::ToSchema;
+use chrono::{NaiveDate, NaiveDateTime, NaiveTime, DateTime, Utc};
 
-#[derive(Debug, Serialize, sqlx::FromRow)]
+#[derive(Debug, Serialize, sqlx::FromRow, ToSchema)]
 pub struct UserModel {
     pub id: Uuid,
     pub username: String,
@@ -13,25 +14,27 @@ pub struct UserModel {
     pub users_date: Option<DateTime<Utc>>, // <-- ALTERADO PARA Option
 }
 
-#[derive(Debug, Serialize, Deserialize, FromRow)]
+#[derive(Debug, Serialize, Deserialize, FromRow, ToSchema)]
 pub struct ParentModel {
     pub id: Uuid,
     pub user_id: Option<Uuid>,
     pub name: String,
     pub email: String,
     pub parents_date: Option<DateTime<Utc>>,
+    pub household_id: Option<Uuid>,
 }
 
-#[derive(Debug, Serialize, Deserialize, FromRow)]
+#[derive(Debug, Serialize, Deserialize, FromRow, ToSchema)]
 pub struct GuardianModel {
     pub id: Uuid,
     pub user_id: Option<Uuid>,
     pub name: String,
     pub relationship: String,
     pub guardians_date: Option<DateTime<Utc>>,
+    pub household_id: Option<Uuid>,
 }
 
-#[derive(Debug, Serialize, Deserialize, sqlx::FromRow)]
+#[derive(Debug, Serialize, Deserialize, sqlx::FromRow, ToSchema)]
 pub struct StudentModel {
     pub id: Uuid,
     pub user_id: Option<Uuid>,
@@ -43,9 +46,10 @@ pub struct StudentModel {
     pub parent_id: Option<Uuid>,
     pub group_id: Option<Uuid>,
     pub students_date: Option<DateTime<Utc>>, // <-- IMPORTANTE!
+    pub household_id: Option<Uuid>,
 }
 
-#[derive(Debug, Serialize, Deserialize, FromRow)]
+#[derive(Debug, Serialize, Deserialize, FromRow, ToSchema)]
 pub struct AddressModel {
     pub id: Uuid,
     pub user_id: Option<Uuid>,
@@ -56,9 +60,10 @@ pub struct AddressModel {
     pub city: String,
     pub state: String,
     pub zip_code: String,
+    pub household_id: Option<Uuid>,
 }
 
-#[derive(Debug, Serialize, Deserialize, FromRow)]
+#[derive(Debug, Serialize, Deserialize, FromRow, ToSchema)]
 pub struct PhoneModel {
     pub id: Uuid,
     pub user_id: Option<Uuid>,
@@ -67,9 +72,10 @@ pub struct PhoneModel {
     pub guardian_id: Option<Uuid>,
     pub number: String,
     pub phone_type: Option<String>,
+    pub household_id: Option<Uuid>,
 }
 
-#[derive(Debug, Serialize, Deserialize, FromRow)]
+#[derive(Debug, Serialize, Deserialize, FromRow, ToSchema)]
 pub struct AttendanceModel {
     pub id: Uuid,
     pub student_id: Uuid,
@@ -78,18 +84,19 @@ pub struct AttendanceModel {
     pub notes: Option<String>,
 }
 
-#[derive(Debug, Serialize, Deserialize, sqlx::FromRow)]
-pub struct AttendanceWithStudent {
+/// Presença com o nome do aluno e do grupo, usada na listagem
+#[derive(Debug, sqlx::FromRow, Serialize, ToSchema)]
+pub struct AttendanceWithGroup {
     pub id: Uuid,
     pub student_id: Uuid,
     pub student_name: String,
-    pub student_group_name: Option<String>, // 👈 aqui
+    pub student_group_name: Option<String>,  // Pode ser None se não houver grupo
     pub attendance_date: NaiveDate,
     pub status: String,
     pub notes: Option<String>,
 }
 
-#[derive(Debug, Serialize, Deserialize, FromRow)]
+#[derive(Debug, Serialize, Deserialize, FromRow, ToSchema)]
 pub struct VideoModel {
     pub id: Uuid,
     pub title: String,
@@ -104,7 +111,17 @@ pub struct VideoModel {
     pub video_date: Option<DateTime<Utc>>,
 }
 
-#[derive(Debug, Serialize, Deserialize, FromRow)]
+#[derive(Debug, Serialize, sqlx::FromRow, ToSchema)]
+pub struct VideoSearchHit {
+    #[sqlx(flatten)]
+    #[serde(flatten)]
+    pub video: VideoModel,
+    pub tags: Option<Vec<String>>,
+    pub rank: f32,
+    pub snippet: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, FromRow, ToSchema)]
 pub struct VideoMediaModel {
     pub id: Uuid,
     pub video_id: Uuid,
@@ -112,7 +129,7 @@ pub struct VideoMediaModel {
     pub status: String,
 }
 
-#[derive(Debug, Serialize, Deserialize, FromRow)]
+#[derive(Debug, Serialize, Deserialize, FromRow, ToSchema)]
 pub struct MeusVideoModel {
     pub id: Uuid,
     pub student_id: Uuid,
@@ -121,7 +138,7 @@ pub struct MeusVideoModel {
     pub created_at: Option<DateTime<Utc>>,
 }
 
-#[derive(Debug, Serialize, Deserialize, FromRow)]
+#[derive(Debug, Serialize, Deserialize, FromRow, ToSchema)]
 pub struct PhotoModel {
     pub id: Uuid,
     pub student_id: Uuid,
@@ -130,7 +147,7 @@ pub struct PhotoModel {
     pub created_at: Option<DateTime<Utc>>,
 }
 
-#[derive(Debug, Serialize, Deserialize, FromRow)]
+#[derive(Debug, Serialize, Deserialize, FromRow, ToSchema)]
 pub struct FileMetadataModel {
     pub id: Uuid,
     pub user_id: Option<Uuid>,
@@ -140,7 +157,7 @@ pub struct FileMetadataModel {
     pub uploaded_at: Option<DateTime<Utc>>,
 }
 
-#[derive(Debug, Serialize, Deserialize, FromRow)]
+#[derive(Debug, Serialize, Deserialize, FromRow, ToSchema)]
 pub struct DocumentModel {
     pub id: Uuid,
     pub student_id: Uuid,
@@ -149,7 +166,7 @@ pub struct DocumentModel {
     pub created_at: Option<DateTime<Utc>>,
 }
 
-#[derive(Debug, Serialize, Deserialize, FromRow)]
+#[derive(Debug, Serialize, Deserialize, FromRow, ToSchema)]
 pub struct TaskModel {
     pub id: Uuid,
     pub title: String,
@@ -157,28 +174,49 @@ pub struct TaskModel {
     pub created_at: Option<DateTime<Utc>>,
 }
 
-#[derive(Debug, Serialize, Deserialize, FromRow)]
+#[derive(Debug, Serialize, Deserialize, FromRow, ToSchema)]
 pub struct LogModel {
     pub id: Uuid,
+    /// Autor declarado em `X-User-Id` (não autenticado); mantido mesmo se o
+    /// usuário for removido
     pub user_id: Option<Uuid>,
+    /// `create`, `update`, `delete` ou, nas entradas manuais, o texto enviado
     pub action: String,
     pub description: Option<String>,
     pub timestamp: Option<DateTime<Utc>>,
+    /// `student`, `parent`, `document`, `warning` ou `user`
+    pub entity_type: Option<String>,
+    pub entity_id: Option<Uuid>,
+    /// Campos alterados antes da mudança (registro inteiro na remoção)
+    #[schema(value_type = Option<Object>)]
+    pub before: Option<serde_json::Value>,
+    /// Campos alterados depois da mudança (registro inteiro na criação)
+    #[schema(value_type = Option<Object>)]
+    pub after: Option<serde_json::Value>,
+    pub ip_address: Option<String>,
+    pub request_id: Option<String>,
 }
 
-#[derive(Debug, Serialize, Deserialize, FromRow)]
+#[derive(Debug, Serialize, Deserialize, FromRow, ToSchema)]
 pub struct TagModel {
     pub id: Uuid,
     pub name: String,
 }
 
-#[derive(Debug, Serialize, Deserialize, FromRow)]
+#[derive(Debug, Serialize, Deserialize, FromRow, ToSchema)]
+pub struct TagUsageModel {
+    pub id: Uuid,
+    pub name: String,
+    pub video_count: i64,
+}
+
+#[derive(Debug, Serialize, Deserialize, FromRow, ToSchema)]
 pub struct VideoTagModel {
     pub video_id: Uuid,
     pub tag_id: Uuid,
 }
 
-#[derive(Debug, Serialize, Deserialize, FromRow)]
+#[derive(Debug, Serialize, Deserialize, FromRow, ToSchema)]
 pub struct WarningModel {
     pub id: Uuid,
     pub student_id: Uuid,
@@ -186,15 +224,24 @@ pub struct WarningModel {
     pub warning_date: Option<DateTime<Utc>>,
 }
 
-#[derive(sqlx::FromRow, serde::Serialize)]
+#[derive(sqlx::FromRow, serde::Serialize, ToSchema)]
 pub struct GroupModel {
     pub id: Uuid,
     pub name: String,
     pub description: Option<String>,
     pub created_at: chrono::DateTime<chrono::Utc>,
+    /// Ano da temporada
+    pub season: Option<i32>,
+    /// Faixa de idade que o aluno completa no ano da temporada
+    pub min_age: Option<i32>,
+    pub max_age: Option<i32>,
+    /// Usuário com o papel `coach`
+    pub coach_id: Option<Uuid>,
+    /// Máximo de alunos; `null` para sem limite
+    pub capacity: Option<i32>,
 }
 
-#[derive(Debug, Serialize, Deserialize, FromRow)]
+#[derive(Debug, Serialize, Deserialize, FromRow, ToSchema)]
 pub struct ChatRoomModel {
     pub id: Uuid,
     pub name: Option<String>,
@@ -202,7 +249,7 @@ pub struct ChatRoomModel {
     pub created_at: Option<DateTime<Utc>>,
 }
 
-#[derive(Debug, Serialize, Deserialize, FromRow)]
+#[derive(Debug, Serialize, Deserialize, FromRow, ToSchema)]
 pub struct MessageModel {
     pub id: Uuid,
     pub room_id: Uuid,
@@ -211,7 +258,7 @@ pub struct MessageModel {
     pub sent_at: Option<DateTime<Utc>>,
 }
 
-#[derive(Debug, Serialize, Deserialize, FromRow)]
+#[derive(Debug, Serialize, Deserialize, FromRow, ToSchema)]
 pub struct ScheduleChangeModel {
     pub id: Uuid,
     pub group_id: Option<Uuid>,
@@ -219,4 +266,163 @@ pub struct ScheduleChangeModel {
     pub new_date: Option<NaiveDateTime>,
     pub reason: Option<String>,
     pub created_at: Option<DateTime<Utc>>,
+    /// Horário da sessão alterada; `old_date` é o início previsto dela
+    pub schedule_id: Option<Uuid>,
+}
+
+/// Horário fixo de treino de uma turma num dia da semana
+#[derive(Debug, Clone, Serialize, FromRow, ToSchema)]
+pub struct GroupScheduleModel {
+    pub id: Uuid,
+    pub group_id: Uuid,
+    /// Dia da semana ISO: 1 = segunda ... 7 = domingo
+    pub weekday: i32,
+    pub start_time: NaiveTime,
+    pub end_time: NaiveTime,
+    pub location: Option<String>,
+    pub valid_from: NaiveDate,
+    /// Último dia do horário; `null` para sem fim
+    pub valid_until: Option<NaiveDate>,
+    pub created_at: DateTime<Utc>,
+    /// Última alteração do horário ou de uma mudança de treino dele
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Situação de uma sessão no calendário da turma
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum SessionStatus {
+    Scheduled,
+    Rescheduled,
+    Cancelled,
+}
+
+/// Uma sessão de treino concreta, já com as mudanças de treino aplicadas
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct CalendarSession {
+    pub schedule_id: Uuid,
+    /// Início previsto pelo horário; identifica a sessão mesmo se remarcada
+    pub occurrence: NaiveDateTime,
+    pub starts_at: NaiveDateTime,
+    pub ends_at: NaiveDateTime,
+    pub location: Option<String>,
+    pub status: SessionStatus,
+    /// Mudança de treino aplicada, se houver
+    pub change_id: Option<Uuid>,
+    pub reason: Option<String>,
+    /// Última alteração do horário ou das mudanças dele; vira o SEQUENCE do .ics
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Feed .ics de uma turma ou de uma família; `token` é o segredo da URL
+#[derive(Debug, Serialize, FromRow, ToSchema)]
+pub struct CalendarFeedModel {
+    pub id: Uuid,
+    pub token: String,
+    pub group_id: Option<Uuid>,
+    pub household_id: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+}
+
+/// Totais de presença de um aluno desde `since`
+#[derive(Debug, Serialize, FromRow, ToSchema)]
+pub struct AttendanceSummary {
+    pub since: NaiveDate,
+    pub total: i64,
+    pub present: i64,
+    pub absent: i64,
+    pub last_date: Option<NaiveDate>,
+}
+
+/// Seção `attendance` do perfil do aluno
+#[derive(Debug, Serialize, ToSchema)]
+pub struct StudentAttendance {
+    #[serde(flatten)]
+    pub summary: AttendanceSummary,
+    /// Últimos registros, do mais recente para o mais antigo
+    pub recent: Vec<AttendanceModel>,
+}
+
+/// Advertências de um aluno (perfil do aluno)
+#[derive(Debug, Serialize, FromRow, ToSchema)]
+pub struct WarningSummary {
+    pub count: i64,
+    pub last_warning_date: Option<DateTime<Utc>>,
+}
+
+/// Arquivos ligados a um aluno (perfil do aluno)
+#[derive(Debug, Serialize, FromRow, ToSchema)]
+pub struct MediaCounts {
+    pub photos: i64,
+    pub documents: i64,
+    /// Vídeos enviados pelo aluno (`meusvideos`)
+    pub submissions: i64,
+}
+
+/// Vínculo entre aluno e responsável (`student_guardians`)
+#[derive(Debug, Serialize, FromRow, ToSchema)]
+pub struct StudentGuardianModel {
+    pub id: Uuid,
+    pub student_id: Uuid,
+    pub guardian_id: Uuid,
+    pub relationship: String,
+    pub can_pick_up: bool,
+    pub emergency_priority: Option<i32>,
+    pub created_at: DateTime<Utc>,
+}
+
+/// Responsável de um aluno, com os dados do vínculo
+#[derive(Debug, Serialize, FromRow, ToSchema)]
+pub struct LinkedGuardian {
+    pub guardian_id: Uuid,
+    pub user_id: Option<Uuid>,
+    pub name: String,
+    /// Parentesco com este aluno
+    pub relationship: String,
+    pub can_pick_up: bool,
+    pub emergency_priority: Option<i32>,
+}
+
+/// Aluno de um responsável, com os dados do vínculo
+#[derive(Debug, Serialize, FromRow, ToSchema)]
+pub struct LinkedStudent {
+    pub student_id: Uuid,
+    pub name: String,
+    pub group_id: Option<Uuid>,
+    pub relationship: String,
+    pub can_pick_up: bool,
+    pub emergency_priority: Option<i32>,
+}
+
+/// Família: agrupa pais, responsáveis e irmãos, com contatos compartilhados
+#[derive(Debug, Serialize, FromRow, ToSchema)]
+pub struct HouseholdModel {
+    pub id: Uuid,
+    pub name: String,
+    pub created_at: DateTime<Utc>,
+}
+
+/// Integrantes da família e os contatos ligados a ela em `household_id`
+#[derive(Debug, Serialize, ToSchema)]
+pub struct HouseholdMembers {
+    pub parents: Vec<ParentModel>,
+    pub guardians: Vec<GuardianModel>,
+    pub students: Vec<StudentModel>,
+    pub addresses: Vec<AddressModel>,
+    pub phones: Vec<PhoneModel>,
+}
+
+/// Turma sugerida para um aluno numa temporada (`/groups/assignments`)
+#[derive(Debug, Serialize, ToSchema)]
+pub struct GroupAssignment {
+    pub student_id: Uuid,
+    pub student_name: String,
+    pub birth_date: Option<NaiveDate>,
+    /// Idade que o aluno completa no ano da temporada
+    pub season_age: Option<i32>,
+    /// `null` quando nenhuma turma serve; o motivo vem em `reason`
+    pub group_id: Option<Uuid>,
+    pub group_name: Option<String>,
+    /// `no_birth_date`, `no_matching_group` ou `groups_full`
+    pub reason: Option<String>,
 }
@@ -0,0 +1,700 @@
+// backend/src/openapi.rs
+//! Documento OpenAPI 3 gerado a partir dos handlers (`#[utoipa::path]`) e dos
+//! tipos de `schema.rs` e `models.rs`. Servido em `/api/openapi.json`, com o
+//! Swagger UI em `/api/docs/`.
+//!
+//! As respostas de sucesso usam o envelope `{"status": "success", <chave>: ...}`;
+//! os tipos abaixo existem só para descrever esses envelopes.
+use actix_web::web::ServiceConfig;
+use chrono::NaiveDate;
+use utoipa::{openapi::Deprecated, Modify, OpenApi, ToSchema};
+use utoipa_swagger_ui::SwaggerUi;
+use uuid::Uuid;
+
+use crate::{
+    error::{ErrorBody, FieldError},
+    models::{
+        AddressModel,
+        AttendanceModel,
+        AttendanceSummary,
+        AttendanceWithGroup,
+        CalendarFeedModel,
+        CalendarSession,
+        ChatRoomModel,
+        DocumentModel,
+        FileMetadataModel,
+        GroupAssignment,
+        GroupModel,
+        GroupScheduleModel,
+        GuardianModel,
+        HouseholdMembers,
+        HouseholdModel,
+        LinkedGuardian,
+        LinkedStudent,
+        LogModel,
+        MediaCounts,
+        MessageModel,
+        MeusVideoModel,
+        ParentModel,
+        PhoneModel,
+        PhotoModel,
+        ScheduleChangeModel,
+        SessionStatus,
+        StudentAttendance,
+        StudentGuardianModel,
+        StudentModel,
+        TagModel,
+        TagUsageModel,
+        TaskModel,
+        UserModel,
+        VideoMediaModel,
+        VideoModel,
+        VideoSearchHit,
+        VideoTagModel,
+        WarningModel,
+        WarningSummary,
+    },
+    schema,
+    services::{
+        self,
+        health::{ComponentHealth, Readiness},
+        video::uploads::FinishUpload,
+    },
+};
+
+// `{"status": "success", <chave>: <registro>}`
+macro_rules! envelope {
+    ($($name:ident { $key:ident: $model:ident },)*) => {$(
+        #[derive(ToSchema)]
+        #[allow(dead_code)]
+        pub struct $name {
+            status: String,
+            $key: $model,
+        }
+    )*};
+}
+
+// `{"status": "success", <chave>: [<registros>]}`, para as listas sem paginação
+macro_rules! list {
+    ($($name:ident { $key:ident: $model:ident },)*) => {$(
+        #[derive(ToSchema)]
+        #[allow(dead_code)]
+        pub struct $name {
+            status: String,
+            $key: Vec<$model>,
+        }
+    )*};
+}
+
+// Envelope de `Page::to_json`
+macro_rules! page {
+    ($($name:ident { $key:ident: $model:ident },)*) => {$(
+        #[derive(ToSchema)]
+        #[allow(dead_code)]
+        pub struct $name {
+            status: String,
+            $key: Vec<$model>,
+            /// Quantidade de itens nesta página
+            results: usize,
+            /// Total de registros que atendem aos filtros
+            total: i64,
+            /// Cursor da próxima página; `null` na última
+            next_cursor: Option<String>,
+        }
+    )*};
+}
+
+envelope! {
+    AddressResponse { address: AddressModel },
+    AllVideoResponse { all_video: VideoMediaModel },
+    AttendanceResponse { attendance: AttendanceModel },
+    ChatRoomResponse { chat_room: ChatRoomModel },
+    DocumentResponse { document: DocumentModel },
+    FileMetadataResponse { file_metadata: FileMetadataModel },
+    GroupResponse { group: GroupModel },
+    GroupScheduleResponse { schedule: GroupScheduleModel },
+    GuardianResponse { guardian: GuardianModel },
+    HouseholdMembersResponse { members: HouseholdMembers },
+    HouseholdResponse { household: HouseholdModel },
+    LegacyVideoResponse { video: VideoMediaModel },
+    LogResponse { log: LogModel },
+    MediaResponse { media: VideoMediaModel },
+    MessageResponse { message: MessageModel },
+    MeusvideoResponse { meusvideo: MeusVideoModel },
+    ParentResponse { parent: ParentModel },
+    PhoneResponse { phone: PhoneModel },
+    PhotoResponse { photo: PhotoModel },
+    PlayeResponse { playe: VideoModel },
+    ScheduleChangeResponse { schedule_change: ScheduleChangeModel },
+    StudentGuardianResponse { student_guardian: StudentGuardianModel },
+    StudentResponse { student: StudentModel },
+    SubmissionResponse { submission: MeusVideoModel },
+    TagResponse { tag: TagModel },
+    TaskResponse { task: TaskModel },
+    UserResponse { user: UserModel },
+    VideoResponse { video: VideoModel },
+    VideomediaResponse { videomedia: VideoMediaModel },
+    VideotagResponse { videotag: VideoTagModel },
+    WarningResponse { warning: WarningModel },
+}
+
+list! {
+    GroupScheduleList { schedules: GroupScheduleModel },
+    LinkedGuardianList { guardians: LinkedGuardian },
+    LinkedStudentList { students: LinkedStudent },
+    TagList { tags: TagModel },
+    TagUsageList { tags: TagUsageModel },
+    VideoList { videos: VideoModel },
+    VideotagList { videotags: VideoTagModel },
+}
+
+page! {
+    AddressesPage { addresses: AddressModel },
+    AllVideosPage { all_videos: VideoMediaModel },
+    AttendancesPage { attendances: AttendanceWithGroup },
+    ChatRoomsPage { chat_rooms: ChatRoomModel },
+    DocumentsPage { documents: DocumentModel },
+    FileMetadatasPage { file_metadatas: FileMetadataModel },
+    GroupsPage { groups: GroupModel },
+    GuardiansPage { guardians: GuardianModel },
+    HouseholdsPage { households: HouseholdModel },
+    LogsPage { logs: LogModel },
+    MediaPage { media: VideoMediaModel },
+    MessagesPage { messages: MessageModel },
+    MeusvideosPage { meusvideos: MeusVideoModel },
+    ParentsPage { parents: ParentModel },
+    PhonesPage { phones: PhoneModel },
+    PhotosPage { photos: PhotoModel },
+    ScheduleChangesPage { schedule_changes: ScheduleChangeModel },
+    StudentsPage { students: StudentModel },
+    SubmissionsPage { submissions: MeusVideoModel },
+    TagsPage { tags: TagModel },
+    TasksPage { tasks: TaskModel },
+    UsersPage { users: UserModel },
+    VideomediasPage { videomedias: VideoMediaModel },
+    VideosPage { videos: VideoModel },
+    WarningsPage { warnings: WarningModel },
+}
+
+#[derive(ToSchema)]
+#[allow(dead_code)]
+pub struct StatusMessage {
+    status: String,
+    message: String,
+}
+
+#[derive(ToSchema)]
+#[allow(dead_code)]
+pub struct UploadResponse {
+    status: String,
+    message: String,
+    /// URL pública do arquivo salvo
+    file_url: Option<String>,
+}
+
+#[derive(ToSchema)]
+#[allow(dead_code)]
+pub struct DocumentUploadResponse {
+    status: String,
+    message: String,
+    filename: String,
+}
+
+#[derive(ToSchema)]
+#[allow(dead_code)]
+pub struct ChunkResponse {
+    status: String,
+    chunk_index: usize,
+}
+
+#[derive(ToSchema)]
+#[allow(dead_code)]
+pub struct SlugRedirect {
+    status: String,
+    /// Slug atual do vídeo
+    slug: String,
+}
+
+#[derive(ToSchema)]
+#[allow(dead_code)]
+pub struct VideoSearchResponse {
+    status: String,
+    results: usize,
+    videos: Vec<VideoSearchHit>,
+}
+
+/// Distribuição dos alunos nas turmas de uma temporada
+#[derive(ToSchema)]
+#[allow(dead_code)]
+pub struct GroupAssignmentsResponse {
+    status: String,
+    season: i32,
+    /// Alunos que mudaram de turma (só em `POST`)
+    assigned: Option<i64>,
+    assignments: Vec<GroupAssignment>,
+}
+
+/// Calendário de uma turma
+#[derive(ToSchema)]
+#[allow(dead_code)]
+pub struct CalendarResponse {
+    status: String,
+    from: NaiveDate,
+    to: NaiveDate,
+    sessions: Vec<CalendarSession>,
+}
+
+/// Feed .ics de uma turma ou família
+#[derive(ToSchema)]
+#[allow(dead_code)]
+pub struct CalendarFeedResponse {
+    status: String,
+    /// Caminho do .ics, com o token; para assinar no app de calendário
+    path: String,
+    calendar_feed: CalendarFeedModel,
+}
+
+/// Contatos do aluno, do seu pai ou mãe e da sua família
+#[derive(ToSchema)]
+#[allow(dead_code)]
+pub struct StudentContacts {
+    addresses: Vec<AddressModel>,
+    phones: Vec<PhoneModel>,
+}
+
+/// Perfil do aluno; as seções fora de `include` não aparecem
+#[derive(ToSchema)]
+#[allow(dead_code)]
+pub struct StudentProfile {
+    student: StudentModel,
+    /// `null` se o aluno não tem `parent_id`
+    parent: Option<ParentModel>,
+    /// `null` se o aluno não tem `group_id`
+    group: Option<GroupModel>,
+    contacts: Option<StudentContacts>,
+    /// Contatos de emergência primeiro, em ordem de prioridade
+    guardians: Option<Vec<LinkedGuardian>>,
+    attendance: Option<StudentAttendance>,
+    warnings: Option<WarningSummary>,
+    media: Option<MediaCounts>,
+}
+
+#[derive(ToSchema)]
+#[allow(dead_code)]
+pub struct StudentProfileResponse {
+    status: String,
+    profile: StudentProfile,
+}
+
+/// Formulário multipart com um arquivo
+#[derive(ToSchema)]
+#[allow(dead_code)]
+pub struct FileUpload {
+    #[schema(value_type = String, format = Binary)]
+    file: Vec<u8>,
+}
+
+/// Formulário multipart de `POST /documents`
+#[derive(ToSchema)]
+#[allow(dead_code)]
+pub struct DocumentUpload {
+    student_id: Uuid,
+    doc_type: String,
+    #[schema(value_type = String, format = Binary)]
+    file: Vec<u8>,
+}
+
+#[derive(OpenApi)]
+#[openapi(
+    info(title = "API da escolinha", description = "Rotas do backend; todas ficam sob `/api`."),
+    servers((url = "/api")),
+    paths(
+        services::addresses::create_address,
+        services::addresses::get_all_addresses,
+        services::addresses::get_address_by_id,
+        services::addresses::update_address_by_id,
+        services::addresses::delete_address_by_id,
+        services::attendances::create_attendance,
+        services::attendances::get_all_attendances,
+        services::attendances::get_attendance_by_id,
+        services::attendances::update_attendance_by_id,
+        services::attendances::delete_attendance_by_id,
+        services::chat_rooms::create_chat_room,
+        services::chat_rooms::get_all_chat_rooms,
+        services::chat_rooms::get_chat_room_by_id,
+        services::chat_rooms::update_chat_room_by_id,
+        services::chat_rooms::delete_chat_room_by_id,
+        services::documents::upload_document,
+        services::documents::create_document,
+        services::documents::get_all_documents,
+        services::documents::get_document_by_id,
+        services::documents::delete_document_by_id,
+        services::documents::update_document_by_id,
+        services::file_metadatas::upload_file,
+        services::file_metadatas::create_file_metadata,
+        services::file_metadatas::get_all_file_metadatas,
+        services::file_metadatas::get_file_metadata_by_id,
+        services::file_metadatas::update_file_metadata_by_id,
+        services::file_metadatas::delete_file_metadata_by_id,
+        services::groups::create_group,
+        services::groups::get_all_groups,
+        services::groups::get_group_by_id,
+        services::groups::update_group_by_id,
+        services::groups::delete_group_by_id,
+        services::groups::suggest_group_assignments,
+        services::groups::apply_group_assignments,
+        services::group_schedules::get_group_schedules,
+        services::group_schedules::create_group_schedule,
+        services::group_schedules::update_group_schedule,
+        services::group_schedules::delete_group_schedule,
+        services::group_schedules::get_group_calendar,
+        services::calendar_feeds::create_group_feed,
+        services::calendar_feeds::delete_group_feed,
+        services::calendar_feeds::create_household_feed,
+        services::calendar_feeds::delete_household_feed,
+        services::calendar_feeds::get_calendar_feed,
+        services::guardians::create_guardian,
+        services::guardians::get_all_guardians,
+        services::guardians::get_guardian_by_id,
+        services::guardians::update_guardian_by_id,
+        services::guardians::delete_guardian_by_id,
+        services::households::create_household,
+        services::households::get_all_households,
+        services::households::get_household_by_id,
+        services::households::get_household_members,
+        services::households::update_household_by_id,
+        services::households::delete_household_by_id,
+        services::health::health_checker,
+        services::health::health_live,
+        services::health::health_ready,
+        services::logs::create_log,
+        services::logs::get_all_logs,
+        services::logs::get_log_by_id,
+        services::messages::create_message,
+        services::messages::get_all_messages,
+        services::messages::get_message_by_id,
+        services::messages::update_message_by_id,
+        services::messages::delete_message_by_id,
+        services::parents::create_parent,
+        services::parents::get_all_parents,
+        services::parents::get_parent_by_id,
+        services::parents::update_parent_by_id,
+        services::parents::delete_parent_by_id,
+        services::phones::create_phone,
+        services::phones::get_all_phones,
+        services::phones::get_phone_by_id,
+        services::phones::update_phone_by_id,
+        services::phones::delete_phone_by_id,
+        services::photos::create_photo,
+        services::photos::get_all_photos,
+        services::photos::get_photo_by_id,
+        services::photos::update_photo_by_id,
+        services::photos::delete_photo_by_id,
+        services::photos::upload_image,
+        services::schedule_changes::create_schedule_change,
+        services::schedule_changes::get_schedule_changes,
+        services::schedule_changes::update_schedule_change,
+        services::schedule_changes::delete_schedule_change,
+        services::student_guardians::get_student_guardians,
+        services::student_guardians::link_guardian,
+        services::student_guardians::unlink_guardian,
+        services::student_guardians::get_guardian_students,
+        services::students::create_student,
+        services::students::get_all_students,
+        services::students::get_student_by_id,
+        services::students::get_student_profile,
+        services::students::update_student_by_id,
+        services::students::delete_student_by_id,
+        services::tags::create_tag,
+        services::tags::get_all_tags,
+        services::tags::get_tags_usage,
+        services::tags::get_tag_by_id,
+        services::tags::update_tag_by_id,
+        services::tags::get_tag_videos,
+        services::tags::merge_tag,
+        services::tags::delete_tag_by_id,
+        services::tasks::create_task,
+        services::tasks::get_all_tasks,
+        services::tasks::get_task_by_id,
+        services::tasks::update_task_by_id,
+        services::tasks::delete_task_by_id,
+        services::users::create_user,
+        services::users::get_all_users,
+        services::users::get_user_by_id,
+        services::users::update_user_by_id,
+        services::users::delete_user_by_id,
+        services::video::legacy::upload_file,
+        services::video::legacy::upload_all_videos_file,
+        services::video::legacy::create_all_video,
+        services::video::legacy::get_all_all_videos,
+        services::video::legacy::get_all_video_by_id,
+        services::video::legacy::update_all_video_by_id,
+        services::video::legacy::delete_all_video_by_id,
+        services::video::legacy::create_videomedia,
+        services::video::legacy::get_all_videomedias,
+        services::video::legacy::get_videomedia_by_id,
+        services::video::legacy::update_videomedia_by_id,
+        services::video::legacy::delete_videomedia_by_id,
+        services::video::legacy::upload_chunk,
+        services::video::legacy::finish_upload,
+        services::video::legacy::create_playe,
+        services::video::legacy::create_meusvideo,
+        services::video::legacy::get_all_meusvideos,
+        services::video::legacy::get_meusvideo_by_id,
+        services::video::legacy::update_meusvideo_by_id,
+        services::video::legacy::delete_meusvideo_by_id,
+        services::video::media::get_video_media,
+        services::video::media::create_video_media,
+        services::video::media::get_video_media_by_id,
+        services::video::media::update_video_media_by_id,
+        services::video::media::delete_video_media_by_id,
+        services::video::submissions::create_submission,
+        services::video::submissions::get_all_submissions,
+        services::video::submissions::get_student_submissions,
+        services::video::submissions::get_submission_by_id,
+        services::video::submissions::update_submission_by_id,
+        services::video::submissions::delete_submission_by_id,
+        services::video::uploads::upload_video_file,
+        services::video::uploads::upload_video_chunk,
+        services::video::uploads::complete_video_upload,
+        services::video::videos::create_video,
+        services::video::videos::get_all_videos,
+        services::video::videos::search_videos,
+        services::video::videos::get_video_by_id,
+        services::video::videos::get_video_by_slug,
+        services::video::videos::update_video_by_id,
+        services::video::videos::delete_video_by_id,
+        services::videotags::create_videotag,
+        services::videotags::get_all_videotags,
+        services::videotags::get_videotag_by_id,
+        services::videotags::update_videotag_by_id,
+        services::videotags::delete_videotag_by_id,
+        services::videotags::get_video_tags,
+        services::videotags::set_video_tags,
+        services::warnings::create_warning,
+        services::warnings::get_all_warnings,
+        services::warnings::get_warning_by_id,
+        services::warnings::update_warning_by_id,
+        services::warnings::delete_warning_by_id,
+    ),
+    components(schemas(
+        ErrorBody,
+        FieldError,
+        AddressModel,
+        AttendanceModel,
+        ChatRoomModel,
+        DocumentModel,
+        FileMetadataModel,
+        GroupModel,
+        GuardianModel,
+        LogModel,
+        MessageModel,
+        MeusVideoModel,
+        ParentModel,
+        PhoneModel,
+        PhotoModel,
+        ScheduleChangeModel,
+        StudentModel,
+        TagModel,
+        TagUsageModel,
+        TaskModel,
+        UserModel,
+        VideoMediaModel,
+        VideoModel,
+        VideoSearchHit,
+        VideoTagModel,
+        WarningModel,
+        AttendanceWithGroup,
+        AttendanceSummary,
+        StudentAttendance,
+        WarningSummary,
+        MediaCounts,
+        StudentGuardianModel,
+        LinkedGuardian,
+        LinkedStudent,
+        HouseholdModel,
+        HouseholdMembers,
+        GroupAssignment,
+        GroupScheduleModel,
+        CalendarSession,
+        SessionStatus,
+        CalendarFeedModel,
+        schema::AssignGroupsSchema,
+        schema::CreateAddressSchema,
+        schema::CreateAttendanceSchema,
+        schema::CreateChatRoomSchema,
+        schema::CreateFileMetadataSchema,
+        schema::CreateGroupSchema,
+        schema::CreateGroupScheduleSchema,
+        schema::CreateGuardianSchema,
+        schema::CreateHouseholdSchema,
+        schema::CreateLogSchema,
+        schema::CreateMessageSchema,
+        schema::CreateMeusVideoSchema,
+        schema::CreateParentSchema,
+        schema::CreatePhoneSchema,
+        schema::CreatePhotoSchema,
+        schema::CreateRenditionSchema,
+        schema::CreateScheduleChangeSchema,
+        schema::CreateStudentSchema,
+        schema::CreateTagSchema,
+        schema::CreateTaskSchema,
+        schema::CreateUserSchema,
+        schema::CreateVideoMediaSchema,
+        schema::CreateVideoSchema,
+        schema::CreateVideoTagSchema,
+        schema::CreateWarningSchema,
+        schema::LinkGuardianSchema,
+        schema::MergeTagSchema,
+        schema::SetVideoTagsSchema,
+        schema::UpdateAddressSchema,
+        schema::UpdateAttendanceSchema,
+        schema::UpdateChatRoomSchema,
+        schema::UpdateDocumentSchema,
+        schema::UpdateFileMetadataSchema,
+        schema::UpdateGroupSchema,
+        schema::UpdateGroupScheduleSchema,
+        schema::UpdateGuardianSchema,
+        schema::UpdateHouseholdSchema,
+        schema::UpdateMessageSchema,
+        schema::UpdateMeusVideoSchema,
+        schema::UpdateParentSchema,
+        schema::UpdatePhoneSchema,
+        schema::UpdatePhotoSchema,
+        schema::UpdateScheduleChangeSchema,
+        schema::UpdateStudentSchema,
+        schema::UpdateTagSchema,
+        schema::UpdateTaskSchema,
+        schema::UpdateUserSchema,
+        schema::UpdateVideoMediaSchema,
+        schema::UpdateVideoSchema,
+        schema::UpdateVideoTagSchema,
+        schema::UpdateWarningSchema,
+        FinishUpload,
+        AddressResponse,
+        AllVideoResponse,
+        AttendanceResponse,
+        ChatRoomResponse,
+        DocumentResponse,
+        FileMetadataResponse,
+        GroupResponse,
+        GroupScheduleResponse,
+        GuardianResponse,
+        HouseholdMembersResponse,
+        HouseholdResponse,
+        LegacyVideoResponse,
+        LogResponse,
+        MediaResponse,
+        MessageResponse,
+        MeusvideoResponse,
+        ParentResponse,
+        PhoneResponse,
+        PhotoResponse,
+        PlayeResponse,
+        ScheduleChangeResponse,
+        StudentGuardianResponse,
+        StudentResponse,
+        SubmissionResponse,
+        TagResponse,
+        TaskResponse,
+        UserResponse,
+        VideoResponse,
+        VideomediaResponse,
+        VideotagResponse,
+        WarningResponse,
+        GroupScheduleList,
+        LinkedGuardianList,
+        LinkedStudentList,
+        TagList,
+        TagUsageList,
+        VideoList,
+        VideotagList,
+        AddressesPage,
+        AllVideosPage,
+        AttendancesPage,
+        ChatRoomsPage,
+        DocumentsPage,
+        FileMetadatasPage,
+        GroupsPage,
+        GuardiansPage,
+        HouseholdsPage,
+        LogsPage,
+        MediaPage,
+        MessagesPage,
+        MeusvideosPage,
+        ParentsPage,
+        PhonesPage,
+        PhotosPage,
+        ScheduleChangesPage,
+        StudentsPage,
+        SubmissionsPage,
+        TagsPage,
+        TasksPage,
+        UsersPage,
+        VideomediasPage,
+        VideosPage,
+        WarningsPage,
+        StatusMessage,
+        UploadResponse,
+        DocumentUploadResponse,
+        ChunkResponse,
+        SlugRedirect,
+        VideoSearchResponse,
+        GroupAssignmentsResponse,
+        CalendarResponse,
+        CalendarFeedResponse,
+        StudentContacts,
+        StudentProfile,
+        StudentProfileResponse,
+        FileUpload,
+        DocumentUpload,
+        ComponentHealth,
+        Readiness,
+    )),
+    tags(
+        (name = "students", description = "Alunos"),
+        (name = "parents", description = "Pais"),
+        (name = "guardians", description = "Responsáveis"),
+        (name = "households", description = "Famílias"),
+        (name = "users", description = "Usuários"),
+        (name = "addresses", description = "Endereços"),
+        (name = "phones", description = "Telefones"),
+        (name = "groups", description = "Turmas e mudanças de treino"),
+        (name = "attendances", description = "Presenças"),
+        (name = "warnings", description = "Advertências"),
+        (name = "videos", description = "Vídeos, renditions e uploads"),
+        (name = "submissions", description = "Vídeos enviados por alunos"),
+        (name = "tags", description = "Tags e marcação de vídeos"),
+        (name = "documents", description = "Documentos dos alunos"),
+        (name = "photos", description = "Fotos"),
+        (name = "files", description = "Metadados de arquivos"),
+        (name = "chat", description = "Salas e mensagens"),
+        (name = "tasks", description = "Tarefas"),
+        (name = "logs", description = "Auditoria das alterações (somente inserção)"),
+        (name = "health", description = "Verificação de saúde"),
+        (name = "legacy", description = "Rotas antigas, depreciadas"),
+    ),
+    modifiers(&DeprecateLegacy)
+)]
+pub struct ApiDoc;
+
+// Marca como depreciadas as operações da tag `legacy`
+struct DeprecateLegacy;
+
+impl Modify for DeprecateLegacy {
+    fn modify(&self, openapi: &mut utoipa::openapi::OpenApi) {
+        for path in openapi.paths.paths.values_mut() {
+            for operation in path.operations.values_mut() {
+                if operation.tags.as_ref().is_some_and(|tags| tags.iter().any(|tag| tag == "legacy")) {
+                    operation.deprecated = Some(Deprecated::True);
+                }
+            }
+        }
+    }
+}
+
+/// Registra `/api/openapi.json` e o Swagger UI. Precisa vir antes do escopo `/api`
+/// dos serviços, que responde 404 para qualquer rota que não conheça.
+pub fn config(cfg: &mut ServiceConfig) {
+    cfg.service(SwaggerUi::new("/api/docs/{_:.*}").url("/api/openapi.json", ApiDoc::openapi()));
+}
@@ -0,0 +1,282 @@
+// backend/src/outbox.rs
+//! Outbox das mensagens publicadas no RabbitMQ.
+//!
+//! O handler grava a mensagem em `outbox` com [`enqueue`], na mesma transação
+//! da alteração que a gera: ou as duas ficam, ou nenhuma. O [`Relay`] publica
+//! as pendentes em ordem, esperando a confirmação do broker, e só então marca
+//! `sent_at`. Se o processo cair entre a publicação e a marcação, a mensagem sai
+//! de novo: a entrega é pelo menos uma vez, e o consumidor deve tolerar repetidas.
+//!
+//! O relay reserva um lote (`claimed_until`, com `FOR UPDATE SKIP LOCKED`) e
+//! grava a reserva antes de publicar, então várias instâncias do backend podem
+//! rodar o relay ao mesmo tempo sem segurar locks durante a chamada ao broker.
+//! Uma reserva de um processo que caiu expira sozinha depois de [`CLAIM_LEASE`].
+//!
+//! Cada falha incrementa `attempts` e grava `last_error`; depois de
+//! `outbox.max_attempts` falhas a mensagem é estacionada (`parked_at`) e as
+//! seguintes voltam a sair. Para reenviar, basta limpar `parked_at` e `attempts`.
+use app_config::OutboxConfig;
+use serde_json::Value;
+use sqlx::{Pool, Postgres, Transaction};
+use std::{
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Mutex,
+    },
+    time::{Duration, Instant},
+};
+use tokio::{sync::Notify, task::JoinHandle};
+use tracing::Instrument;
+
+use crate::{
+    error::ApiError,
+    metrics::metrics,
+    publisher::{Job, JobPublisher},
+};
+
+/// Tempo máximo esperando o lote em andamento no desligamento
+const CLOSE_TIMEOUT: Duration = Duration::from_secs(10);
+/// Intervalo entre as limpezas das mensagens já publicadas
+const PURGE_INTERVAL: Duration = Duration::from_secs(600);
+/// Validade da reserva de um lote; passado o prazo, outro relay pode pegá-lo
+const CLAIM_LEASE: Duration = Duration::from_secs(300);
+
+/// Grava a mensagem para o relay publicar depois do commit
+pub async fn enqueue(
+    tx: &mut Transaction<'_, Postgres>,
+    routing_key: &str,
+    payload: &Value,
+    request_id: Option<&str>,
+) -> Result<(), ApiError> {
+    sqlx::query("INSERT INTO outbox (routing_key, payload, request_id) VALUES ($1, $2, $3)")
+        .bind(routing_key)
+        .bind(payload)
+        .bind(request_id)
+        .execute(&mut *tx)
+        .await?;
+    Ok(())
+}
+
+#[derive(sqlx::FromRow)]
+struct Pending {
+    id: i64,
+    routing_key: String,
+    payload: Value,
+    request_id: Option<String>,
+}
+
+impl Pending {
+    fn into_job(self) -> (i64, Job) {
+        let job = Job { routing_key: self.routing_key, payload: self.payload, request_id: self.request_id };
+        (self.id, job)
+    }
+}
+
+pub struct Relay {
+    db: Pool<Postgres>,
+    publisher: Arc<dyn JobPublisher>,
+    config: OutboxConfig,
+    /// Acorda o relay (mensagem nova ou desligamento)
+    wake: Notify,
+    shutting_down: AtomicBool,
+    task: Mutex<Option<JoinHandle<()>>>,
+}
+
+impl Relay {
+    /// Cria o relay e inicia a tarefa que publica as pendentes
+    pub fn start(db: Pool<Postgres>, publisher: Arc<dyn JobPublisher>, config: OutboxConfig) -> Arc<Self> {
+        let relay = Arc::new(Relay {
+            db,
+            publisher,
+            config,
+            wake: Notify::new(),
+            shutting_down: AtomicBool::new(false),
+            task: Mutex::new(None),
+        });
+        let task = tokio::spawn(Arc::clone(&relay).run());
+        *relay.task.lock().expect("task lock poisoned") = Some(task);
+        relay
+    }
+
+    /// Avisa que há mensagens novas; chamar depois do commit
+    pub fn notify(&self) {
+        self.wake.notify_one();
+    }
+
+    /// Termina o lote em andamento e para o relay; o que ficar pendente sai no
+    /// próximo início
+    pub async fn close(&self) {
+        self.shutting_down.store(true, Ordering::SeqCst);
+        self.wake.notify_one();
+
+        let task = self.task.lock().expect("task lock poisoned").take();
+        if let Some(task) = task {
+            if tokio::time::timeout(CLOSE_TIMEOUT, task).await.is_err() {
+                tracing::warn!("Timed out stopping the outbox relay");
+            }
+        }
+    }
+
+    fn is_shutting_down(&self) -> bool {
+        self.shutting_down.load(Ordering::SeqCst)
+    }
+
+    async fn run(self: Arc<Self>) {
+        let poll_interval = Duration::from_millis(self.config.poll_interval_ms);
+        let mut last_purge: Option<Instant> = None;
+
+        while !self.is_shutting_down() {
+            // Sem conexão não adianta tentar; as mensagens esperam na tabela
+            if self.publisher.is_connected() {
+                match self.relay_batch().await {
+                    // Lote cheio: provavelmente há mais, segue sem esperar
+                    Ok(sent) if sent == self.config.batch_size as usize => continue,
+                    Ok(_) => {}
+                    Err(error) => tracing::error!(error = %error, "Outbox relay failed"),
+                }
+            }
+
+            if last_purge.is_none_or(|at| at.elapsed() >= PURGE_INTERVAL) {
+                last_purge = Some(Instant::now());
+                if let Err(error) = self.purge().await {
+                    tracing::warn!(error = %error, "Failed to purge sent outbox messages");
+                }
+            }
+            self.update_pending().await;
+
+            tokio::select! {
+                _ = tokio::time::sleep(poll_interval) => {}
+                _ = self.wake.notified() => {}
+            }
+        }
+    }
+
+    /// Publica um lote em ordem e devolve quantas mensagens saíram. Para na
+    /// primeira falha, para não passar uma mensagem na frente da anterior; a
+    /// reserva das que ficaram para trás é devolvida.
+    async fn relay_batch(&self) -> Result<usize, sqlx::Error> {
+        let pending = self.claim().await?;
+        let claimed: Vec<i64> = pending.iter().map(|message| message.id).collect();
+
+        let (mut tried, mut sent) = (0, 0);
+        for (id, job) in pending.into_iter().map(Pending::into_job) {
+            tried += 1;
+            let span = tracing::info_span!("outbox_publish", outbox_id = id, request_id = job.request_id.as_deref());
+            match self.publisher.publish(&job).instrument(span.clone()).await {
+                Ok(()) => {
+                    span.in_scope(|| tracing::info!("Outbox message published"));
+                    sqlx::query(
+                        "UPDATE outbox SET sent_at = NOW(), attempts = attempts + 1, claimed_until = NULL WHERE id = $1",
+                    )
+                    .bind(id)
+                    .execute(&self.db)
+                    .await?;
+                    sent += 1;
+                }
+                Err(error) => {
+                    metrics().queue_publish_failures.inc();
+                    let (attempts, parked): (i32, bool) = sqlx::query_as(
+                        r#"
+                        UPDATE outbox
+                        SET attempts = attempts + 1,
+                            last_error = $2,
+                            claimed_until = NULL,
+                            parked_at = CASE WHEN attempts + 1 >= $3 THEN NOW() END
+                        WHERE id = $1
+                        RETURNING attempts, parked_at IS NOT NULL
+                        "#,
+                    )
+                    .bind(id)
+                    .bind(error.to_string())
+                    .bind(self.config.max_attempts as i32)
+                    .fetch_one(&self.db)
+                    .await?;
+                    if parked {
+                        tracing::error!(outbox_id = id, attempts, error = %error, "Parked outbox message after repeated failures");
+                    } else {
+                        tracing::warn!(outbox_id = id, attempts, error = %error, "Failed to publish outbox message");
+                    }
+                    break;
+                }
+            }
+        }
+
+        self.release(&claimed[tried..]).await?;
+        Ok(sent)
+    }
+
+    /// Reserva as próximas pendentes; o comando roda na própria transação,
+    /// então os locks são soltos antes de qualquer publicação
+    async fn claim(&self) -> Result<Vec<Pending>, sqlx::Error> {
+        sqlx::query_as(
+            r#"
+            WITH next AS (
+                SELECT id
+                FROM outbox
+                WHERE sent_at IS NULL
+                  AND parked_at IS NULL
+                  AND (claimed_until IS NULL OR claimed_until < NOW())
+                ORDER BY id
+                LIMIT $1
+                FOR UPDATE SKIP LOCKED
+            )
+            UPDATE outbox o
+            SET claimed_until = NOW() + make_interval(secs => $2)
+            FROM next
+            WHERE o.id = next.id
+            RETURNING o.id, o.routing_key, o.payload, o.request_id
+            "#,
+        )
+        .bind(i64::from(self.config.batch_size))
+        .bind(CLAIM_LEASE.as_secs_f64())
+        .fetch_all(&self.db)
+        .await
+        .map(|mut pending: Vec<Pending>| {
+            // `UPDATE ... RETURNING` não garante ordem
+            pending.sort_by_key(|message| message.id);
+            pending
+        })
+    }
+
+    /// Devolve a reserva das mensagens que não chegaram a ser tentadas
+    async fn release(&self, ids: &[i64]) -> Result<(), sqlx::Error> {
+        if ids.is_empty() {
+            return Ok(());
+        }
+        sqlx::query("UPDATE outbox SET claimed_until = NULL WHERE id = ANY($1) AND sent_at IS NULL")
+            .bind(ids)
+            .execute(&self.db)
+            .await?;
+        Ok(())
+    }
+
+    async fn purge(&self) -> Result<(), sqlx::Error> {
+        let result = sqlx::query("DELETE FROM outbox WHERE sent_at < NOW() - make_interval(hours => $1)")
+            .bind(self.config.retention_hours as i32)
+            .execute(&self.db)
+            .await?;
+        if result.rows_affected() > 0 {
+            tracing::info!(deleted = result.rows_affected(), "Purged sent outbox messages");
+        }
+        Ok(())
+    }
+
+    async fn update_pending(&self) {
+        let counts: Result<(i64, i64), _> = sqlx::query_as(
+            r#"
+            SELECT COUNT(*) FILTER (WHERE parked_at IS NULL), COUNT(*) FILTER (WHERE parked_at IS NOT NULL)
+            FROM outbox
+            WHERE sent_at IS NULL
+            "#,
+        )
+        .fetch_one(&self.db)
+        .await;
+        match counts {
+            Ok((pending, parked)) => {
+                metrics().outbox_pending.set(pending);
+                metrics().outbox_parked.set(parked);
+            }
+            Err(error) => tracing::warn!(error = %error, "Failed to count pending outbox messages"),
+        }
+    }
+}
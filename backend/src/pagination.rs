@@ -0,0 +1,296 @@
+// backend/src/pagination.rs
+//! Listagens paginadas por cursor (keyset), com ordenação e filtros por campo.
+//!
+//! Cada rota de listagem descreve a consulta com um `ListSpec` (tabela, colunas
+//! que podem ser usadas em `sort` e em filtros) e recebe um `ListQuery`, extraído
+//! da query string:
+//!
+//! - `limit`: tamanho da página (padrão `DEFAULT_LIMIT`, máximo `MAX_LIMIT`)
+//! - `sort`: coluna da lista branca, `-coluna` para ordem decrescente
+//! - `cursor`: valor opaco devolvido em `next_cursor` pela página anterior
+//! - `page`: paginação antiga por deslocamento, mantida para o frontend atual
+//! - qualquer outro parâmetro é um filtro de igualdade (`?group_id=...`) ou,
+//!   nas colunas declaradas com `Column::since`/`Column::until`, um limite de
+//!   intervalo (`?from=2025-01-01T00:00:00Z`)
+use actix_web::{dev::Payload, web::Query, FromRequest, HttpRequest};
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+use futures_util::future::{ready, Ready};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use sqlx::{postgres::PgRow, FromRow, PgPool, Postgres, QueryBuilder, Row};
+use utoipa::IntoParams;
+
+use crate::error::ApiError;
+
+pub const DEFAULT_LIMIT: i64 = 10;
+pub const MAX_LIMIT: i64 = 100;
+
+/// Tipo Postgres usado no cast dos valores vindos da query string e do cursor
+#[derive(Clone, Copy)]
+pub enum SqlType {
+    Uuid,
+    Text,
+    Int,
+    Bool,
+    Date,
+    Timestamp,
+    TimestampTz,
+}
+
+impl SqlType {
+    fn cast(self) -> &'static str {
+        match self {
+            SqlType::Uuid => "uuid",
+            SqlType::Text => "text",
+            SqlType::Int => "integer",
+            SqlType::Bool => "boolean",
+            SqlType::Date => "date",
+            SqlType::Timestamp => "timestamp",
+            SqlType::TimestampTz => "timestamptz",
+        }
+    }
+}
+
+/// Coluna exposta para `sort` ou filtro. `expr` é o SQL usado na consulta; para
+/// ordenação ele não pode ser nulo (use `COALESCE` em colunas opcionais).
+pub struct Column {
+    pub name: &'static str,
+    pub expr: &'static str,
+    pub ty: SqlType,
+    /// Operador usado quando a coluna é filtro
+    pub op: &'static str,
+}
+
+impl Column {
+    pub const fn new(name: &'static str, expr: &'static str, ty: SqlType) -> Self {
+        Column { name, expr, ty, op: "=" }
+    }
+
+    /// Filtro de limite inferior inclusivo (`expr >= valor`)
+    pub const fn since(name: &'static str, expr: &'static str, ty: SqlType) -> Self {
+        Column { name, expr, ty, op: ">=" }
+    }
+
+    /// Filtro de limite superior exclusivo (`expr < valor`)
+    pub const fn until(name: &'static str, expr: &'static str, ty: SqlType) -> Self {
+        Column { name, expr, ty, op: "<" }
+    }
+}
+
+/// Descrição de uma listagem
+pub struct ListSpec {
+    /// Cláusula FROM (pode conter JOINs)
+    pub from: &'static str,
+    /// Colunas selecionadas; precisam cobrir os campos do model
+    pub select: &'static str,
+    /// Chave única usada como desempate na ordenação
+    pub id: &'static str,
+    pub sorts: &'static [Column],
+    /// Ordenação padrão, no mesmo formato do parâmetro `sort`
+    pub default_sort: &'static str,
+    pub filters: &'static [Column],
+}
+
+/// Parâmetros de listagem extraídos da query string
+#[derive(Debug, Default)]
+pub struct ListQuery {
+    pub limit: Option<i64>,
+    pub page: Option<i64>,
+    pub sort: Option<String>,
+    pub cursor: Option<String>,
+    pub filters: Vec<(String, String)>,
+}
+
+impl ListQuery {
+    fn parse(query_string: &str) -> Result<Self, ApiError> {
+        let params = Query::<Vec<(String, String)>>::from_query(query_string)
+            .map_err(|err| ApiError::BadRequest(format!("Invalid query string: {}", err)))?
+            .into_inner();
+
+        let mut query = ListQuery::default();
+        for (key, value) in params {
+            match key.as_str() {
+                "limit" => query.limit = Some(parse_positive("limit", &value)?),
+                "page" => query.page = Some(parse_positive("page", &value)?),
+                "sort" => query.sort = Some(value),
+                "cursor" => query.cursor = Some(value),
+                _ => query.filters.push((key, value)),
+            }
+        }
+        Ok(query)
+    }
+
+    /// Acrescenta um filtro fixo (ex.: o id do recurso pai vindo do path)
+    pub fn with_filter(mut self, name: &str, value: impl ToString) -> Self {
+        self.filters.retain(|(key, _)| key != name);
+        self.filters.push((name.to_string(), value.to_string()));
+        self
+    }
+
+    fn limit(&self) -> i64 {
+        self.limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT)
+    }
+}
+
+fn parse_positive(name: &str, value: &str) -> Result<i64, ApiError> {
+    value
+        .parse::<i64>()
+        .ok()
+        .filter(|n| *n > 0)
+        .ok_or_else(|| ApiError::BadRequest(format!("'{}' must be a positive integer", name)))
+}
+
+impl FromRequest for ListQuery {
+    type Error = ApiError;
+    type Future = Ready<Result<Self, Self::Error>>;
+
+    fn from_request(req: &HttpRequest, _: &mut Payload) -> Self::Future {
+        ready(ListQuery::parse(req.query_string()))
+    }
+}
+
+/// Parâmetros comuns de `ListQuery`, só para a documentação OpenAPI; os filtros
+/// de cada rota são documentados junto do handler
+#[derive(IntoParams)]
+#[into_params(parameter_in = Query)]
+#[allow(dead_code)]
+pub struct ListParams {
+    /// Tamanho da página (padrão 10, máximo 100)
+    limit: Option<i64>,
+    /// Valor de `next_cursor` devolvido pela página anterior
+    cursor: Option<String>,
+    /// Coluna de ordenação; prefixo `-` para ordem decrescente
+    sort: Option<String>,
+    /// Paginação antiga por deslocamento, ignorada quando há `cursor`
+    page: Option<i64>,
+}
+
+/// Conteúdo do cursor: a ordenação usada e a posição (valor de ordenação + id) do último item
+#[derive(Serialize, Deserialize)]
+struct Cursor {
+    sort: String,
+    key: String,
+    id: String,
+}
+
+impl Cursor {
+    fn encode(&self) -> String {
+        URL_SAFE_NO_PAD.encode(serde_json::to_vec(self).unwrap_or_default())
+    }
+
+    fn decode(value: &str) -> Result<Self, ApiError> {
+        URL_SAFE_NO_PAD
+            .decode(value)
+            .ok()
+            .and_then(|bytes| serde_json::from_slice(&bytes).ok())
+            .ok_or_else(|| ApiError::BadRequest("Invalid cursor".to_string()))
+    }
+}
+
+/// Uma página de resultados
+pub struct Page<T> {
+    pub items: Vec<T>,
+    pub total: i64,
+    pub next_cursor: Option<String>,
+}
+
+impl<T: Serialize> Page<T> {
+    /// Envelope padrão das listagens, com os itens em `key`
+    pub fn to_json(&self, key: &str) -> Value {
+        let mut body = json!({
+            "status": "success",
+            "results": self.items.len(),
+            "total": self.total,
+            "next_cursor": self.next_cursor,
+        });
+        body[key] = json!(self.items);
+        body
+    }
+}
+
+fn push_filters<'a>(builder: &mut QueryBuilder<'a, Postgres>, spec: &ListSpec, query: &ListQuery) -> Result<(), ApiError> {
+    builder.push(" WHERE TRUE");
+    for (name, value) in &query.filters {
+        let column = spec.filters.iter().find(|c| c.name == name.as_str()).ok_or_else(|| {
+            let allowed: Vec<&str> = spec.filters.iter().map(|c| c.name).collect();
+            ApiError::BadRequest(format!("Unknown filter '{}'; allowed: {}", name, allowed.join(", ")))
+        })?;
+        builder
+            .push(format!(" AND {} {} ", column.expr, column.op))
+            .push_bind(value.clone())
+            .push(format!("::{}", column.ty.cast()));
+    }
+    Ok(())
+}
+
+/// Executa a listagem descrita por `spec` e devolve a página pedida em `query`
+pub async fn fetch_page<T>(db: &PgPool, spec: &ListSpec, query: &ListQuery) -> Result<Page<T>, ApiError>
+where
+    T: for<'r> FromRow<'r, PgRow>,
+{
+    let sort = query.sort.as_deref().unwrap_or(spec.default_sort);
+    let (descending, sort_name) = match sort.strip_prefix('-') {
+        Some(name) => (true, name),
+        None => (false, sort),
+    };
+    let sort_column = spec.sorts.iter().find(|c| c.name == sort_name).ok_or_else(|| {
+        let allowed: Vec<&str> = spec.sorts.iter().map(|c| c.name).collect();
+        ApiError::BadRequest(format!("Cannot sort by '{}'; allowed: {}", sort_name, allowed.join(", ")))
+    })?;
+    let limit = query.limit();
+
+    let mut count = QueryBuilder::<Postgres>::new(format!("SELECT COUNT(*) FROM {}", spec.from));
+    push_filters(&mut count, spec, query)?;
+    let total: i64 = count.build().fetch_one(db).await?.try_get(0)?;
+
+    let mut list = QueryBuilder::<Postgres>::new(format!(
+        "SELECT {}, ({})::text AS page_sort_key, ({})::text AS page_id FROM {}",
+        spec.select, sort_column.expr, spec.id, spec.from
+    ));
+    push_filters(&mut list, spec, query)?;
+
+    if let Some(cursor) = &query.cursor {
+        let cursor = Cursor::decode(cursor)?;
+        if cursor.sort != sort {
+            return Err(ApiError::BadRequest("Cursor was issued for a different sort".to_string()));
+        }
+        list.push(format!(" AND ({}, {}) {} (", sort_column.expr, spec.id, if descending { "<" } else { ">" }))
+            .push_bind(cursor.key)
+            .push(format!("::{}, ", sort_column.ty.cast()))
+            .push_bind(cursor.id)
+            .push("::uuid)");
+    }
+
+    let direction = if descending { "DESC" } else { "ASC" };
+    list.push(format!(" ORDER BY {} {}, {} {}", sort_column.expr, direction, spec.id, direction))
+        .push(" LIMIT ")
+        .push_bind(limit + 1);
+
+    // `page` só é usado sem cursor, para manter a paginação antiga funcionando
+    if let (Some(page), None) = (query.page, &query.cursor) {
+        let offset = (page - 1)
+            .checked_mul(limit)
+            .ok_or_else(|| ApiError::BadRequest("'page' is too large".to_string()))?;
+        list.push(" OFFSET ").push_bind(offset);
+    }
+
+    let mut rows = list.build().fetch_all(db).await?;
+    let has_more = rows.len() as i64 > limit;
+    rows.truncate(limit as usize);
+
+    let next_cursor = match rows.last() {
+        Some(row) if has_more => Some(
+            Cursor {
+                sort: sort.to_string(),
+                key: row.try_get("page_sort_key")?,
+                id: row.try_get("page_id")?,
+            }
+            .encode(),
+        ),
+        _ => None,
+    };
+
+    let items = rows.iter().map(T::from_row).collect::<Result<Vec<T>, _>>()?;
+
+    Ok(Page { items, total, next_cursor })
+}
@@ -0,0 +1,143 @@
+// backend/src/publisher.rs
+//! Publicação dos jobs para os workers. O relay da outbox publica por meio de
+//! um [`JobPublisher`], escolhido em `jobs.publisher`: o RabbitMQ
+//! (`amqp::ConnectionManager`), a fila em memória do próprio backend
+//! (`in_process::InProcessPublisher`) ou o [`NoopPublisher`]. Nos testes, um
+//! [`RecordingPublisher`] só guarda as mensagens.
+use async_trait::async_trait;
+use serde_json::Value;
+use std::fmt;
+
+/// Mensagem para um worker
+#[derive(Debug, Clone)]
+pub struct Job {
+    pub routing_key: String,
+    pub payload: Value,
+    /// Repassado ao worker no cabeçalho `x-request-id`
+    pub request_id: Option<String>,
+}
+
+pub enum PublishError {
+    /// Sem conexão com o broker no momento, ou publisher já fechado
+    Unavailable,
+    /// O broker recusou a mensagem (nack) ou não havia fila para ela
+    Rejected,
+    /// Sem confirmação do broker dentro de `amqp.publish_timeout_ms`
+    Timeout,
+    Amqp(lapin::Error),
+}
+
+impl fmt::Display for PublishError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PublishError::Unavailable => write!(f, "job publisher is not available"),
+            PublishError::Rejected => write!(f, "message was rejected or not routed by RabbitMQ"),
+            PublishError::Timeout => write!(f, "RabbitMQ did not confirm the message in time"),
+            PublishError::Amqp(error) => write!(f, "RabbitMQ error: {}", error),
+        }
+    }
+}
+
+impl fmt::Debug for PublishError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+impl std::error::Error for PublishError {}
+
+#[async_trait]
+pub trait JobPublisher: Send + Sync {
+    /// Publica o job; só retorna `Ok` depois da confirmação do destino
+    async fn publish(&self, job: &Job) -> Result<(), PublishError>;
+
+    /// Se aceita publicações agora (usado pelo relay e pelo health check)
+    fn is_connected(&self) -> bool;
+
+    /// Nome do componente em `/api/health/ready`
+    fn name(&self) -> &'static str;
+
+    /// Libera as conexões; chamado no desligamento
+    async fn close(&self);
+}
+
+/// Descarta os jobs (`jobs.publisher = "noop"`), ex.: para rodar a API sem
+/// processar vídeos
+pub struct NoopPublisher;
+
+#[async_trait]
+impl JobPublisher for NoopPublisher {
+    async fn publish(&self, job: &Job) -> Result<(), PublishError> {
+        tracing::info!(routing_key = %job.routing_key, payload = %job.payload, "Discarding job");
+        Ok(())
+    }
+
+    fn is_connected(&self) -> bool {
+        true
+    }
+
+    fn name(&self) -> &'static str {
+        "noop"
+    }
+
+    async fn close(&self) {}
+}
+
+/// Guarda os jobs em memória em vez de publicá-los
+#[cfg(test)]
+#[derive(Default)]
+pub struct RecordingPublisher {
+    jobs: std::sync::Mutex<Vec<Job>>,
+    disconnected: std::sync::atomic::AtomicBool,
+    rejected: std::sync::Mutex<Vec<Value>>,
+    paused: tokio::sync::RwLock<()>,
+}
+
+#[cfg(test)]
+impl RecordingPublisher {
+    /// Jobs publicados até agora, em ordem
+    pub fn jobs(&self) -> Vec<Job> {
+        self.jobs.lock().expect("jobs lock poisoned").clone()
+    }
+
+    /// Simula a queda (`false`) ou a volta (`true`) do broker
+    pub fn set_connected(&self, connected: bool) {
+        self.disconnected.store(!connected, std::sync::atomic::Ordering::SeqCst);
+    }
+
+    /// Faz o broker recusar os jobs com este payload, com a conexão de pé
+    pub fn reject(&self, payload: Value) {
+        self.rejected.lock().expect("rejected lock poisoned").push(payload);
+    }
+
+    /// Segura as publicações (como um broker lento) enquanto o guard existir
+    pub async fn pause(&self) -> tokio::sync::RwLockWriteGuard<'_, ()> {
+        self.paused.write().await
+    }
+}
+
+#[cfg(test)]
+#[async_trait]
+impl JobPublisher for RecordingPublisher {
+    async fn publish(&self, job: &Job) -> Result<(), PublishError> {
+        let _running = self.paused.read().await;
+        if !self.is_connected() {
+            return Err(PublishError::Unavailable);
+        }
+        if self.rejected.lock().expect("rejected lock poisoned").contains(&job.payload) {
+            return Err(PublishError::Rejected);
+        }
+        self.jobs.lock().expect("jobs lock poisoned").push(job.clone());
+        Ok(())
+    }
+
+    fn is_connected(&self) -> bool {
+        !self.disconnected.load(std::sync::atomic::Ordering::SeqCst)
+    }
+
+    fn name(&self) -> &'static str {
+        "recording"
+    }
+
+    async fn close(&self) {}
+}
@@ -0,0 +1,138 @@
+// backend/src/repo/attendances.rs
+use chrono::NaiveDate;
+use sqlx::{postgres::PgExecutor, PgPool};
+use uuid::Uuid;
+
+use crate::{
+    error::ApiError,
+    models::{AttendanceModel, AttendanceSummary, AttendanceWithGroup},
+    pagination::{fetch_page, Column, ListQuery, ListSpec, Page, SqlType},
+    schema::{CreateAttendanceSchema, UpdateAttendanceSchema},
+};
+
+const ATTENDANCE_LIST: ListSpec = ListSpec {
+    from: "attendances a JOIN students s ON a.student_id = s.id LEFT JOIN groups g ON s.group_id = g.id",
+    select: "a.id, a.student_id, s.name AS student_name, g.name AS student_group_name, a.date AS attendance_date, a.status, a.notes",
+    id: "a.id",
+    sorts: &[
+        Column::new("id", "a.id", SqlType::Uuid),
+        Column::new("date", "a.date", SqlType::Date),
+        Column::new("student_name", "s.name", SqlType::Text),
+    ],
+    default_sort: "-date",
+    filters: &[
+        Column::new("student_id", "a.student_id", SqlType::Uuid),
+        Column::new("group_id", "s.group_id", SqlType::Uuid),
+        Column::new("status", "a.status", SqlType::Text),
+        Column::new("date", "a.date", SqlType::Date),
+    ],
+};
+
+/// Tabela `attendances`
+pub struct AttendanceRepo;
+
+impl AttendanceRepo {
+    pub async fn create<'e>(db: impl PgExecutor<'e>, new: &CreateAttendanceSchema) -> sqlx::Result<AttendanceModel> {
+        let query = r#"
+            INSERT INTO attendances (student_id, date, status, notes)
+            VALUES ($1, $2, $3, $4)
+            RETURNING id, student_id, date, status, notes
+        "#;
+
+        sqlx::query_as::<_, AttendanceModel>(query)
+            .bind(new.student_id)
+            .bind(new.date)
+            .bind(&new.status)
+            .bind(&new.notes)
+            .fetch_one(db)
+            .await
+    }
+
+    /// Página de `GET /attendances`, com o nome do aluno e do grupo
+    pub async fn list(db: &PgPool, query: &ListQuery) -> Result<Page<AttendanceWithGroup>, ApiError> {
+        fetch_page(db, &ATTENDANCE_LIST, query).await
+    }
+
+    pub async fn find<'e>(db: impl PgExecutor<'e>, id: Uuid) -> sqlx::Result<Option<AttendanceModel>> {
+        let query = "SELECT id, student_id, date, status, notes FROM attendances WHERE id = $1";
+
+        sqlx::query_as::<_, AttendanceModel>(query)
+            .bind(id)
+            .fetch_optional(db)
+            .await
+    }
+
+    /// Totais do aluno a partir de `since` (inclusive), sem os registros recentes
+    pub async fn summary<'e>(
+        db: impl PgExecutor<'e>,
+        student_id: Uuid,
+        since: NaiveDate,
+    ) -> sqlx::Result<AttendanceSummary> {
+        let query = r#"
+            SELECT $2::date AS since,
+                   COUNT(*) AS total,
+                   COUNT(*) FILTER (WHERE status = 'presente') AS present,
+                   COUNT(*) FILTER (WHERE status = 'falta') AS absent,
+                   MAX(date) AS last_date
+            FROM attendances
+            WHERE student_id = $1 AND date >= $2
+        "#;
+
+        sqlx::query_as::<_, AttendanceSummary>(query)
+            .bind(student_id)
+            .bind(since)
+            .fetch_one(db)
+            .await
+    }
+
+    /// Os `limit` registros mais recentes do aluno
+    pub async fn recent<'e>(db: impl PgExecutor<'e>, student_id: Uuid, limit: i64) -> sqlx::Result<Vec<AttendanceModel>> {
+        let query = r#"
+            SELECT id, student_id, date, status, notes FROM attendances
+            WHERE student_id = $1
+            ORDER BY date DESC
+            LIMIT $2
+        "#;
+
+        sqlx::query_as::<_, AttendanceModel>(query)
+            .bind(student_id)
+            .bind(limit)
+            .fetch_all(db)
+            .await
+    }
+
+    /// Altera só os campos informados; `None` se a presença não existe
+    pub async fn update<'e>(
+        db: impl PgExecutor<'e>,
+        id: Uuid,
+        changes: &UpdateAttendanceSchema,
+    ) -> sqlx::Result<Option<AttendanceModel>> {
+        let query = r#"
+            UPDATE attendances
+            SET student_id = COALESCE($1, student_id),
+                date = COALESCE($2, date),
+                status = COALESCE($3, status),
+                notes = COALESCE($4, notes)
+            WHERE id = $5
+            RETURNING id, student_id, date, status, notes
+        "#;
+
+        sqlx::query_as::<_, AttendanceModel>(query)
+            .bind(changes.student_id)
+            .bind(changes.date)
+            .bind(&changes.status)
+            .bind(&changes.notes)
+            .bind(id)
+            .fetch_optional(db)
+            .await
+    }
+
+    /// `false` se a presença não existia
+    pub async fn delete<'e>(db: impl PgExecutor<'e>, id: Uuid) -> sqlx::Result<bool> {
+        let result = sqlx::query("DELETE FROM attendances WHERE id = $1")
+            .bind(id)
+            .execute(db)
+            .await?;
+        Ok(result.rows_affected() > 0)
+    }
+}
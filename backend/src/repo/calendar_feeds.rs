@@ -0,0 +1,84 @@
+// backend/src/repo/calendar_feeds.rs
+use sqlx::postgres::PgExecutor;
+use uuid::Uuid;
+
+use crate::models::CalendarFeedModel;
+
+/// Dono de um feed .ics
+#[derive(Debug, Clone, Copy)]
+pub enum FeedOwner {
+    Group(Uuid),
+    Household(Uuid),
+}
+
+impl FeedOwner {
+    fn column(self) -> &'static str {
+        match self {
+            FeedOwner::Group(_) => "group_id",
+            FeedOwner::Household(_) => "household_id",
+        }
+    }
+
+    fn id(self) -> Uuid {
+        match self {
+            FeedOwner::Group(id) | FeedOwner::Household(id) => id,
+        }
+    }
+}
+
+/// Tabela `calendar_feeds`
+pub struct CalendarFeedRepo;
+
+impl CalendarFeedRepo {
+    /// Grava o feed do dono com um token novo, substituindo o anterior.
+    /// Devolve também se o feed foi criado agora.
+    pub async fn rotate<'e>(
+        db: impl PgExecutor<'e>,
+        owner: FeedOwner,
+        token: &str,
+    ) -> sqlx::Result<(CalendarFeedModel, bool)> {
+        let query = format!(
+            r#"
+            INSERT INTO calendar_feeds (token, {column})
+            VALUES ($1, $2)
+            ON CONFLICT ({column}) DO UPDATE SET token = EXCLUDED.token, created_at = NOW()
+            RETURNING id, token, group_id, household_id, created_at, (xmax = 0) AS inserted
+            "#,
+            column = owner.column()
+        );
+
+        #[derive(sqlx::FromRow)]
+        struct Row {
+            #[sqlx(flatten)]
+            feed: CalendarFeedModel,
+            inserted: bool,
+        }
+
+        let row = sqlx::query_as::<_, Row>(&query)
+            .bind(token)
+            .bind(owner.id())
+            .fetch_one(db)
+            .await?;
+        Ok((row.feed, row.inserted))
+    }
+
+    /// `false` se o dono não tinha feed
+    pub async fn delete<'e>(db: impl PgExecutor<'e>, owner: FeedOwner) -> sqlx::Result<bool> {
+        let query = format!("DELETE FROM calendar_feeds WHERE {} = $1", owner.column());
+
+        let result = sqlx::query(&query)
+            .bind(owner.id())
+            .execute(db)
+            .await?;
+        Ok(result.rows_affected() > 0)
+    }
+
+    pub async fn find_by_token<'e>(db: impl PgExecutor<'e>, token: &str) -> sqlx::Result<Option<CalendarFeedModel>> {
+        sqlx::query_as::<_, CalendarFeedModel>(
+            "SELECT id, token, group_id, household_id, created_at FROM calendar_feeds WHERE token = $1",
+        )
+        .bind(token)
+        .fetch_optional(db)
+        .await
+    }
+}
@@ -0,0 +1,145 @@
+// backend/src/repo/group_schedules.rs
+use chrono::NaiveDateTime;
+use sqlx::postgres::PgExecutor;
+use uuid::Uuid;
+
+use crate::{
+    models::{GroupScheduleModel, ScheduleChangeModel},
+    schema::{CreateGroupScheduleSchema, UpdateGroupScheduleSchema},
+};
+
+const SCHEDULE_COLUMNS: &str = "id, group_id, weekday, start_time, end_time, location, valid_from, valid_until, created_at, updated_at";
+
+/// Tabela `group_schedules` (horários fixos de treino) e as mudanças de treino
+/// que o calendário aplica sobre eles
+pub struct GroupScheduleRepo;
+
+impl GroupScheduleRepo {
+    pub async fn create<'e>(
+        db: impl PgExecutor<'e>,
+        group_id: Uuid,
+        new: &CreateGroupScheduleSchema,
+    ) -> sqlx::Result<GroupScheduleModel> {
+        let query = format!(
+            r#"
+            INSERT INTO group_schedules (group_id, weekday, start_time, end_time, location, valid_from, valid_until)
+            VALUES ($1, $2, $3, $4, $5, $6, $7)
+            RETURNING {}
+            "#,
+            SCHEDULE_COLUMNS
+        );
+
+        sqlx::query_as::<_, GroupScheduleModel>(&query)
+            .bind(group_id)
+            .bind(new.weekday)
+            .bind(new.start_time)
+            .bind(new.end_time)
+            .bind(&new.location)
+            .bind(new.valid_from)
+            .bind(new.valid_until)
+            .fetch_one(db)
+            .await
+    }
+
+    /// Horários da turma, por dia da semana e hora
+    pub async fn for_group<'e>(db: impl PgExecutor<'e>, group_id: Uuid) -> sqlx::Result<Vec<GroupScheduleModel>> {
+        let query = format!(
+            "SELECT {} FROM group_schedules WHERE group_id = $1 ORDER BY weekday, start_time, valid_from",
+            SCHEDULE_COLUMNS
+        );
+
+        sqlx::query_as::<_, GroupScheduleModel>(&query)
+            .bind(group_id)
+            .fetch_all(db)
+            .await
+    }
+
+    pub async fn find<'e>(db: impl PgExecutor<'e>, id: Uuid) -> sqlx::Result<Option<GroupScheduleModel>> {
+        let query = format!("SELECT {} FROM group_schedules WHERE id = $1", SCHEDULE_COLUMNS);
+
+        sqlx::query_as::<_, GroupScheduleModel>(&query)
+            .bind(id)
+            .fetch_optional(db)
+            .await
+    }
+
+    /// Altera só os campos informados (`location` e `valid_until` podem ser
+    /// limpos com `null`); `None` se o horário não existe ou é de outra turma
+    pub async fn update<'e>(
+        db: impl PgExecutor<'e>,
+        group_id: Uuid,
+        id: Uuid,
+        changes: &UpdateGroupScheduleSchema,
+    ) -> sqlx::Result<Option<GroupScheduleModel>> {
+        let query = format!(
+            r#"
+            UPDATE group_schedules SET
+                weekday = COALESCE($1, weekday),
+                start_time = COALESCE($2, start_time),
+                end_time = COALESCE($3, end_time),
+                location = CASE WHEN $4 THEN $5 ELSE location END,
+                valid_from = COALESCE($6, valid_from),
+                valid_until = CASE WHEN $7 THEN $8 ELSE valid_until END
+            WHERE id = $9 AND group_id = $10
+            RETURNING {}
+            "#,
+            SCHEDULE_COLUMNS
+        );
+
+        sqlx::query_as::<_, GroupScheduleModel>(&query)
+            .bind(changes.weekday)
+            .bind(changes.start_time)
+            .bind(changes.end_time)
+            .bind(changes.location.is_some())
+            .bind(changes.location.as_ref().and_then(Option::as_deref))
+            .bind(changes.valid_from)
+            .bind(changes.valid_until.is_some())
+            .bind(changes.valid_until.flatten())
+            .bind(id)
+            .bind(group_id)
+            .fetch_optional(db)
+            .await
+    }
+
+    /// `false` se o horário não existia na turma
+    pub async fn delete<'e>(db: impl PgExecutor<'e>, group_id: Uuid, id: Uuid) -> sqlx::Result<bool> {
+        let result = sqlx::query("DELETE FROM group_schedules WHERE id = $1 AND group_id = $2")
+            .bind(id)
+            .bind(group_id)
+            .execute(db)
+            .await?;
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// Datas previstas das mudanças de treino presas ao horário
+    pub async fn change_dates<'e>(db: impl PgExecutor<'e>, schedule_id: Uuid) -> sqlx::Result<Vec<NaiveDateTime>> {
+        sqlx::query_scalar("SELECT old_date FROM schedule_changes WHERE schedule_id = $1 AND old_date IS NOT NULL ORDER BY old_date")
+            .bind(schedule_id)
+            .fetch_all(db)
+            .await
+    }
+
+    /// Mudanças de treino da turma com a data prevista ou a nova em
+    /// `[from, until)`, da mais antiga para a mais nova
+    pub async fn changes_between<'e>(
+        db: impl PgExecutor<'e>,
+        group_id: Uuid,
+        from: NaiveDateTime,
+        until: NaiveDateTime,
+    ) -> sqlx::Result<Vec<ScheduleChangeModel>> {
+        let query = r#"
+            SELECT id, group_id, old_date, new_date, reason, created_at, schedule_id
+            FROM schedule_changes
+            WHERE group_id = $1
+              AND ((old_date >= $2 AND old_date < $3) OR (new_date >= $2 AND new_date < $3))
+            ORDER BY created_at NULLS FIRST, id
+        "#;
+
+        sqlx::query_as::<_, ScheduleChangeModel>(query)
+            .bind(group_id)
+            .bind(from)
+            .bind(until)
+            .fetch_all(db)
+            .await
+    }
+}
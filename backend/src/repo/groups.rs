@@ -0,0 +1,211 @@
+// backend/src/repo/groups.rs
+use std::collections::HashMap;
+
+use sqlx::{postgres::PgExecutor, PgPool};
+use uuid::Uuid;
+
+use crate::{
+    error::ApiError,
+    models::{GroupModel, StudentModel},
+    pagination::{fetch_page, Column, ListQuery, ListSpec, Page, SqlType},
+    schema::{CreateGroupSchema, UpdateGroupSchema},
+};
+
+const GROUP_COLUMNS: &str = "id, name, description, created_at, season, min_age, max_age, coach_id, capacity";
+
+const GROUP_LIST: ListSpec = ListSpec {
+    from: "groups",
+    select: GROUP_COLUMNS,
+    id: "id",
+    sorts: &[
+        Column::new("id", "id", SqlType::Uuid),
+        Column::new("name", "name", SqlType::Text),
+        Column::new("created_at", "created_at", SqlType::TimestampTz),
+        Column::new("min_age", "COALESCE(min_age, -1)", SqlType::Int),
+    ],
+    default_sort: "-created_at",
+    filters: &[
+        Column::new("name", "name", SqlType::Text),
+        Column::new("season", "season", SqlType::Int),
+        Column::new("coach_id", "coach_id", SqlType::Uuid),
+    ],
+};
+
+/// Tabela `groups` (turmas)
+pub struct GroupRepo;
+
+impl GroupRepo {
+    pub async fn create<'e>(db: impl PgExecutor<'e>, new: &CreateGroupSchema) -> sqlx::Result<GroupModel> {
+        let query = format!(
+            r#"
+            INSERT INTO groups (name, description, season, min_age, max_age, coach_id, capacity)
+            VALUES ($1, $2, $3, $4, $5, $6, $7)
+            RETURNING {}
+            "#,
+            GROUP_COLUMNS
+        );
+
+        sqlx::query_as::<_, GroupModel>(&query)
+            .bind(&new.name)
+            .bind(&new.description)
+            .bind(new.season)
+            .bind(new.min_age)
+            .bind(new.max_age)
+            .bind(new.coach_id)
+            .bind(new.capacity)
+            .fetch_one(db)
+            .await
+    }
+
+    /// Página de `GET /groups`, com os filtros de `GROUP_LIST`
+    pub async fn list(db: &PgPool, query: &ListQuery) -> Result<Page<GroupModel>, ApiError> {
+        fetch_page(db, &GROUP_LIST, query).await
+    }
+
+    pub async fn find<'e>(db: impl PgExecutor<'e>, id: Uuid) -> sqlx::Result<Option<GroupModel>> {
+        let query = format!("SELECT {} FROM groups WHERE id = $1", GROUP_COLUMNS);
+
+        sqlx::query_as::<_, GroupModel>(&query)
+            .bind(id)
+            .fetch_optional(db)
+            .await
+    }
+
+    /// Altera só os campos informados (todos menos `name` podem ser limpos com
+    /// `null`); `None` se a turma não existe
+    pub async fn update<'e>(
+        db: impl PgExecutor<'e>,
+        id: Uuid,
+        changes: &UpdateGroupSchema,
+    ) -> sqlx::Result<Option<GroupModel>> {
+        let query = format!(
+            r#"
+            UPDATE groups SET
+                name = COALESCE($1, name),
+                description = CASE WHEN $2 THEN $3 ELSE description END,
+                season = CASE WHEN $4 THEN $5 ELSE season END,
+                min_age = CASE WHEN $6 THEN $7 ELSE min_age END,
+                max_age = CASE WHEN $8 THEN $9 ELSE max_age END,
+                coach_id = CASE WHEN $10 THEN $11 ELSE coach_id END,
+                capacity = CASE WHEN $12 THEN $13 ELSE capacity END
+            WHERE id = $14
+            RETURNING {}
+            "#,
+            GROUP_COLUMNS
+        );
+
+        sqlx::query_as::<_, GroupModel>(&query)
+            .bind(changes.name.as_deref())
+            .bind(changes.description.is_some())
+            .bind(changes.description.as_ref().and_then(Option::as_deref))
+            .bind(changes.season.is_some())
+            .bind(changes.season.flatten())
+            .bind(changes.min_age.is_some())
+            .bind(changes.min_age.flatten())
+            .bind(changes.max_age.is_some())
+            .bind(changes.max_age.flatten())
+            .bind(changes.coach_id.is_some())
+            .bind(changes.coach_id.flatten())
+            .bind(changes.capacity.is_some())
+            .bind(changes.capacity.flatten())
+            .bind(id)
+            .fetch_optional(db)
+            .await
+    }
+
+    /// `false` se a turma não existia
+    pub async fn delete<'e>(db: impl PgExecutor<'e>, id: Uuid) -> sqlx::Result<bool> {
+        let result = sqlx::query("DELETE FROM groups WHERE id = $1")
+            .bind(id)
+            .execute(db)
+            .await?;
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// Papel do usuário indicado como técnico; `None` se ele não existe
+    pub async fn user_role<'e>(db: impl PgExecutor<'e>, user_id: Uuid) -> sqlx::Result<Option<String>> {
+        sqlx::query_scalar::<_, String>("SELECT role FROM users WHERE id = $1")
+            .bind(user_id)
+            .fetch_optional(db)
+            .await
+    }
+
+    /// Turmas da temporada com faixa etária, da mais nova para a mais velha.
+    /// Com `lock`, trava as turmas até o fim da transação, para duas
+    /// distribuições simultâneas não passarem do número de vagas.
+    pub async fn for_season<'e>(db: impl PgExecutor<'e>, season: i32, lock: bool) -> sqlx::Result<Vec<GroupModel>> {
+        let query = format!(
+            r#"
+            SELECT {}
+            FROM groups
+            WHERE season = $1 AND (min_age IS NOT NULL OR max_age IS NOT NULL)
+            ORDER BY min_age NULLS FIRST, max_age NULLS LAST, name
+            {}
+            "#,
+            GROUP_COLUMNS,
+            if lock { "FOR UPDATE" } else { "" }
+        );
+
+        sqlx::query_as::<_, GroupModel>(&query)
+            .bind(season)
+            .fetch_all(db)
+            .await
+    }
+
+    /// Turmas dos alunos da família, por nome
+    pub async fn for_household<'e>(db: impl PgExecutor<'e>, household_id: Uuid) -> sqlx::Result<Vec<GroupModel>> {
+        let query = format!(
+            r#"
+            SELECT {}
+            FROM groups
+            WHERE id IN (SELECT group_id FROM students WHERE household_id = $1)
+            ORDER BY name, id
+            "#,
+            GROUP_COLUMNS
+        );
+
+        sqlx::query_as::<_, GroupModel>(&query)
+            .bind(household_id)
+            .fetch_all(db)
+            .await
+    }
+
+    /// Alunos em cada turma da lista
+    pub async fn member_counts<'e>(db: impl PgExecutor<'e>, ids: &[Uuid]) -> sqlx::Result<HashMap<Uuid, i64>> {
+        let rows: Vec<(Uuid, i64)> = sqlx::query_as(
+            "SELECT group_id, COUNT(*) FROM students WHERE group_id = ANY($1) GROUP BY group_id",
+        )
+        .bind(ids)
+        .fetch_all(db)
+        .await?;
+        Ok(rows.into_iter().collect())
+    }
+
+    /// Alunos a distribuir na temporada, por data de nascimento: os sem turma e
+    /// os que estão numa turma de temporada anterior. Quem está numa turma sem
+    /// temporada (cadastro antigo) ou de temporada posterior fica onde está.
+    pub async fn students_without_group<'e>(db: impl PgExecutor<'e>, season: i32) -> sqlx::Result<Vec<StudentModel>> {
+        let query = r#"
+            SELECT s.id, s.user_id, s.name, s.email, s.age, s.birth_date, s.shirt_size, s.parent_id, s.group_id,
+                   s.students_date, s.household_id
+            FROM students s
+            LEFT JOIN groups g ON g.id = s.group_id
+            WHERE s.group_id IS NULL OR g.season < $1
+            ORDER BY s.birth_date NULLS LAST, s.name, s.id
+        "#;
+
+        sqlx::query_as::<_, StudentModel>(query)
+            .bind(season)
+            .fetch_all(db)
+            .await
+    }
+
+    pub async fn assign<'e>(db: impl PgExecutor<'e>, student_id: Uuid, group_id: Uuid) -> sqlx::Result<()> {
+        sqlx::query("UPDATE students SET group_id = $1 WHERE id = $2")
+            .bind(group_id)
+            .bind(student_id)
+            .execute(db)
+            .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,124 @@
+// backend/src/repo/households.rs
+use sqlx::{postgres::PgExecutor, PgPool};
+use uuid::Uuid;
+
+use crate::{
+    error::ApiError,
+    models::{AddressModel, GuardianModel, HouseholdMembers, HouseholdModel, ParentModel, PhoneModel, StudentModel},
+    pagination::{fetch_page, Column, ListQuery, ListSpec, Page, SqlType},
+    schema::{CreateHouseholdSchema, UpdateHouseholdSchema},
+};
+
+const HOUSEHOLD_LIST: ListSpec = ListSpec {
+    from: "households",
+    select: "id, name, created_at",
+    id: "id",
+    sorts: &[
+        Column::new("id", "id", SqlType::Uuid),
+        Column::new("name", "name", SqlType::Text),
+        Column::new("created_at", "created_at", SqlType::TimestampTz),
+    ],
+    default_sort: "name",
+    filters: &[Column::new("name", "name", SqlType::Text)],
+};
+
+/// Tabela `households` e quem aponta para ela em `household_id`
+pub struct HouseholdRepo;
+
+impl HouseholdRepo {
+    pub async fn create<'e>(db: impl PgExecutor<'e>, new: &CreateHouseholdSchema) -> sqlx::Result<HouseholdModel> {
+        sqlx::query_as::<_, HouseholdModel>("INSERT INTO households (name) VALUES ($1) RETURNING id, name, created_at")
+            .bind(&new.name)
+            .fetch_one(db)
+            .await
+    }
+
+    /// Página de `GET /households`, com os filtros de `HOUSEHOLD_LIST`
+    pub async fn list(db: &PgPool, query: &ListQuery) -> Result<Page<HouseholdModel>, ApiError> {
+        fetch_page(db, &HOUSEHOLD_LIST, query).await
+    }
+
+    pub async fn find<'e>(db: impl PgExecutor<'e>, id: Uuid) -> sqlx::Result<Option<HouseholdModel>> {
+        sqlx::query_as::<_, HouseholdModel>("SELECT id, name, created_at FROM households WHERE id = $1")
+            .bind(id)
+            .fetch_optional(db)
+            .await
+    }
+
+    /// Altera só os campos informados; `None` se a família não existe
+    pub async fn update<'e>(
+        db: impl PgExecutor<'e>,
+        id: Uuid,
+        changes: &UpdateHouseholdSchema,
+    ) -> sqlx::Result<Option<HouseholdModel>> {
+        let query = r#"
+            UPDATE households SET name = COALESCE($1, name)
+            WHERE id = $2
+            RETURNING id, name, created_at
+        "#;
+
+        sqlx::query_as::<_, HouseholdModel>(query)
+            .bind(changes.name.as_ref())
+            .bind(id)
+            .fetch_optional(db)
+            .await
+    }
+
+    /// Pais, responsáveis e alunos da família, por nome, e os contatos dela
+    pub async fn members(db: &PgPool, id: Uuid) -> sqlx::Result<HouseholdMembers> {
+        let parents = sqlx::query_as::<_, ParentModel>(
+            "SELECT id, user_id, name, email, parents_date, household_id FROM parents WHERE household_id = $1 ORDER BY name",
+        )
+        .bind(id)
+        .fetch_all(db);
+        let guardians = sqlx::query_as::<_, GuardianModel>(
+            "SELECT id, user_id, name, relationship, guardians_date, household_id FROM guardians WHERE household_id = $1 ORDER BY name",
+        )
+        .bind(id)
+        .fetch_all(db);
+        let students = sqlx::query_as::<_, StudentModel>(
+            r#"
+            SELECT id, user_id, name, email, age, birth_date, shirt_size, parent_id, group_id, students_date, household_id
+            FROM students
+            WHERE household_id = $1
+            ORDER BY name
+            "#,
+        )
+        .bind(id)
+        .fetch_all(db);
+        let addresses = sqlx::query_as::<_, AddressModel>(
+            r#"
+            SELECT id, user_id, parent_id, student_id, guardian_id, household_id, street, city, state, zip_code
+            FROM addresses
+            WHERE household_id = $1
+            ORDER BY id
+            "#,
+        )
+        .bind(id)
+        .fetch_all(db);
+        let phones = sqlx::query_as::<_, PhoneModel>(
+            r#"
+            SELECT id, user_id, parent_id, student_id, guardian_id, household_id, number, phone_type
+            FROM phones
+            WHERE household_id = $1
+            ORDER BY id
+            "#,
+        )
+        .bind(id)
+        .fetch_all(db);
+
+        let (parents, guardians, students, addresses, phones) =
+            tokio::try_join!(parents, guardians, students, addresses, phones)?;
+        Ok(HouseholdMembers { parents, guardians, students, addresses, phones })
+    }
+
+    /// `false` se a família não existia. Os integrantes ficam sem família e os
+    /// contatos dela são removidos.
+    pub async fn delete<'e>(db: impl PgExecutor<'e>, id: Uuid) -> sqlx::Result<bool> {
+        let result = sqlx::query("DELETE FROM households WHERE id = $1")
+            .bind(id)
+            .execute(db)
+            .await?;
+        Ok(result.rows_affected() > 0)
+    }
+}
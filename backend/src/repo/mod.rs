@@ -0,0 +1,28 @@
+// backend/src/repo/mod.rs
+//! Acesso ao banco por agregado, separado dos handlers HTTP, para as mesmas
+//! consultas servirem às rotas, aos jobs e a ferramentas de linha de comando.
+//!
+//! Cada repositório é um conjunto de consultas tipadas que recebem qualquer
+//! executor do sqlx: o pool (`&data.db`) para uma consulta avulsa, ou `&mut tx`
+//! para entrar numa transação junto com a auditoria e a outbox. Os erros são os
+//! do sqlx (as listagens devolvem `ApiError`, por validarem a query string); os
+//! handlers convertem com `?` e decidem o 404.
+//!
+//! Os recursos ainda não migrados consultam o banco direto nos handlers.
+mod attendances;
+mod calendar_feeds;
+mod group_schedules;
+mod groups;
+mod households;
+mod student_guardians;
+mod students;
+mod videos;
+
+pub use attendances::AttendanceRepo;
+pub use calendar_feeds::{CalendarFeedRepo, FeedOwner};
+pub use group_schedules::GroupScheduleRepo;
+pub use groups::GroupRepo;
+pub use households::HouseholdRepo;
+pub use student_guardians::StudentGuardianRepo;
+pub use students::StudentRepo;
+pub use videos::VideoRepo;
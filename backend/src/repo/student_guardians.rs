@@ -0,0 +1,103 @@
+// backend/src/repo/student_guardians.rs
+use sqlx::postgres::PgExecutor;
+use uuid::Uuid;
+
+use crate::{
+    models::{LinkedGuardian, LinkedStudent, StudentGuardianModel},
+    schema::{LinkGuardianSchema, StudentGuardianFilter},
+};
+
+/// Tabela `student_guardians`, que liga alunos e responsáveis
+pub struct StudentGuardianRepo;
+
+impl StudentGuardianRepo {
+    /// Responsáveis do aluno, primeiro os contatos de emergência (em ordem de
+    /// prioridade) e depois os demais, por nome
+    pub async fn for_student<'e>(
+        db: impl PgExecutor<'e>,
+        student_id: Uuid,
+        filter: &StudentGuardianFilter,
+    ) -> sqlx::Result<Vec<LinkedGuardian>> {
+        let query = r#"
+            SELECT sg.guardian_id, g.user_id, g.name, sg.relationship, sg.can_pick_up, sg.emergency_priority
+            FROM student_guardians sg
+            JOIN guardians g ON g.id = sg.guardian_id
+            WHERE sg.student_id = $1
+              AND ($2::bool IS NULL OR sg.can_pick_up = $2)
+              AND ($3::bool IS NULL OR (sg.emergency_priority IS NOT NULL) = $3)
+            ORDER BY sg.emergency_priority NULLS LAST, g.name
+        "#;
+
+        sqlx::query_as::<_, LinkedGuardian>(query)
+            .bind(student_id)
+            .bind(filter.can_pick_up)
+            .bind(filter.emergency)
+            .fetch_all(db)
+            .await
+    }
+
+    /// Alunos pelos quais o responsável responde, por nome
+    pub async fn for_guardian<'e>(db: impl PgExecutor<'e>, guardian_id: Uuid) -> sqlx::Result<Vec<LinkedStudent>> {
+        let query = r#"
+            SELECT sg.student_id, s.name, s.group_id, sg.relationship, sg.can_pick_up, sg.emergency_priority
+            FROM student_guardians sg
+            JOIN students s ON s.id = sg.student_id
+            WHERE sg.guardian_id = $1
+            ORDER BY s.name
+        "#;
+
+        sqlx::query_as::<_, LinkedStudent>(query)
+            .bind(guardian_id)
+            .fetch_all(db)
+            .await
+    }
+
+    pub async fn find<'e>(
+        db: impl PgExecutor<'e>,
+        student_id: Uuid,
+        guardian_id: Uuid,
+    ) -> sqlx::Result<Option<StudentGuardianModel>> {
+        let query = "SELECT * FROM student_guardians WHERE student_id = $1 AND guardian_id = $2";
+
+        sqlx::query_as::<_, StudentGuardianModel>(query)
+            .bind(student_id)
+            .bind(guardian_id)
+            .fetch_optional(db)
+            .await
+    }
+
+    /// Cria o vínculo ou substitui os dados do existente
+    pub async fn upsert<'e>(
+        db: impl PgExecutor<'e>,
+        student_id: Uuid,
+        guardian_id: Uuid,
+        link: &LinkGuardianSchema,
+    ) -> sqlx::Result<StudentGuardianModel> {
+        let query = r#"
+            INSERT INTO student_guardians (student_id, guardian_id, relationship, can_pick_up, emergency_priority)
+            VALUES ($1, $2, $3, $4, $5)
+            ON CONFLICT (student_id, guardian_id) DO UPDATE SET
+                relationship = EXCLUDED.relationship,
+                can_pick_up = EXCLUDED.can_pick_up,
+                emergency_priority = EXCLUDED.emergency_priority
+            RETURNING *
+        "#;
+
+        sqlx::query_as::<_, StudentGuardianModel>(query)
+            .bind(student_id)
+            .bind(guardian_id)
+            .bind(&link.relationship)
+            .bind(link.can_pick_up)
+            .bind(link.emergency_priority)
+            .fetch_one(db)
+            .await
+    }
+
+    pub async fn delete<'e>(db: impl PgExecutor<'e>, id: Uuid) -> sqlx::Result<bool> {
+        let result = sqlx::query("DELETE FROM student_guardians WHERE id = $1")
+            .bind(id)
+            .execute(db)
+            .await?;
+        Ok(result.rows_affected() > 0)
+    }
+}
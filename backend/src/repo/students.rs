@@ -0,0 +1,209 @@
+// backend/src/repo/students.rs
+use sqlx::{postgres::PgExecutor, PgPool};
+use uuid::Uuid;
+
+use crate::{
+    error::ApiError,
+    models::{AddressModel, GroupModel, MediaCounts, ParentModel, PhoneModel, StudentModel, WarningSummary},
+    pagination::{fetch_page, Column, ListQuery, ListSpec, Page, SqlType},
+    schema::{CreateStudentSchema, UpdateStudentSchema},
+};
+
+const STUDENT_LIST: ListSpec = ListSpec {
+    from: "students",
+    select: "id, user_id, name, email, age, birth_date, shirt_size, parent_id, group_id, students_date, household_id",
+    id: "id",
+    sorts: &[
+        Column::new("id", "id", SqlType::Uuid),
+        Column::new("name", "name", SqlType::Text),
+        Column::new("age", "age", SqlType::Int),
+        Column::new("birth_date", "COALESCE(birth_date, 'epoch')", SqlType::Date),
+        Column::new("students_date", "COALESCE(students_date, 'epoch')", SqlType::TimestampTz),
+    ],
+    default_sort: "id",
+    filters: &[
+        Column::new("group_id", "group_id", SqlType::Uuid),
+        Column::new("parent_id", "parent_id", SqlType::Uuid),
+        Column::new("household_id", "household_id", SqlType::Uuid),
+        Column::new("user_id", "user_id", SqlType::Uuid),
+        Column::new("shirt_size", "shirt_size", SqlType::Text),
+    ],
+};
+
+/// Tabela `students`
+pub struct StudentRepo;
+
+impl StudentRepo {
+    pub async fn create<'e>(db: impl PgExecutor<'e>, new: &CreateStudentSchema) -> sqlx::Result<StudentModel> {
+        let query = r#"
+            INSERT INTO students (user_id, name, email, age, birth_date, shirt_size, parent_id, group_id, household_id)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+            RETURNING id, user_id, name, email, age, birth_date, shirt_size, parent_id, group_id, students_date, household_id
+        "#;
+
+        sqlx::query_as::<_, StudentModel>(query)
+            .bind(new.user_id)
+            .bind(&new.name)
+            .bind(&new.email)
+            .bind(new.age)
+            .bind(new.birth_date)
+            .bind(&new.shirt_size)
+            .bind(new.parent_id)       // Option<Uuid>
+            .bind(new.group_id)        // Option<Uuid>
+            .bind(new.household_id)
+            .fetch_one(db)
+            .await
+    }
+
+    /// Página de `GET /students`, com os filtros de `STUDENT_LIST`
+    pub async fn list(db: &PgPool, query: &ListQuery) -> Result<Page<StudentModel>, ApiError> {
+        fetch_page(db, &STUDENT_LIST, query).await
+    }
+
+    pub async fn find<'e>(db: impl PgExecutor<'e>, id: Uuid) -> sqlx::Result<Option<StudentModel>> {
+        sqlx::query_as!(
+            StudentModel,
+            r#"
+            SELECT id, user_id, name, email, age, birth_date, shirt_size, parent_id, group_id, students_date, household_id
+            FROM students
+            WHERE id = $1
+            "#,
+            id
+        )
+        .fetch_optional(db)
+        .await
+    }
+
+    /// Altera só os campos informados; `None` se o aluno não existe
+    pub async fn update<'e>(
+        db: impl PgExecutor<'e>,
+        id: Uuid,
+        changes: &UpdateStudentSchema,
+    ) -> sqlx::Result<Option<StudentModel>> {
+        sqlx::query_as!(
+            StudentModel,
+            r#"
+            UPDATE students SET
+                user_id = COALESCE($1, user_id),
+                name = COALESCE($2, name),
+                email = COALESCE($3, email),
+                age = COALESCE($4, age),
+                birth_date = COALESCE($5, birth_date),
+                shirt_size = COALESCE($6, shirt_size),
+                parent_id = COALESCE($7, parent_id),
+                group_id = COALESCE($8, group_id),
+                household_id = COALESCE($9, household_id)
+            WHERE id = $10
+            RETURNING id, user_id, name, email, age, birth_date, shirt_size, parent_id, group_id, students_date, household_id
+            "#,
+            changes.user_id,
+            changes.name.as_ref(),
+            changes.email.as_ref(),
+            changes.age,
+            changes.birth_date,
+            changes.shirt_size.as_ref(),
+            changes.parent_id,
+            changes.group_id,
+            changes.household_id,
+            id
+        )
+        .fetch_optional(db)
+        .await
+    }
+
+    /// Pai ou mãe ligado em `parent_id`
+    pub async fn parent<'e>(db: impl PgExecutor<'e>, student: &StudentModel) -> sqlx::Result<Option<ParentModel>> {
+        let Some(parent_id) = student.parent_id else {
+            return Ok(None);
+        };
+        sqlx::query_as::<_, ParentModel>("SELECT id, user_id, name, email, parents_date, household_id FROM parents WHERE id = $1")
+            .bind(parent_id)
+            .fetch_optional(db)
+            .await
+    }
+
+    /// Turma ligada em `group_id`
+    pub async fn group<'e>(db: impl PgExecutor<'e>, student: &StudentModel) -> sqlx::Result<Option<GroupModel>> {
+        let Some(group_id) = student.group_id else {
+            return Ok(None);
+        };
+        sqlx::query_as::<_, GroupModel>("SELECT id, name, description, created_at, season, min_age, max_age, coach_id, capacity FROM groups WHERE id = $1")
+            .bind(group_id)
+            .fetch_optional(db)
+            .await
+    }
+
+    /// Endereços do aluno, do seu pai ou mãe e da sua família (o dono vem em
+    /// `student_id`/`parent_id`/`household_id`)
+    pub async fn addresses<'e>(db: impl PgExecutor<'e>, student: &StudentModel) -> sqlx::Result<Vec<AddressModel>> {
+        let query = r#"
+            SELECT id, user_id, parent_id, student_id, guardian_id, household_id, street, city, state, zip_code
+            FROM addresses
+            WHERE student_id = $1
+               OR ($2::uuid IS NOT NULL AND parent_id = $2)
+               OR ($3::uuid IS NOT NULL AND household_id = $3)
+            ORDER BY student_id IS NULL, household_id IS NOT NULL, id
+        "#;
+
+        sqlx::query_as::<_, AddressModel>(query)
+            .bind(student.id)
+            .bind(student.parent_id)
+            .bind(student.household_id)
+            .fetch_all(db)
+            .await
+    }
+
+    /// Telefones do aluno, do seu pai ou mãe e da sua família (o dono vem em
+    /// `student_id`/`parent_id`/`household_id`)
+    pub async fn phones<'e>(db: impl PgExecutor<'e>, student: &StudentModel) -> sqlx::Result<Vec<PhoneModel>> {
+        let query = r#"
+            SELECT id, user_id, parent_id, student_id, guardian_id, household_id, number, phone_type
+            FROM phones
+            WHERE student_id = $1
+               OR ($2::uuid IS NOT NULL AND parent_id = $2)
+               OR ($3::uuid IS NOT NULL AND household_id = $3)
+            ORDER BY student_id IS NULL, household_id IS NOT NULL, id
+        "#;
+
+        sqlx::query_as::<_, PhoneModel>(query)
+            .bind(student.id)
+            .bind(student.parent_id)
+            .bind(student.household_id)
+            .fetch_all(db)
+            .await
+    }
+
+    pub async fn warning_summary<'e>(db: impl PgExecutor<'e>, id: Uuid) -> sqlx::Result<WarningSummary> {
+        let query = r#"
+            SELECT COUNT(*) AS count, MAX(warning_date) AS last_warning_date
+            FROM warnings
+            WHERE student_id = $1
+        "#;
+
+        sqlx::query_as::<_, WarningSummary>(query)
+            .bind(id)
+            .fetch_one(db)
+            .await
+    }
+
+    pub async fn media_counts<'e>(db: impl PgExecutor<'e>, id: Uuid) -> sqlx::Result<MediaCounts> {
+        let query = r#"
+            SELECT (SELECT COUNT(*) FROM photos WHERE student_id = $1) AS photos,
+                   (SELECT COUNT(*) FROM documents WHERE student_id = $1) AS documents,
+                   (SELECT COUNT(*) FROM meusvideos WHERE student_id = $1) AS submissions
+        "#;
+
+        sqlx::query_as::<_, MediaCounts>(query)
+            .bind(id)
+            .fetch_one(db)
+            .await
+    }
+
+    /// `false` se o aluno não existia
+    pub async fn delete<'e>(db: impl PgExecutor<'e>, id: Uuid) -> sqlx::Result<bool> {
+        let result = sqlx::query!("DELETE FROM students WHERE id = $1", id)
+            .execute(db)
+            .await?;
+        Ok(result.rows_affected() > 0)
+    }
+}
@@ -0,0 +1,260 @@
+// backend/src/repo/videos.rs
+use sqlx::{postgres::PgExecutor, PgPool};
+use uuid::Uuid;
+
+use crate::{
+    error::ApiError,
+    models::{VideoModel, VideoSearchHit},
+    pagination::{fetch_page, Column, ListQuery, ListSpec, Page, SqlType},
+    schema::{CreateVideoSchema, UpdateVideoSchema, VideoSearchOptions},
+};
+
+const VIDEO_LIST: ListSpec = ListSpec {
+    from: "videos",
+    select: "id, title, description, thumbnail_path, slug, published_at, is_published, num_likes, num_views, author_id, video_date",
+    id: "id",
+    sorts: &[
+        Column::new("id", "id", SqlType::Uuid),
+        Column::new("title", "title", SqlType::Text),
+        Column::new("published_at", "COALESCE(published_at, 'epoch')", SqlType::Timestamp),
+        Column::new("video_date", "COALESCE(video_date, 'epoch')", SqlType::TimestampTz),
+        Column::new("num_views", "COALESCE(num_views, 0)", SqlType::Int),
+        Column::new("num_likes", "COALESCE(num_likes, 0)", SqlType::Int),
+    ],
+    default_sort: "id",
+    filters: &[
+        Column::new("author_id", "author_id", SqlType::Uuid),
+        Column::new("is_published", "is_published", SqlType::Bool),
+    ],
+};
+
+// Delimitadores dos termos encontrados no `ts_headline` (caracteres de uso
+// privado, tirados do texto antes), trocados por `<mark>` depois de escapar
+const MARK_START: char = '\u{E000}';
+const MARK_END: char = '\u{E001}';
+
+// Escapa o trecho para HTML e marca os termos encontrados
+fn highlight_html(snippet: &str) -> String {
+    let mut html = String::with_capacity(snippet.len());
+    for c in snippet.chars() {
+        match c {
+            MARK_START => html.push_str("<mark>"),
+            MARK_END => html.push_str("</mark>"),
+            '&' => html.push_str("&amp;"),
+            '<' => html.push_str("&lt;"),
+            '>' => html.push_str("&gt;"),
+            '"' => html.push_str("&quot;"),
+            '\'' => html.push_str("&#39;"),
+            c => html.push(c),
+        }
+    }
+    html
+}
+
+/// Tabela `videos` e os redirecionamentos de slugs antigos (`video_slug_redirects`)
+pub struct VideoRepo;
+
+impl VideoRepo {
+    /// Grava o vídeo com o `slug` já normalizado e único (`slug::unique_slug`)
+    pub async fn create<'e>(db: impl PgExecutor<'e>, new: &CreateVideoSchema, slug: &str) -> sqlx::Result<VideoModel> {
+        let query = r#"
+           INSERT INTO videos (title, description, thumbnail_path, slug, published_at, is_published, num_likes, num_views, author_id)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+            RETURNING id, title, description, thumbnail_path, slug, published_at, is_published, num_likes, num_views, author_id, video_date
+        "#;
+
+        sqlx::query_as::<_, VideoModel>(query)
+            .bind(&new.title)
+            .bind(&new.description)
+            .bind(&new.thumbnail_path)
+            .bind(slug)
+            .bind(new.published_at)
+            .bind(new.is_published)
+            .bind(new.num_likes)
+            .bind(new.num_views)
+            .bind(new.author_id)
+            .fetch_one(db)
+            .await
+    }
+
+    /// Página de `GET /videos`, com os filtros de `VIDEO_LIST`
+    pub async fn list(db: &PgPool, query: &ListQuery) -> Result<Page<VideoModel>, ApiError> {
+        fetch_page(db, &VIDEO_LIST, query).await
+    }
+
+    /// Busca textual em título, descrição e nomes das tags, por relevância.
+    /// `q` já vem sem espaços nas pontas; os demais filtros vêm de `opts`.
+    ///
+    /// Um vídeo entra se o título e a descrição (`idx_videos_search_vector`) ou
+    /// o nome de uma das suas tags atendem à consulta inteira; o `snippet` vem
+    /// em HTML escapado, com os termos encontrados em `<mark>`.
+    pub async fn search<'e>(
+        db: impl PgExecutor<'e>,
+        q: &str,
+        opts: &VideoSearchOptions,
+        limit: i64,
+        offset: i64,
+    ) -> sqlx::Result<Vec<VideoSearchHit>> {
+        let query = r#"
+            WITH q AS (SELECT websearch_to_tsquery('portuguese', $1) AS query),
+            matches AS (
+                SELECT id FROM videos WHERE search_vector @@ websearch_to_tsquery('portuguese', $1)
+                UNION
+                SELECT vt.video_id
+                FROM video_tags vt
+                JOIN tags tg ON tg.id = vt.tag_id
+                WHERE to_tsvector('portuguese', tg.name) @@ websearch_to_tsquery('portuguese', $1)
+            )
+            SELECT v.id, v.title, v.description, v.thumbnail_path, v.slug, v.published_at,
+                   v.is_published, v.num_likes, v.num_views, v.author_id, v.video_date,
+                   t.tag_names AS tags,
+                   ts_rank(v.search_vector || coalesce(t.tags_vector, ''::tsvector), q.query) AS rank,
+                   ts_headline('portuguese',
+                               translate(v.title || ' ' || coalesce(v.description, ''), $9, ''),
+                               q.query, $10) AS snippet
+            FROM matches m
+            JOIN videos v ON v.id = m.id
+            CROSS JOIN q
+            LEFT JOIN LATERAL (
+                SELECT array_agg(tg.name ORDER BY tg.name) AS tag_names,
+                       setweight(to_tsvector('portuguese', string_agg(tg.name, ' ')), 'A') AS tags_vector
+                FROM video_tags vt
+                JOIN tags tg ON tg.id = vt.tag_id
+                WHERE vt.video_id = v.id
+            ) t ON TRUE
+            WHERE ($2::text IS NULL OR EXISTS (
+                    SELECT 1 FROM video_tags vt
+                    JOIN tags tg ON tg.id = vt.tag_id
+                    WHERE vt.video_id = v.id AND lower(tg.name) = lower($2)))
+              AND ($3::uuid IS NULL OR v.author_id = $3)
+              AND ($4::date IS NULL OR v.video_date::date >= $4)
+              AND ($5::date IS NULL OR v.video_date::date <= $5)
+              AND ($6::bool IS NULL OR coalesce(v.is_published, FALSE) = $6)
+            ORDER BY rank DESC, v.video_date DESC
+            LIMIT $7 OFFSET $8
+        "#;
+
+        let hits = sqlx::query_as::<_, VideoSearchHit>(query)
+            .bind(q)
+            .bind(&opts.tag)
+            .bind(opts.author_id)
+            .bind(opts.from)
+            .bind(opts.to)
+            .bind(opts.is_published)
+            .bind(limit)
+            .bind(offset)
+            .bind(format!("{}{}", MARK_START, MARK_END))
+            .bind(format!("StartSel={}, StopSel={}, MaxFragments=2, MaxWords=30", MARK_START, MARK_END))
+            .fetch_all(db)
+            .await?;
+
+        Ok(hits
+            .into_iter()
+            .map(|mut hit| {
+                hit.snippet = hit.snippet.as_deref().map(highlight_html);
+                hit
+            })
+            .collect())
+    }
+
+    pub async fn find<'e>(db: impl PgExecutor<'e>, id: Uuid) -> sqlx::Result<Option<VideoModel>> {
+        sqlx::query_as!(
+            VideoModel,
+            r#"
+            SELECT id, title, description, thumbnail_path, slug, published_at,
+                   is_published, num_likes, num_views, author_id, video_date
+            FROM videos
+            WHERE id = $1
+            "#,
+            id
+        )
+        .fetch_optional(db)
+        .await
+    }
+
+    pub async fn exists<'e>(db: impl PgExecutor<'e>, id: Uuid) -> sqlx::Result<bool> {
+        sqlx::query_scalar::<_, bool>("SELECT EXISTS (SELECT 1 FROM videos WHERE id = $1)")
+            .bind(id)
+            .fetch_one(db)
+            .await
+    }
+
+    /// Vídeo com o slug atual `slug` (slugs antigos: `current_slug`)
+    pub async fn find_by_slug<'e>(db: impl PgExecutor<'e>, slug: &str) -> sqlx::Result<Option<VideoModel>> {
+        sqlx::query_as::<_, VideoModel>("SELECT * FROM videos WHERE slug = $1")
+            .bind(slug)
+            .fetch_optional(db)
+            .await
+    }
+
+    /// Slug atual do vídeo que já usou `old_slug`
+    pub async fn current_slug<'e>(db: impl PgExecutor<'e>, old_slug: &str) -> sqlx::Result<Option<String>> {
+        let query = r#"
+            SELECT v.slug FROM video_slug_redirects r
+            JOIN videos v ON v.id = r.video_id
+            WHERE r.old_slug = $1
+        "#;
+
+        let slug = sqlx::query_scalar::<_, Option<String>>(query)
+            .bind(old_slug)
+            .fetch_optional(db)
+            .await?;
+        Ok(slug.flatten())
+    }
+
+    /// Mantém `old_slug` válido como redirecionamento para o vídeo
+    pub async fn redirect_slug<'e>(db: impl PgExecutor<'e>, old_slug: &str, video_id: Uuid) -> sqlx::Result<()> {
+        let query = r#"
+            INSERT INTO video_slug_redirects (old_slug, video_id)
+            VALUES ($1, $2)
+            ON CONFLICT (old_slug) DO UPDATE SET video_id = EXCLUDED.video_id
+        "#;
+
+        sqlx::query(query)
+            .bind(old_slug)
+            .bind(video_id)
+            .execute(db)
+            .await?;
+        Ok(())
+    }
+
+    /// Um slug reaproveitado deixa de ser redirecionamento
+    pub async fn release_slug<'e>(db: impl PgExecutor<'e>, slug: &str) -> sqlx::Result<()> {
+        sqlx::query("DELETE FROM video_slug_redirects WHERE old_slug = $1")
+            .bind(slug)
+            .execute(db)
+            .await?;
+        Ok(())
+    }
+
+    /// Altera título e descrição, se informados, e o slug já resolvido pelo
+    /// chamador; `None` se o vídeo não existe
+    pub async fn update<'e>(
+        db: impl PgExecutor<'e>,
+        id: Uuid,
+        changes: &UpdateVideoSchema,
+        slug: Option<&str>,
+    ) -> sqlx::Result<Option<VideoModel>> {
+        let query = r#"
+            UPDATE videos SET title = COALESCE($1, title), description = COALESCE($2, description),
+                slug = COALESCE($3, slug)
+            WHERE id = $4 RETURNING *
+        "#;
+
+        sqlx::query_as::<_, VideoModel>(query)
+            .bind(&changes.title)
+            .bind(&changes.description)
+            .bind(slug)
+            .bind(id)
+            .fetch_optional(db)
+            .await
+    }
+
+    /// `false` se o vídeo não existia
+    pub async fn delete<'e>(db: impl PgExecutor<'e>, id: Uuid) -> sqlx::Result<bool> {
+        let result = sqlx::query!("DELETE FROM videos WHERE id = $1", id)
+            .execute(db)
+            .await?;
+        Ok(result.rows_affected() > 0)
+    }
+}
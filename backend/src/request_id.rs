@@ -0,0 +1,140 @@
+// backend/src/request_id.rs
+//! Identificador de requisição e span `http_request` por requisição.
+//!
+//! O `X-Request-Id` enviado pelo cliente é reaproveitado quando parece válido;
+//! senão um UUID novo é gerado. O valor vai para o span (e portanto para todas
+//! as linhas de log da requisição), volta no cabeçalho da resposta e fica
+//! disponível para os handlers pelo extrator [`RequestId`], que o repassa às
+//! mensagens publicadas no RabbitMQ. Caminhos com segredo (o token dos feeds
+//! .ics) vão para o span sem ele.
+use actix_web::{
+    body::MessageBody,
+    dev::{Payload, ServiceRequest, ServiceResponse},
+    http::header::{HeaderName, HeaderValue},
+    Error, FromRequest, HttpMessage, HttpRequest,
+};
+use std::{
+    borrow::Cow,
+    future::{ready, Future, Ready},
+    time::Instant,
+};
+use telemetry::{new_request_id, REQUEST_ID_HEADER};
+use tracing::{field::Empty, Instrument};
+
+/// Maior `X-Request-Id` aceito do cliente
+const MAX_LEN: usize = 128;
+
+/// Prefixos de caminho cujo resto é um segredo (o token dos feeds .ics)
+const SECRET_PATHS: &[&str] = &["/api/calendar/"];
+
+/// Identificador da requisição atual
+#[derive(Debug, Clone)]
+pub struct RequestId(pub String);
+
+impl RequestId {
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl FromRequest for RequestId {
+    type Error = Error;
+    type Future = Ready<Result<Self, Error>>;
+
+    fn from_request(req: &HttpRequest, _: &mut Payload) -> Self::Future {
+        // Fora do middleware (não deveria acontecer) gera um avulso
+        let id = req.extensions().get::<RequestId>().cloned();
+        ready(Ok(id.unwrap_or_else(|| RequestId(new_request_id()))))
+    }
+}
+
+// Só aceita valores curtos e imprimíveis, para não poluir logs e cabeçalhos
+fn accepted(value: &str) -> bool {
+    !value.is_empty() && value.len() <= MAX_LEN && value.bytes().all(|b| b.is_ascii_graphic())
+}
+
+// Caminho para os logs, com o segredo trocado por `[redacted]`
+fn loggable_path(path: &str) -> Cow<'_, str> {
+    match SECRET_PATHS.iter().find(|prefix| path.starts_with(**prefix)) {
+        Some(prefix) => Cow::Owned(format!("{}[redacted]", prefix)),
+        None => Cow::Borrowed(path),
+    }
+}
+
+/// Middleware (`App::wrap_fn`) que abre o span da requisição, registra o
+/// resultado ao final e devolve o `X-Request-Id`.
+pub fn propagate<S, B>(req: ServiceRequest, service: &S) -> impl Future<Output = Result<ServiceResponse<B>, Error>>
+where
+    S: actix_web::dev::Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error>,
+    B: MessageBody,
+{
+    let request_id = req
+        .headers()
+        .get(REQUEST_ID_HEADER)
+        .and_then(|value| value.to_str().ok())
+        .filter(|value| accepted(value))
+        .map(str::to_string)
+        .unwrap_or_else(new_request_id);
+    req.extensions_mut().insert(RequestId(request_id.clone()));
+
+    let span = tracing::info_span!(
+        "http_request",
+        request_id = %request_id,
+        method = %req.method(),
+        path = %loggable_path(req.path()),
+        route = Empty,
+        status = Empty,
+    );
+    let started = Instant::now();
+    let response = span.in_scope(|| service.call(req));
+
+    async move {
+        let result = response.await;
+        let span = tracing::Span::current();
+        let elapsed_ms = started.elapsed().as_secs_f64() * 1000.0;
+
+        match result {
+            Ok(mut response) => {
+                if let Some(route) = response.request().match_pattern() {
+                    span.record("route", route.as_str());
+                }
+                let status = response.status();
+                span.record("status", status.as_u16());
+                if status.is_server_error() {
+                    tracing::error!(elapsed_ms, "request failed");
+                } else {
+                    tracing::info!(elapsed_ms, "request completed");
+                }
+
+                if let Ok(value) = HeaderValue::from_str(&request_id) {
+                    response
+                        .headers_mut()
+                        .insert(HeaderName::from_static(REQUEST_ID_HEADER), value);
+                }
+                Ok(response)
+            }
+            Err(error) => {
+                tracing::error!(elapsed_ms, error = %error, "request failed");
+                Err(error)
+            }
+        }
+    }
+    .instrument(span)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn feed_tokens_are_not_logged() {
+        let path = format!("/api/calendar/{}.ics", "ab".repeat(32));
+        assert_eq!(loggable_path(&path), "/api/calendar/[redacted]");
+    }
+
+    #[test]
+    fn other_paths_are_logged_as_is() {
+        assert_eq!(loggable_path("/api/groups/1/calendar_feed"), "/api/groups/1/calendar_feed");
+        assert_eq!(loggable_path("/api/calendar"), "/api/calendar");
+    }
+}
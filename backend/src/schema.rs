@@ -1,370 +1,666 @@
 // src/schema.rs
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
-use chrono::{NaiveDate, NaiveDateTime};
+use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
+use utoipa::{IntoParams, ToSchema};
+use validator::{Validate, ValidationError};
+
+use crate::validation::{age_matches_birth_date, age_range, date_range, time_range};
 
 /// USERS
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Validate, ToSchema)]
 pub struct CreateUserSchema {
+    #[validate(length(min = 1, max = 50))]
     pub username: String,
+    #[validate(length(min = 1, max = 255))]
     pub password_hash: String,  // ou 'password' se for texto puro (veja nota)
+    #[validate(custom = "crate::validation::user_role")]
     pub role: String,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Validate, ToSchema)]
 pub struct UpdateUserSchema {
+    #[validate(length(min = 1, max = 50))]
     pub username: Option<String>,
+    #[validate(length(min = 1, max = 255))]
     pub password_hash: Option<String>,
+    #[validate(custom = "crate::validation::user_role")]
     pub role: Option<String>,
 }
 
 
 /// PARENTS
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
 pub struct CreateParentSchema {
     pub user_id: Option<Uuid>,
+    #[validate(length(max = 100), custom = "crate::validation::not_blank")]
     pub name: String,
+    #[validate(email, length(max = 100))]
     pub email: String,
+    pub household_id: Option<Uuid>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
 pub struct UpdateParentSchema {
     pub user_id: Option<Uuid>,
+    #[validate(length(max = 100), custom = "crate::validation::not_blank")]
     pub name: Option<String>,
+    #[validate(email, length(max = 100))]
     pub email: Option<String>,
+    pub household_id: Option<Uuid>,
 }
 
 /// GUARDIANS
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
 pub struct CreateGuardianSchema {
     pub user_id: Option<Uuid>,
+    #[validate(length(max = 100), custom = "crate::validation::not_blank")]
     pub name: String,
+    #[validate(length(max = 50), custom = "crate::validation::not_blank")]
     pub relationship: String,
+    pub household_id: Option<Uuid>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
 pub struct UpdateGuardianSchema {
     pub user_id: Option<Uuid>,
+    #[validate(length(max = 100), custom = "crate::validation::not_blank")]
     pub name: Option<String>,
+    #[validate(length(max = 50), custom = "crate::validation::not_blank")]
     pub relationship: Option<String>,
+    pub household_id: Option<Uuid>,
+}
+
+/// HOUSEHOLDS
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct CreateHouseholdSchema {
+    #[validate(length(max = 100), custom = "crate::validation::not_blank")]
+    pub name: String,
+}
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct UpdateHouseholdSchema {
+    #[validate(length(max = 100), custom = "crate::validation::not_blank")]
+    pub name: Option<String>,
+}
+
+/// Vínculo aluno-responsável (`PUT /students/{id}/guardians/{guardian_id}`);
+/// substitui o vínculo inteiro, então campos ausentes voltam ao padrão
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct LinkGuardianSchema {
+    /// Parentesco com este aluno
+    #[validate(length(max = 50), custom = "crate::validation::not_blank")]
+    pub relationship: String,
+    /// Autorizado a buscar o aluno (padrão `false`)
+    #[serde(default)]
+    pub can_pick_up: bool,
+    /// Ordem de contato em emergências, a partir de 1; ausente se não é contato de emergência
+    #[validate(range(min = 1))]
+    pub emergency_priority: Option<i32>,
+}
+
+/// Filtros de `GET /students/{id}/guardians`
+#[derive(Debug, Default, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct StudentGuardianFilter {
+    /// Só os autorizados (`true`) ou não autorizados (`false`) a buscar o aluno
+    pub can_pick_up: Option<bool>,
+    /// Só os contatos de emergência (`true`) ou os demais (`false`)
+    pub emergency: Option<bool>,
 }
 
 /// STUDENTS
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+#[validate(schema(function = "validate_student_age"))]
 pub struct CreateStudentSchema {
     pub user_id: Uuid,
+    #[validate(length(max = 100), custom = "crate::validation::not_blank")]
     pub name: String,
+    #[validate(email, length(max = 100))]
     pub email: String,
+    #[validate(range(min = 1, max = 100))]
     pub age: i32,
+    #[validate(custom = "crate::validation::not_in_future")]
     pub birth_date: Option<NaiveDate>,
+    #[validate(length(min = 1, max = 5))]
     pub shirt_size: Option<String>,
     pub parent_id: Option<Uuid>,
     pub group_id: Option<Uuid>,
+    pub household_id: Option<Uuid>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+#[validate(schema(function = "validate_student_age_update"))]
 pub struct UpdateStudentSchema {
     pub user_id: Option<Uuid>,
+    #[validate(length(max = 100), custom = "crate::validation::not_blank")]
     pub name: Option<String>,
+    #[validate(email, length(max = 100))]
     pub email: Option<String>,
+    #[validate(range(min = 1, max = 100))]
     pub age: Option<i32>,
+    #[validate(custom = "crate::validation::not_in_future")]
     pub birth_date: Option<NaiveDate>,
+    #[validate(length(min = 1, max = 5))]
     pub shirt_size: Option<String>,
     pub parent_id: Option<Uuid>,
     pub group_id: Option<Uuid>,
+    pub household_id: Option<Uuid>,
+}
+
+fn validate_student_age(student: &CreateStudentSchema) -> Result<(), ValidationError> {
+    age_matches_birth_date(Some(student.age), student.birth_date)
+}
+
+fn validate_student_age_update(student: &UpdateStudentSchema) -> Result<(), ValidationError> {
+    age_matches_birth_date(student.age, student.birth_date)
 }
 
 /// ADDRESSES
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
 pub struct CreateAddressSchema {
     pub user_id: Option<Uuid>,
     pub parent_id: Option<Uuid>,
     pub student_id: Option<Uuid>,
     pub guardian_id: Option<Uuid>,
+    pub household_id: Option<Uuid>,
+    #[validate(length(max = 100), custom = "crate::validation::not_blank")]
     pub street: String,
+    #[validate(length(max = 50), custom = "crate::validation::not_blank")]
     pub city: String,
+    #[validate(length(max = 50), custom = "crate::validation::not_blank")]
     pub state: String,
+    #[validate(length(max = 10), custom = "crate::validation::not_blank")]
     pub zip_code: String,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
 pub struct UpdateAddressSchema {
     pub user_id: Option<Uuid>,
     pub parent_id: Option<Uuid>,
     pub student_id: Option<Uuid>,
     pub guardian_id: Option<Uuid>,
+    pub household_id: Option<Uuid>,
+    #[validate(length(max = 100), custom = "crate::validation::not_blank")]
     pub street: Option<String>,
+    #[validate(length(max = 50), custom = "crate::validation::not_blank")]
     pub city: Option<String>,
+    #[validate(length(max = 50), custom = "crate::validation::not_blank")]
     pub state: Option<String>,
+    #[validate(length(max = 10), custom = "crate::validation::not_blank")]
     pub zip_code: Option<String>,
 }
 
 /// PHONES
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
 pub struct CreatePhoneSchema {
     pub user_id: Option<Uuid>,
     pub parent_id: Option<Uuid>,
     pub student_id: Option<Uuid>,
     pub guardian_id: Option<Uuid>,
+    pub household_id: Option<Uuid>,
+    #[validate(length(max = 15), custom = "crate::validation::not_blank")]
     pub number: String,
+    #[validate(custom = "crate::validation::phone_type")]
     pub phone_type: Option<String>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
 pub struct UpdatePhoneSchema {
     pub user_id: Option<Uuid>,
     pub parent_id: Option<Uuid>,
     pub student_id: Option<Uuid>,
     pub guardian_id: Option<Uuid>,
+    pub household_id: Option<Uuid>,
+    #[validate(length(max = 15), custom = "crate::validation::not_blank")]
     pub number: Option<String>,    // deve ser Option<String>
+    #[validate(custom = "crate::validation::phone_type")]
     pub phone_type: Option<String>,
 }
 
 /// ATTENDANCES
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
 pub struct CreateAttendanceSchema {
     pub student_id: Uuid,
     pub date: NaiveDate,
+    #[validate(custom = "crate::validation::attendance_status")]
     pub status: String,
     pub notes: Option<String>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
 pub struct UpdateAttendanceSchema {
     pub student_id: Option<Uuid>,
     pub date: Option<NaiveDate>,
+    #[validate(custom = "crate::validation::attendance_status")]
     pub status: Option<String>,
     pub notes: Option<String>,  // <-- adicione essa linha
 }
 
 /// VIDEOS
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
 pub struct CreateVideoSchema {
+    #[validate(length(max = 255), custom = "crate::validation::not_blank")]
     pub title: String,
     pub description: Option<String>,
+    #[validate(length(max = 255))]
     pub thumbnail_path: Option<String>,
+    #[validate(length(max = 100))]
     pub slug: Option<String>,
     pub published_at: Option<NaiveDateTime>,
     pub is_published: Option<bool>,
+    #[validate(range(min = 0))]
     pub num_likes: Option<i32>,
+    #[validate(range(min = 0))]
     pub num_views: Option<i32>,
     pub author_id: Option<Uuid>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
 pub struct UpdateVideoSchema {
+    #[validate(length(max = 255), custom = "crate::validation::not_blank")]
     pub title: Option<String>,
     pub description: Option<String>,
+    #[validate(length(max = 255))]
     pub thumbnail_path: Option<String>,
+    #[validate(length(max = 100))]
     pub slug: Option<String>,
     pub published_at: Option<NaiveDateTime>,
     pub is_published: Option<bool>,
+    #[validate(range(min = 0))]
     pub num_likes: Option<i32>,
+    #[validate(range(min = 0))]
     pub num_views: Option<i32>,
     pub author_id: Option<Uuid>,
 }
 
+/// Seções de `GET /students/{id}/profile`
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct StudentProfileOptions {
+    /// Seções separadas por vírgula: `parent`, `group`, `contacts`,
+    /// `guardians`, `attendance`, `warnings`, `media`. Sem o parâmetro, todas; vazio, só o aluno.
+    pub include: Option<String>,
+    /// Dias cobertos pelo resumo de presenças (padrão 30, máximo 366)
+    pub attendance_days: Option<i64>,
+}
+
+/// Busca textual de vídeos: `q` usa a sintaxe de `websearch_to_tsquery`
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct VideoSearchOptions {
+    pub q: String,
+    pub tag: Option<String>,
+    pub author_id: Option<Uuid>,
+    pub from: Option<NaiveDate>,
+    pub to: Option<NaiveDate>,
+    pub is_published: Option<bool>,
+    pub limit: Option<usize>,
+    pub page: Option<usize>,
+}
+
 /// VIDEO MEDIA
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
 pub struct CreateVideoMediaSchema {
     pub video_id: Uuid,
+    #[validate(custom = "crate::validation::not_blank")]
     pub video_path: String,
+    #[validate(custom = "crate::validation::not_blank")]
     pub status: String,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+/// Rendition criada a partir da rota do vídeo (`POST /videos/{id}/media`)
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
+pub struct CreateRenditionSchema {
+    #[validate(custom = "crate::validation::not_blank")]
+    pub video_path: String,
+    #[validate(custom = "crate::validation::not_blank")]
+    pub status: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
 pub struct UpdateVideoMediaSchema {
     pub video_id: Option<Uuid>,
+    #[validate(custom = "crate::validation::not_blank")]
     pub video_path: Option<String>,
+    #[validate(custom = "crate::validation::not_blank")]
     pub status: Option<String>,
 }
 
 /// TAGS
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
 pub struct CreateTagSchema {
+    #[validate(length(max = 50), custom = "crate::validation::not_blank")]
     pub name: String,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
 pub struct UpdateTagSchema {
+    #[validate(length(max = 50), custom = "crate::validation::not_blank")]
     pub name: Option<String>,
 }
 
+/// Junta a tag de origem em `target_id` (as associações com vídeos são movidas);
+/// `target_id` não pode ser a própria tag de origem
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
+pub struct MergeTagSchema {
+    pub target_id: Uuid,
+}
+
 /// VIDEO TAGS
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct CreateVideoTagSchema {
     pub video_id: Uuid,
     pub tag_id: Uuid,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct UpdateVideoTagSchema {
     pub video_id: Option<Uuid>,
     pub tag_id: Option<Uuid>,
 }
 
+/// Conjunto completo de tags de um vídeo, por nome (tags inexistentes são criadas)
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
+pub struct SetVideoTagsSchema {
+    #[validate(custom = "crate::validation::tag_names")]
+    pub tags: Vec<String>,
+}
+
 /// GROUPS
-#[derive(serde::Deserialize)]
+#[derive(serde::Deserialize, Validate, ToSchema)]
+#[validate(schema(function = "validate_group_ages"))]
 pub struct CreateGroupSchema {
+    #[validate(length(max = 100), custom = "crate::validation::not_blank")]
     pub name: String,
     pub description: Option<String>,
-}
-
-#[derive(serde::Deserialize)]
+    #[validate(range(min = 2000, max = 2100))]
+    pub season: Option<i32>,
+    /// Idade que o aluno completa no ano da temporada
+    #[validate(range(min = 0, max = 100))]
+    pub min_age: Option<i32>,
+    #[validate(range(min = 0, max = 100))]
+    pub max_age: Option<i32>,
+    /// Usuário com o papel `coach`
+    pub coach_id: Option<Uuid>,
+    #[validate(range(min = 1))]
+    pub capacity: Option<i32>,
+}
+
+/// Alteração de uma turma: campo ausente fica como está; todos menos `name`
+/// aceitam `null` para limpar o valor
+#[derive(serde::Deserialize, Validate, ToSchema)]
+#[validate(schema(function = "validate_group_ages_update"))]
 pub struct UpdateGroupSchema {
+    #[validate(length(max = 100), custom = "crate::validation::not_blank")]
     pub name: Option<String>,
-    pub description: Option<String>,
+    #[serde(default, deserialize_with = "nullable")]
+    #[schema(value_type = Option<String>, nullable)]
+    pub description: Option<Option<String>>,
+    #[serde(default, deserialize_with = "nullable")]
+    #[schema(value_type = Option<i32>, nullable)]
+    #[validate(range(min = 2000, max = 2100))]
+    pub season: Option<Option<i32>>,
+    #[serde(default, deserialize_with = "nullable")]
+    #[schema(value_type = Option<i32>, nullable)]
+    #[validate(range(min = 0, max = 100))]
+    pub min_age: Option<Option<i32>>,
+    #[serde(default, deserialize_with = "nullable")]
+    #[schema(value_type = Option<i32>, nullable)]
+    #[validate(range(min = 0, max = 100))]
+    pub max_age: Option<Option<i32>>,
+    #[serde(default, deserialize_with = "nullable")]
+    #[schema(value_type = Option<Uuid>, nullable)]
+    pub coach_id: Option<Option<Uuid>>,
+    #[serde(default, deserialize_with = "nullable")]
+    #[schema(value_type = Option<i32>, nullable)]
+    #[validate(range(min = 1))]
+    pub capacity: Option<Option<i32>>,
+}
+
+fn validate_group_ages(group: &CreateGroupSchema) -> Result<(), ValidationError> {
+    age_range(group.min_age, group.max_age)
+}
+
+fn validate_group_ages_update(group: &UpdateGroupSchema) -> Result<(), ValidationError> {
+    age_range(group.min_age.flatten(), group.max_age.flatten())
+}
+
+/// Temporada de `GET /groups/assignments`
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct GroupAssignmentOptions {
+    /// Ano da temporada
+    pub season: i32,
+}
+
+/// Corpo de `POST /groups/assignments`
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct AssignGroupsSchema {
+    #[validate(range(min = 2000, max = 2100))]
+    pub season: i32,
+}
+
+/// Horário fixo de treino (`POST /groups/{id}/schedules`)
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+#[validate(schema(function = "validate_schedule"))]
+pub struct CreateGroupScheduleSchema {
+    /// Dia da semana ISO: 1 = segunda ... 7 = domingo
+    #[validate(range(min = 1, max = 7))]
+    pub weekday: i32,
+    pub start_time: NaiveTime,
+    pub end_time: NaiveTime,
+    #[validate(length(max = 200), custom = "crate::validation::not_blank")]
+    pub location: Option<String>,
+    pub valid_from: NaiveDate,
+    /// Último dia do horário; ausente para sem fim
+    pub valid_until: Option<NaiveDate>,
+}
+
+/// Alteração de um horário: campo ausente fica como está; `location` e
+/// `valid_until` aceitam `null` para limpar o valor
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+#[validate(schema(function = "validate_schedule_update"))]
+pub struct UpdateGroupScheduleSchema {
+    #[validate(range(min = 1, max = 7))]
+    pub weekday: Option<i32>,
+    pub start_time: Option<NaiveTime>,
+    pub end_time: Option<NaiveTime>,
+    #[serde(default, deserialize_with = "nullable")]
+    #[schema(value_type = Option<String>, nullable)]
+    #[validate(length(max = 200), custom = "crate::validation::not_blank")]
+    pub location: Option<Option<String>>,
+    pub valid_from: Option<NaiveDate>,
+    #[serde(default, deserialize_with = "nullable")]
+    #[schema(value_type = Option<NaiveDate>, nullable)]
+    pub valid_until: Option<Option<NaiveDate>>,
+}
+
+// Distingue o campo ausente (`None`) do `null` explícito (`Some(None)`)
+fn nullable<'de, D, T>(deserializer: D) -> Result<Option<Option<T>>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    Option::<T>::deserialize(deserializer).map(Some)
+}
+
+fn validate_schedule(schedule: &CreateGroupScheduleSchema) -> Result<(), ValidationError> {
+    time_range(Some(schedule.start_time), Some(schedule.end_time))?;
+    date_range(Some(schedule.valid_from), schedule.valid_until)
+}
+
+fn validate_schedule_update(schedule: &UpdateGroupScheduleSchema) -> Result<(), ValidationError> {
+    time_range(schedule.start_time, schedule.end_time)?;
+    date_range(schedule.valid_from, schedule.valid_until.flatten())
+}
+
+/// Período de `GET /groups/{id}/calendar`
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct CalendarOptions {
+    /// Primeiro dia (padrão: hoje)
+    pub from: Option<NaiveDate>,
+    /// Último dia, inclusive (padrão: 4 semanas a partir de `from`; no máximo 366 dias)
+    pub to: Option<NaiveDate>,
 }
 
 /// WARNINGS
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
 pub struct CreateWarningSchema {
     pub student_id: Uuid,
+    #[validate(custom = "crate::validation::not_blank")]
     pub reason: String,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
 pub struct UpdateWarningSchema {
     pub student_id: Option<Uuid>,
+    #[validate(custom = "crate::validation::not_blank")]
     pub reason: Option<String>,
 }
 
 /// CHAT ROOMS
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
 pub struct CreateChatRoomSchema {
+    #[validate(length(max = 100))]
     pub name: Option<String>,
     pub is_group: bool,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
 pub struct UpdateChatRoomSchema {
+    #[validate(length(max = 100))]
     pub name: Option<String>,
     pub is_group: Option<bool>,
 }
 
 /// MESSAGES
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
 pub struct CreateMessageSchema {
     pub room_id: Uuid,
     pub sender_id: Uuid,
+    #[validate(custom = "crate::validation::not_blank")]
     pub content: String,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
 pub struct UpdateMessageSchema {
+    #[validate(custom = "crate::validation::not_blank")]
     pub content: Option<String>,
 }
 
 /// SCHEDULE CHANGES
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
 pub struct CreateScheduleChangeSchema {
     pub group_id: Option<Uuid>,
+    /// Horário da sessão alterada; `old_date` precisa ser o início previsto de uma sessão dele
+    pub schedule_id: Option<Uuid>,
     pub old_date: Option<NaiveDateTime>,
     pub new_date: Option<NaiveDateTime>,
+    #[validate(custom = "crate::validation::not_blank")]
     pub reason: Option<String>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
 pub struct UpdateScheduleChangeSchema {
     pub group_id: Option<Uuid>,
+    pub schedule_id: Option<Uuid>,
     pub old_date: Option<NaiveDateTime>,
     pub new_date: Option<NaiveDateTime>,
+    #[validate(custom = "crate::validation::not_blank")]
     pub reason: Option<String>,
 }
 
 /// DOCUMENTS
-#[derive(Debug, Serialize, Deserialize)]
-pub struct CreateDocumentSchema {
-    pub student_id: Uuid,
-    pub doc_type: String,
-    pub filename: String,
-}
-
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
 pub struct UpdateDocumentSchema {
     pub student_id: Option<Uuid>,
+    #[validate(custom = "crate::validation::not_blank")]
     pub doc_type: Option<String>,
+    #[validate(custom = "crate::validation::not_blank")]
     pub filename: Option<String>,
 }
 
 /// FILE METADATA
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
 pub struct CreateFileMetadataSchema {
     pub user_id: Option<Uuid>,
+    #[validate(custom = "crate::validation::file_type")]
     pub file_type: String,
+    #[validate(custom = "crate::validation::not_blank")]
     pub filename: String,
     pub description: Option<String>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
 pub struct UpdateFileMetadataSchema {
     pub user_id: Option<Uuid>,
+    #[validate(custom = "crate::validation::file_type")]
     pub file_type: Option<String>,
+    #[validate(custom = "crate::validation::not_blank")]
     pub filename: Option<String>,
     pub description: Option<String>,
 }
 
 /// TASKS
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
 pub struct CreateTaskSchema {
+    #[validate(length(max = 255), custom = "crate::validation::not_blank")]
     pub title: String,
+    #[validate(custom = "crate::validation::not_blank")]
     pub content: String,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
 pub struct UpdateTaskSchema {
+    #[validate(length(max = 255), custom = "crate::validation::not_blank")]
     pub title: Option<String>,
+    #[validate(custom = "crate::validation::not_blank")]
     pub content: Option<String>,
 }
 
 /// PHOTOS
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
 pub struct CreatePhotoSchema {
     pub student_id: Uuid,
+    #[validate(custom = "crate::validation::not_blank")]
     pub filename: String,
     pub description: Option<String>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
 pub struct UpdatePhotoSchema {
     pub student_id: Option<Uuid>,
+    #[validate(custom = "crate::validation::not_blank")]
     pub filename: Option<String>,
     pub description: Option<String>,
 }
 
 /// MEUS VÍDEOS
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
 pub struct CreateMeusVideoSchema {
     pub student_id: Uuid,
+    #[validate(custom = "crate::validation::not_blank")]
     pub filename: String,
     pub description: Option<String>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
 pub struct UpdateMeusVideoSchema {
     pub student_id: Option<Uuid>,
+    #[validate(custom = "crate::validation::not_blank")]
     pub filename: Option<String>,
     pub description: Option<String>,
 }
 
 /// LOGS
-#[derive(Debug, Serialize, Deserialize)]
+/// Entrada manual; as alterações em alunos, responsáveis, documentos,
+/// advertências e usuários são registradas pelo servidor. O autor é sempre o
+/// declarado em `X-User-Id` (não autenticado); um `user_id` no corpo é ignorado
+#[derive(Debug, Serialize, Deserialize, Validate, ToSchema)]
 pub struct CreateLogSchema {
-    pub user_id: Option<Uuid>,
+    #[validate(custom = "crate::validation::not_blank")]
     pub action: String,
     pub description: String,
 }
-
-#[derive(Debug, Serialize, Deserialize)]
-pub struct UpdateLogSchema {
-    pub user_id: Option<Uuid>,
-    pub action: Option<String>,
-    pub description: Option<String>,
-}
-
-/// FILTROS
-#[derive(Debug, Serialize, Deserialize)]
-pub struct FilterOptions {
-    pub limit: Option<usize>,
-    pub page: Option<usize>,
-}
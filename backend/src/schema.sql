@@ -1,183 +0,0 @@
--- src/schema.sql
-CREATE EXTENSION IF NOT EXISTS "uuid-ossp";
-
--- Usuários
-CREATE TABLE users (
-    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
-    username TEXT NOT NULL UNIQUE,
-    password_hash TEXT NOT NULL,
-    role TEXT NOT NULL,
-    users_date TIMESTAMPTZ DEFAULT now()
-);
-
--- Pais (pais ou responsáveis primários)
-CREATE TABLE parents (
-    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
-    user_id UUID REFERENCES users(id) ON DELETE CASCADE,
-    name TEXT NOT NULL,
-    email TEXT NOT NULL
-);
-
--- Responsáveis adicionais
-CREATE TABLE guardians (
-    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
-    user_id UUID REFERENCES users(id) ON DELETE CASCADE,
-    name TEXT NOT NULL,
-    relationship TEXT NOT NULL
-);
-
--- Estudantes
-CREATE TABLE students (
-    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
-    user_id UUID REFERENCES users(id) ON DELETE CASCADE,
-    parent_id UUID REFERENCES parents(id) ON DELETE SET NULL,
-    name TEXT NOT NULL,
-    email TEXT NOT NULL,
-    age INTEGER,
-    birthday DATE,
-    shirt_size TEXT,
-    group_type TEXT CHECK (group_type IN ('pequeno', 'medio', 'grande')),
-    warning_count INTEGER DEFAULT 0,
-    created_at TIMESTAMPTZ DEFAULT now()
-);
-
--- Endereços
-CREATE TABLE addresses (
-    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
-    user_id UUID REFERENCES users(id) ON DELETE CASCADE,
-    parent_id UUID REFERENCES parents(id) ON DELETE CASCADE,
-    student_id UUID REFERENCES students(id) ON DELETE CASCADE,
-    guardian_id UUID REFERENCES guardians(id) ON DELETE CASCADE,
-    street TEXT NOT NULL,
-    city TEXT NOT NULL,
-    state TEXT NOT NULL,
-    zip_code TEXT NOT NULL
-);
-
--- Telefones
-CREATE TABLE phones (
-    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
-    user_id UUID REFERENCES users(id) ON DELETE CASCADE,
-    parent_id UUID REFERENCES parents(id) ON DELETE CASCADE,
-    student_id UUID REFERENCES students(id) ON DELETE CASCADE,
-    guardian_id UUID REFERENCES guardians(id) ON DELETE CASCADE,
-    number TEXT NOT NULL,
-    phone_type TEXT
-);
-
--- Presenças
-CREATE TABLE attendances (
-    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
-    student_id UUID REFERENCES students(id) ON DELETE CASCADE,
-    date DATE NOT NULL,
-    status TEXT CHECK (status IN ('presente', 'faltou')),
-    created_at TIMESTAMPTZ DEFAULT now()
-);
-
--- Vídeos
-CREATE TABLE videos (
-    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
-    title TEXT NOT NULL,
-    description TEXT,
-    thumbnail_path TEXT,
-    slug TEXT,
-    published_at TIMESTAMPTZ,
-    is_published BOOLEAN,
-    num_likes INTEGER DEFAULT 0,
-    num_views INTEGER DEFAULT 0,
-    author_id UUID REFERENCES users(id) ON DELETE SET NULL
-);
-
--- Mídias de vídeos (arquivos)
-CREATE TABLE videomedias (
-    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
-    video_id UUID REFERENCES videos(id) ON DELETE CASCADE,
-    video_path TEXT NOT NULL,
-    status TEXT NOT NULL
-);
-
--- Tags
-CREATE TABLE tags (
-    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
-    name TEXT NOT NULL UNIQUE
-);
-
--- Associação de vídeos com tags
-CREATE TABLE tagvideos (
-    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
-    video_id UUID REFERENCES videos(id) ON DELETE CASCADE,
-    tag_id UUID REFERENCES tags(id) ON DELETE CASCADE
-);
-
--- Meus vídeos (vídeos enviados por alunos)
-CREATE TABLE meusvideos (
-    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
-    student_id UUID REFERENCES students(id) ON DELETE CASCADE,
-    filename TEXT NOT NULL,
-    description TEXT
-);
-
--- Documentos
-CREATE TABLE documents (
-    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
-    student_id UUID REFERENCES students(id) ON DELETE CASCADE,
-    doc_type TEXT NOT NULL,
-    filename TEXT NOT NULL
-);
-
--- Fotos
-CREATE TABLE photos (
-    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
-    student_id UUID REFERENCES students(id) ON DELETE CASCADE,
-    filename TEXT NOT NULL,
-    description TEXT
-);
-
--- Metadados de arquivos
-CREATE TABLE filemetadata (
-    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
-    user_id UUID REFERENCES users(id) ON DELETE SET NULL,
-    file_type TEXT NOT NULL,
-    filename TEXT NOT NULL,
-    description TEXT
-);
-
--- Tarefas
-CREATE TABLE tasks (
-    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
-    title TEXT NOT NULL,
-    content TEXT NOT NULL,
-    created_at TIMESTAMPTZ DEFAULT now()
-);
-
--- Logs de atividades
-CREATE TABLE logs (
-    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
-    user_id UUID REFERENCES users(id) ON DELETE SET NULL,
-    action TEXT NOT NULL,
-    description TEXT NOT NULL,
-    created_at TIMESTAMPTZ DEFAULT now()
-);
-
--- Mensagens entre usuários
-CREATE TABLE messages (
-    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
-    sender_id UUID REFERENCES users(id),
-    receiver_id UUID REFERENCES users(id),
-    content TEXT NOT NULL,
-    sent_at TIMESTAMPTZ DEFAULT now()
-);
-
-#[derive(Debug, Serialize, Deserialize)]
-pub struct CreateAttendanceSchema {
-    pub student_id: Uuid,
-    pub date: NaiveDate,
-    pub status: String, // "presente" ou "falta"
-}
-
-#[derive(Debug, Serialize, Deserialize)]
-pub struct UpdateAttendanceSchema {
-    pub student_id: Option<Uuid>,
-    pub date: Option<NaiveDate>,
-    pub status: Option<String>,
-}
@@ -1,180 +1,202 @@
 // src/services/addresses.rs
 use actix_web::{
     delete, get, patch, post,
-    web::{Data, Json, Path, ServiceConfig},
-    HttpResponse, Responder,
+    web::{Data, Path, ServiceConfig},
+    HttpResponse,
 };
 use serde_json::json;
 use crate::{
+    validation::ValidatedJson,
+    error::ApiError,
+    pagination::{Column, ListParams, ListQuery, ListSpec, SqlType, fetch_page},
     models::AddressModel,
     schema::{CreateAddressSchema, UpdateAddressSchema},
     AppState
 };
-use sqlx::PgPool;
 use uuid::Uuid;
 
+#[utoipa::path(
+    tag = "addresses",
+    request_body = CreateAddressSchema,
+    responses(
+        (status = 201, description = "Registro criado", body = AddressResponse),
+        (status = 400, description = "Requisição inválida", body = ErrorBody),
+        (status = 409, description = "Conflito com um registro existente", body = ErrorBody),
+        (status = 422, description = "Referência a um registro inexistente", body = ErrorBody),
+    )
+)]
 #[post("/addresses")]
 async fn create_address(
-    body: Json<CreateAddressSchema>,
+    body: ValidatedJson<CreateAddressSchema>,
     data: Data<AppState>,
-) -> impl Responder {
+) -> Result<HttpResponse, ApiError> {
     let query = r#"
-        INSERT INTO addresses (user_id, parent_id, student_id, guardian_id, street, city, state, zip_code)
-        VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
-        RETURNING id, user_id, parent_id, student_id, guardian_id, street, city, state, zip_code
+        INSERT INTO addresses (user_id, parent_id, student_id, guardian_id, household_id, street, city, state, zip_code)
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+        RETURNING id, user_id, parent_id, student_id, guardian_id, household_id, street, city, state, zip_code
     "#;
 
-    match sqlx::query_as::<_, AddressModel>(query)
-        //.bind(Uuid::new_v4()) // Gera um novo UUID para o endereço
+    let address = sqlx::query_as::<_, AddressModel>(query)
         .bind(body.user_id)
         .bind(body.parent_id)
         .bind(body.student_id)
         .bind(body.guardian_id)
+        .bind(body.household_id)
         .bind(&body.street)
         .bind(&body.city)
         .bind(&body.state)
         .bind(&body.zip_code)
         .fetch_one(&data.db)
-        .await
-    {
-        Ok(address) => {
-            let response = json!( {
-                "status": "success",
-                "address": {
-                    "id": address.id,
-                    "user_id": address.user_id,
-                    "parent_id": address.parent_id,
-                    "student_id": address.student_id,
-                    "guardian_id": address.guardian_id,
-                    "street": address.street,
-                    "city": address.city,
-                    "state": address.state,
-                    "zip_code": address.zip_code,
-                }
-            });
-            HttpResponse::Created().json(response)
-        }
-        Err(error) => {
-            let response = json!( {
-                "status": "error",
-                "message": format!("Failed to create address: {:?}", error)
-            });
-            HttpResponse::InternalServerError().json(response)
-        }
-    }
+        .await?;
+
+    Ok(HttpResponse::Created().json(json!({"status": "success", "address": address})))
 }
 
+const ADDRESS_LIST: ListSpec = ListSpec {
+    from: "addresses",
+    select: "id, user_id, parent_id, student_id, guardian_id, household_id, street, city, state, zip_code",
+    id: "id",
+    sorts: &[
+        Column::new("id", "id", SqlType::Uuid),
+        Column::new("city", "city", SqlType::Text),
+        Column::new("zip_code", "zip_code", SqlType::Text),
+    ],
+    default_sort: "id",
+    filters: &[
+        Column::new("user_id", "user_id", SqlType::Uuid),
+        Column::new("parent_id", "parent_id", SqlType::Uuid),
+        Column::new("student_id", "student_id", SqlType::Uuid),
+        Column::new("guardian_id", "guardian_id", SqlType::Uuid),
+        Column::new("household_id", "household_id", SqlType::Uuid),
+        Column::new("city", "city", SqlType::Text),
+        Column::new("state", "state", SqlType::Text),
+        Column::new("zip_code", "zip_code", SqlType::Text),
+    ],
+};
+
+#[utoipa::path(
+    tag = "addresses",
+    params(
+        ListParams,
+        ("user_id" = Option<Uuid>, Query, description = "Filtra por `user_id`"),
+        ("parent_id" = Option<Uuid>, Query, description = "Filtra por `parent_id`"),
+        ("student_id" = Option<Uuid>, Query, description = "Filtra por `student_id`"),
+        ("guardian_id" = Option<Uuid>, Query, description = "Filtra por `guardian_id`"),
+        ("household_id" = Option<Uuid>, Query, description = "Filtra por `household_id`"),
+        ("city" = Option<String>, Query, description = "Filtra por `city`"),
+        ("state" = Option<String>, Query, description = "Filtra por `state`"),
+        ("zip_code" = Option<String>, Query, description = "Filtra por `zip_code`"),
+    ),
+    responses(
+        (status = 200, description = "Página de resultados", body = AddressesPage),
+        (status = 400, description = "Requisição inválida", body = ErrorBody),
+    )
+)]
 #[get("/addresses")]
-async fn get_all_addresses(data: Data<AppState>) -> impl Responder {
-    let query = "SELECT id, user_id, parent_id, student_id, guardian_id, street, city, state, zip_code FROM addresses ORDER BY id";
+async fn get_all_addresses(
+    query: ListQuery,
+    data: Data<AppState>
+) -> Result<HttpResponse, ApiError> {
+    let page = fetch_page::<AddressModel>(&data.db, &ADDRESS_LIST, &query).await?;
 
-    match sqlx::query_as::<_, AddressModel>(query).fetch_all(&data.db).await {
-        Ok(addresses) => HttpResponse::Ok().json(json!({"status": "success", "addresses": addresses})),
-        Err(error) => HttpResponse::InternalServerError().json(json!({"status": "error", "message": format!("Failed to get addresses: {:?}", error)})),
-    }
+    Ok(HttpResponse::Ok().json(page.to_json("addresses")))
 }
 
+#[utoipa::path(
+    tag = "addresses",
+    responses(
+        (status = 200, description = "Registro encontrado", body = AddressResponse),
+        (status = 404, description = "Registro não encontrado", body = ErrorBody),
+    )
+)]
 #[get("/addresses/{id}")]
 async fn get_address_by_id(
     path: Path<Uuid>,
     data: Data<AppState>,
-) -> impl Responder {
+) -> Result<HttpResponse, ApiError> {
     let address_id = path.into_inner();
 
-    match sqlx::query_as!(AddressModel, "SELECT * FROM addresses WHERE id = $1", address_id)
-        .fetch_one(&data.db)
-        .await
-    {
-        Ok(address) => HttpResponse::Ok().json(json!({"status": "success", "address": address})),
-        Err(error) => HttpResponse::InternalServerError().json(json!({"status": "error", "message": format!("Failed to get address: {:?}", error)})),
-    }
+    let address = sqlx::query_as!(AddressModel, "SELECT * FROM addresses WHERE id = $1", address_id)
+        .fetch_optional(&data.db)
+        .await?
+        .ok_or_else(|| ApiError::not_found("Address"))?;
+
+    Ok(HttpResponse::Ok().json(json!({"status": "success", "address": address})))
 }
 
+#[utoipa::path(
+    tag = "addresses",
+    request_body = UpdateAddressSchema,
+    responses(
+        (status = 200, description = "Registro atualizado", body = AddressResponse),
+        (status = 400, description = "Requisição inválida", body = ErrorBody),
+        (status = 404, description = "Registro não encontrado", body = ErrorBody),
+        (status = 409, description = "Conflito com um registro existente", body = ErrorBody),
+        (status = 422, description = "Referência a um registro inexistente", body = ErrorBody),
+    )
+)]
 #[patch("/addresses/{id}")]
 async fn update_address_by_id(
     path: Path<Uuid>,
-    body: Json<UpdateAddressSchema>,
+    body: ValidatedJson<UpdateAddressSchema>,
     data: Data<AppState>,
-) -> impl Responder {
+) -> Result<HttpResponse, ApiError> {
     let address_id = path.into_inner();
 
-    match sqlx::query_as!(AddressModel, "SELECT * FROM addresses WHERE id = $1", address_id)
-        .fetch_one(&data.db)
-        .await
-    {
-        Ok(_) => {
-            let update_result = sqlx::query_as!(
-                AddressModel,
-                "UPDATE addresses SET 
-                    user_id = COALESCE($1, user_id),
-                    parent_id = COALESCE($2, parent_id),
-                    student_id = COALESCE($3, student_id),
-                    guardian_id = COALESCE($4, guardian_id),
-                    street = COALESCE($5, street), 
-                    city = COALESCE($6, city), 
-                    state = COALESCE($7, state), 
-                    zip_code = COALESCE($8, zip_code) 
-                WHERE id = $9 RETURNING *",
-                body.user_id.as_ref(),
-                body.parent_id.as_ref(),
-                body.student_id.as_ref(),
-                body.guardian_id.as_ref(),
-                body.street.as_ref(),
-                body.city.as_ref(),
-                body.state.as_ref(),
-                body.zip_code.as_ref(),
-                address_id
-            )
-            .fetch_one(&data.db)
-            .await;
-
-            match update_result {
-                Ok(updated_address) => {
-                    let response = json!( {
-                        "status": "success",
-                        "address": updated_address
-                    });
-                    HttpResponse::Ok().json(response)
-                }
-                Err(update_error) => {
-                    let response = json!( {
-                        "status": "error",
-                        "message": format!("Failed to update address: {:?}", update_error)
-                    });
-                    HttpResponse::InternalServerError().json(response)
-                }
-            }
-        }
-        Err(fetch_error) => {
-            let response = json!( {
-                "status": "error",
-                "message": format!("Address not found: {:?}", fetch_error)
-            });
-            HttpResponse::NotFound().json(response)
-        }
-    }
+    let updated_address = sqlx::query_as!(
+        AddressModel,
+        "UPDATE addresses SET
+            user_id = COALESCE($1, user_id),
+            parent_id = COALESCE($2, parent_id),
+            student_id = COALESCE($3, student_id),
+            guardian_id = COALESCE($4, guardian_id),
+            household_id = COALESCE($5, household_id),
+            street = COALESCE($6, street),
+            city = COALESCE($7, city),
+            state = COALESCE($8, state),
+            zip_code = COALESCE($9, zip_code)
+        WHERE id = $10 RETURNING *",
+        body.user_id.as_ref(),
+        body.parent_id.as_ref(),
+        body.student_id.as_ref(),
+        body.guardian_id.as_ref(),
+        body.household_id.as_ref(),
+        body.street.as_ref(),
+        body.city.as_ref(),
+        body.state.as_ref(),
+        body.zip_code.as_ref(),
+        address_id
+    )
+    .fetch_optional(&data.db)
+    .await?
+    .ok_or_else(|| ApiError::not_found("Address"))?;
+
+    Ok(HttpResponse::Ok().json(json!({"status": "success", "address": updated_address})))
 }
 
+#[utoipa::path(
+    tag = "addresses",
+    responses(
+        (status = 204, description = "Registro removido"),
+        (status = 404, description = "Registro não encontrado", body = ErrorBody),
+    )
+)]
 #[delete("/addresses/{id}")]
 async fn delete_address_by_id(
     path: Path<Uuid>,
     data: Data<AppState>,
-) -> impl Responder {
+) -> Result<HttpResponse, ApiError> {
     let address_id = path.into_inner();
 
-    match sqlx::query!("DELETE FROM addresses WHERE id = $1", address_id)
+    let result = sqlx::query!("DELETE FROM addresses WHERE id = $1", address_id)
         .execute(&data.db)
-        .await
-    {
-        Ok(_) => HttpResponse::NoContent().finish(),
-        Err(err) => {
-            let response = json!( {
-                "status": "error",
-                "message": format!("Failed to delete address: {:?}", err)
-            });
-            HttpResponse::InternalServerError().json(response)
-        }
+        .await?;
+
+    if result.rows_affected() == 0 {
+        return Err(ApiError::not_found("Address"));
     }
+
+    Ok(HttpResponse::NoContent().finish())
 }
 
 // Configuração das rotas para endereços
@@ -0,0 +1,191 @@
+// backend/src/services/calendar_feeds.rs
+//! Feeds .ics dos treinos, para assinar no calendário do celular. A URL leva
+//! um token secreto no lugar de login, já que os apps de calendário não mandam
+//! cabeçalhos; gerar o feed de novo troca o token e a URL antiga para de valer.
+use actix_web::{
+    get, post, delete,
+    web::{Data, Path, ServiceConfig},
+    HttpResponse,
+};
+use chrono::{Duration, Local, Utc};
+use futures_util::future::try_join_all;
+use rand::{rngs::OsRng, RngCore};
+use serde_json::json;
+use uuid::Uuid;
+
+use crate::{
+    calendar, ics,
+    error::ApiError,
+    repo::{CalendarFeedRepo, FeedOwner, GroupRepo, HouseholdRepo},
+    AppState,
+};
+
+/// Dias passados e futuros cobertos pelo feed
+const FEED_PAST_DAYS: i64 = 28;
+const FEED_FUTURE_DAYS: i64 = 182;
+
+/// Bytes aleatórios do token (64 caracteres em hexadecimal)
+const TOKEN_BYTES: usize = 32;
+
+// Token do gerador do sistema operacional, em hexadecimal
+fn new_token() -> String {
+    let mut bytes = [0u8; TOKEN_BYTES];
+    OsRng.fill_bytes(&mut bytes);
+    bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+async fn rotate_feed(data: &AppState, owner: FeedOwner) -> Result<HttpResponse, ApiError> {
+    let (feed, created) = CalendarFeedRepo::rotate(&data.db, owner, &new_token()).await?;
+
+    let mut response = if created { HttpResponse::Created() } else { HttpResponse::Ok() };
+    Ok(response.json(json!({
+        "status": "success",
+        "path": format!("/api/calendar/{}.ics", feed.token),
+        "calendar_feed": feed
+    })))
+}
+
+async fn delete_feed(data: &AppState, owner: FeedOwner) -> Result<HttpResponse, ApiError> {
+    if !CalendarFeedRepo::delete(&data.db, owner).await? {
+        return Err(ApiError::not_found("Calendar feed"));
+    }
+
+    Ok(HttpResponse::NoContent().finish())
+}
+
+/// Gera o feed .ics da turma (201) ou troca o token do existente (200)
+#[utoipa::path(
+    tag = "groups",
+    responses(
+        (status = 200, description = "Token trocado; a URL anterior deixou de valer", body = CalendarFeedResponse),
+        (status = 201, description = "Feed criado", body = CalendarFeedResponse),
+        (status = 404, description = "Turma não encontrada", body = ErrorBody),
+    )
+)]
+#[post("/groups/{id}/calendar_feed")]
+async fn create_group_feed(
+    path: Path<Uuid>,
+    data: Data<AppState>
+) -> Result<HttpResponse, ApiError> {
+    let group_id = path.into_inner();
+
+    GroupRepo::find(&data.db, group_id)
+        .await?
+        .ok_or_else(|| ApiError::not_found("Group"))?;
+    rotate_feed(&data, FeedOwner::Group(group_id)).await
+}
+
+/// Desativa o feed .ics da turma
+#[utoipa::path(
+    tag = "groups",
+    responses(
+        (status = 204, description = "Feed removido"),
+        (status = 404, description = "Feed não encontrado", body = ErrorBody),
+    )
+)]
+#[delete("/groups/{id}/calendar_feed")]
+async fn delete_group_feed(
+    path: Path<Uuid>,
+    data: Data<AppState>
+) -> Result<HttpResponse, ApiError> {
+    delete_feed(&data, FeedOwner::Group(path.into_inner())).await
+}
+
+/// Gera o feed .ics da família, com os treinos das turmas dos seus alunos (201),
+/// ou troca o token do existente (200)
+#[utoipa::path(
+    tag = "households",
+    responses(
+        (status = 200, description = "Token trocado; a URL anterior deixou de valer", body = CalendarFeedResponse),
+        (status = 201, description = "Feed criado", body = CalendarFeedResponse),
+        (status = 404, description = "Família não encontrada", body = ErrorBody),
+    )
+)]
+#[post("/households/{id}/calendar_feed")]
+async fn create_household_feed(
+    path: Path<Uuid>,
+    data: Data<AppState>
+) -> Result<HttpResponse, ApiError> {
+    let household_id = path.into_inner();
+
+    HouseholdRepo::find(&data.db, household_id)
+        .await?
+        .ok_or_else(|| ApiError::not_found("Household"))?;
+    rotate_feed(&data, FeedOwner::Household(household_id)).await
+}
+
+/// Desativa o feed .ics da família
+#[utoipa::path(
+    tag = "households",
+    responses(
+        (status = 204, description = "Feed removido"),
+        (status = 404, description = "Feed não encontrado", body = ErrorBody),
+    )
+)]
+#[delete("/households/{id}/calendar_feed")]
+async fn delete_household_feed(
+    path: Path<Uuid>,
+    data: Data<AppState>
+) -> Result<HttpResponse, ApiError> {
+    delete_feed(&data, FeedOwner::Household(path.into_inner())).await
+}
+
+/// Calendário iCalendar do feed: os treinos das últimas 4 semanas e dos
+/// próximos 6 meses, com cancelamentos e remarcações
+#[utoipa::path(
+    tag = "groups",
+    responses(
+        (status = 200, description = "Calendário RFC 5545", body = String, content_type = "text/calendar"),
+        (status = 404, description = "Feed não encontrado", body = ErrorBody),
+    )
+)]
+#[get("/calendar/{token}.ics")]
+async fn get_calendar_feed(
+    path: Path<String>,
+    data: Data<AppState>
+) -> Result<HttpResponse, ApiError> {
+    let feed = CalendarFeedRepo::find_by_token(&data.db, &path.into_inner())
+        .await?
+        .ok_or_else(|| ApiError::not_found("Calendar feed"))?;
+
+    let (name, groups) = match (feed.group_id, feed.household_id) {
+        (Some(group_id), _) => {
+            let group = GroupRepo::find(&data.db, group_id)
+                .await?
+                .ok_or_else(|| ApiError::not_found("Group"))?;
+            (group.name.clone(), vec![group])
+        }
+        (None, Some(household_id)) => {
+            let household = HouseholdRepo::find(&data.db, household_id)
+                .await?
+                .ok_or_else(|| ApiError::not_found("Household"))?;
+            (household.name, GroupRepo::for_household(&data.db, household_id).await?)
+        }
+        (None, None) => return Err(ApiError::not_found("Calendar feed")),
+    };
+
+    let today = Local::now().date_naive();
+    let from = today - Duration::days(FEED_PAST_DAYS);
+    let to = today + Duration::days(FEED_FUTURE_DAYS);
+    let per_group = try_join_all(groups.iter().map(|group| calendar::sessions(&data.db, group.id, from, to))).await?;
+
+    let summaries: Vec<String> = groups.iter().map(|group| format!("Treino {}", group.name)).collect();
+    let mut events: Vec<(&str, _)> = summaries
+        .iter()
+        .zip(&per_group)
+        .flat_map(|(summary, sessions)| sessions.iter().map(move |session| (summary.as_str(), session)))
+        .collect();
+    events.sort_by_key(|(_, session)| session.starts_at);
+
+    let body = ics::render(&format!("Treinos {}", name), events, Utc::now());
+    Ok(HttpResponse::Ok().content_type(ics::CONTENT_TYPE).body(body))
+}
+
+// Configuração das rotas
+pub fn config_calendar_feeds(conf: &mut ServiceConfig) {
+    conf.service(create_group_feed)
+        .service(delete_group_feed)
+        .service(create_household_feed)
+        .service(delete_household_feed)
+        .service(get_calendar_feed);
+}
@@ -1,75 +1,120 @@
 // src/services/chat_rooms.rs
-use actix_web::{get, post, delete, patch, web::{Data, Json, Path, Query, ServiceConfig}, HttpResponse, Responder};
+use actix_web::{get, post, delete, patch, web::{Data, Path, ServiceConfig}, HttpResponse};
 use serde_json::json;
 use uuid::Uuid;
 
 use crate::{
+    validation::ValidatedJson,
+    error::ApiError,
+    pagination::{Column, ListParams, ListQuery, ListSpec, SqlType, fetch_page},
     models::ChatRoomModel,
-    schema::{CreateChatRoomSchema, UpdateChatRoomSchema, FilterOptions},
+    schema::{CreateChatRoomSchema, UpdateChatRoomSchema},
     AppState
 };
 
+#[utoipa::path(
+    tag = "chat",
+    request_body = CreateChatRoomSchema,
+    responses(
+        (status = 201, description = "Registro criado", body = ChatRoomResponse),
+        (status = 400, description = "Requisição inválida", body = ErrorBody),
+        (status = 409, description = "Conflito com um registro existente", body = ErrorBody),
+        (status = 422, description = "Referência a um registro inexistente", body = ErrorBody),
+    )
+)]
 #[post("/chat_rooms")]
 async fn create_chat_room(
-    body: Json<CreateChatRoomSchema>,
+    body: ValidatedJson<CreateChatRoomSchema>,
     data: Data<AppState>
-) -> impl Responder {
+) -> Result<HttpResponse, ApiError> {
     let query = r#"
         INSERT INTO chat_rooms (name, is_group)
         VALUES ($1, $2)
         RETURNING id, name, is_group, created_at
     "#;
 
-    match sqlx::query_as::<_, ChatRoomModel>(query)
+    let room = sqlx::query_as::<_, ChatRoomModel>(query)
         .bind(&body.name)
         .bind(body.is_group)
         .fetch_one(&data.db)
-        .await
-    {
-        Ok(room) => HttpResponse::Created().json(json!({ "status": "success", "chat_room": room })),
-        Err(err) => HttpResponse::InternalServerError().json(json!({ "status": "error", "message": format!("{:?}", err) }))
-    }
+        .await?;
+
+    Ok(HttpResponse::Created().json(json!({ "status": "success", "chat_room": room })))
 }
 
+const CHAT_ROOM_LIST: ListSpec = ListSpec {
+    from: "chat_rooms",
+    select: "id, name, is_group, created_at",
+    id: "id",
+    sorts: &[
+        Column::new("id", "id", SqlType::Uuid),
+        Column::new("name", "COALESCE(name, '')", SqlType::Text),
+        Column::new("created_at", "COALESCE(created_at, 'epoch')", SqlType::TimestampTz),
+    ],
+    default_sort: "-created_at",
+    filters: &[Column::new("is_group", "is_group", SqlType::Bool)],
+};
+
+#[utoipa::path(
+    tag = "chat",
+    params(
+        ListParams,
+        ("is_group" = Option<bool>, Query, description = "Filtra por `is_group`"),
+    ),
+    responses(
+        (status = 200, description = "Página de resultados", body = ChatRoomsPage),
+        (status = 400, description = "Requisição inválida", body = ErrorBody),
+    )
+)]
 #[get("/chat_rooms")]
 async fn get_all_chat_rooms(
-    opts: Query<FilterOptions>,
+    query: ListQuery,
     data: Data<AppState>
-) -> impl Responder {
-    let limit = opts.limit.unwrap_or(10);
-    let offset = (opts.page.unwrap_or(1) - 1) * limit;
-
-    match sqlx::query_as!(ChatRoomModel, "SELECT * FROM chat_rooms ORDER BY created_at DESC LIMIT $1 OFFSET $2", limit as i32, offset as i32)
-        .fetch_all(&data.db)
-        .await
-    {
-        Ok(rooms) => HttpResponse::Ok().json(json!({ "status": "success", "chat_rooms": rooms })),
-        Err(err) => HttpResponse::InternalServerError().json(json!({ "status": "error", "message": format!("{:?}", err) }))
-    }
+) -> Result<HttpResponse, ApiError> {
+    let page = fetch_page::<ChatRoomModel>(&data.db, &CHAT_ROOM_LIST, &query).await?;
+
+    Ok(HttpResponse::Ok().json(page.to_json("chat_rooms")))
 }
 
+#[utoipa::path(
+    tag = "chat",
+    responses(
+        (status = 200, description = "Registro encontrado", body = ChatRoomResponse),
+        (status = 404, description = "Registro não encontrado", body = ErrorBody),
+    )
+)]
 #[get("/chat_rooms/{id}")]
 async fn get_chat_room_by_id(
     path: Path<Uuid>,
     data: Data<AppState>
-) -> impl Responder {
+) -> Result<HttpResponse, ApiError> {
     let id = path.into_inner();
 
-    match sqlx::query_as!(ChatRoomModel, "SELECT * FROM chat_rooms WHERE id = $1", id)
-        .fetch_one(&data.db)
-        .await
-    {
-        Ok(room) => HttpResponse::Ok().json(json!({ "status": "success", "chat_room": room })),
-        Err(err) => HttpResponse::NotFound().json(json!({ "status": "error", "message": format!("{:?}", err) }))
-    }
+    let room = sqlx::query_as!(ChatRoomModel, "SELECT * FROM chat_rooms WHERE id = $1", id)
+        .fetch_optional(&data.db)
+        .await?
+        .ok_or_else(|| ApiError::not_found("Chat room"))?;
+
+    Ok(HttpResponse::Ok().json(json!({ "status": "success", "chat_room": room })))
 }
 
+#[utoipa::path(
+    tag = "chat",
+    request_body = UpdateChatRoomSchema,
+    responses(
+        (status = 200, description = "Registro atualizado", body = ChatRoomResponse),
+        (status = 400, description = "Requisição inválida", body = ErrorBody),
+        (status = 404, description = "Registro não encontrado", body = ErrorBody),
+        (status = 409, description = "Conflito com um registro existente", body = ErrorBody),
+        (status = 422, description = "Referência a um registro inexistente", body = ErrorBody),
+    )
+)]
 #[patch("/chat_rooms/{id}")]
 async fn update_chat_room_by_id(
     path: Path<Uuid>,
-    body: Json<UpdateChatRoomSchema>,
+    body: ValidatedJson<UpdateChatRoomSchema>,
     data: Data<AppState>
-) -> impl Responder {
+) -> Result<HttpResponse, ApiError> {
     let id = path.into_inner();
 
     let query = r#"
@@ -79,32 +124,40 @@ async fn update_chat_room_by_id(
         RETURNING id, name, is_group, created_at
     "#;
 
-    match sqlx::query_as::<_, ChatRoomModel>(query)
+    let room = sqlx::query_as::<_, ChatRoomModel>(query)
         .bind(&body.name)
         .bind(body.is_group)
         .bind(id)
-        .fetch_one(&data.db)
-        .await
-    {
-        Ok(room) => HttpResponse::Ok().json(json!({ "status": "success", "chat_room": room })),
-        Err(err) => HttpResponse::InternalServerError().json(json!({ "status": "error", "message": format!("{:?}", err) }))
-    }
+        .fetch_optional(&data.db)
+        .await?
+        .ok_or_else(|| ApiError::not_found("Chat room"))?;
+
+    Ok(HttpResponse::Ok().json(json!({ "status": "success", "chat_room": room })))
 }
 
+#[utoipa::path(
+    tag = "chat",
+    responses(
+        (status = 204, description = "Registro removido"),
+        (status = 404, description = "Registro não encontrado", body = ErrorBody),
+    )
+)]
 #[delete("/chat_rooms/{id}")]
 async fn delete_chat_room_by_id(
     path: Path<Uuid>,
     data: Data<AppState>
-) -> impl Responder {
+) -> Result<HttpResponse, ApiError> {
     let id = path.into_inner();
 
-    match sqlx::query!("DELETE FROM chat_rooms WHERE id = $1", id)
+    let result = sqlx::query!("DELETE FROM chat_rooms WHERE id = $1", id)
         .execute(&data.db)
-        .await
-    {
-        Ok(_) => HttpResponse::NoContent().finish(),
-        Err(err) => HttpResponse::InternalServerError().json(json!({ "status": "error", "message": format!("{:?}", err) }))
+        .await?;
+
+    if result.rows_affected() == 0 {
+        return Err(ApiError::not_found("Chat room"));
     }
+
+    Ok(HttpResponse::NoContent().finish())
 }
 
 pub fn config_chat_rooms(cfg: &mut ServiceConfig) {
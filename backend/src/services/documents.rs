@@ -1,122 +1,101 @@
 use actix_web::{
     get, post, delete, patch,
-    web::{Data, Json, Path, ServiceConfig, Query},
-    HttpResponse, Responder,
+    web::{Data, Path, ServiceConfig},
+    HttpResponse,
 };
-use actix_multipart::Multipart;
+use actix_multipart::{Field, Multipart};
+use app_config::StorageConfig;
 use futures_util::StreamExt;
-use tokio::fs::File;
-use tokio::io::AsyncWriteExt;
 use serde_json::json;
-use sqlx::PgPool;
+use std::path::Path as FsPath;
 use uuid::Uuid;
-use std::path::PathBuf;
-use std::fs;
-use crate::{models::DocumentModel, schema::{CreateDocumentSchema, UpdateDocumentSchema, FilterOptions}, AppState};
+use crate::{audit::{self, Actor, Entity}, validation::ValidatedJson, error::ApiError, pagination::{Column, ListParams, ListQuery, ListSpec, SqlType, fetch_page}, models::DocumentModel, schema::UpdateDocumentSchema, storage, AppState};
 
-const UPLOAD_DIR: &str = "static/uploads";
+async fn create_upload_dir(storage_config: &StorageConfig) -> Result<(), ApiError> {
+    tokio::fs::create_dir_all(&storage_config.uploads_dir).await?;
+    Ok(())
+}
+
+// Grava o conteúdo de um campo do multipart em `uploads_dir/filename`; o nome
+// já deve ter passado por `storage::sanitize_filename`
+async fn save_field(field: &mut Field, storage_config: &StorageConfig, filename: &str) -> Result<(), ApiError> {
+    let filepath = FsPath::new(&storage_config.uploads_dir).join(filename);
+    storage::save_field(field, &filepath, storage_config.max_upload_bytes).await?;
+    Ok(())
+}
 
-fn create_upload_dir() {
-    if !PathBuf::from(UPLOAD_DIR).exists() {
-        fs::create_dir_all(UPLOAD_DIR).unwrap();
+// Lê um campo de texto do multipart
+async fn read_text_field(field: &mut Field) -> Result<String, ApiError> {
+    let mut value = String::new();
+    while let Some(chunk) = field.next().await {
+        value.push_str(&String::from_utf8_lossy(&chunk?));
     }
+    Ok(value)
 }
 
-#[post("/upload")]
-async fn upload_document(mut payload: Multipart, _data: Data<AppState>) -> impl Responder {
-    create_upload_dir();
+#[utoipa::path(
+    tag = "documents",
+    request_body(content = FileUpload, content_type = "multipart/form-data"),
+    responses(
+        (status = 200, description = "Arquivo salvo", body = DocumentUploadResponse),
+        (status = 400, description = "Requisição inválida", body = ErrorBody),
+        (status = 413, description = "Arquivo maior que o limite configurado", body = ErrorBody),
+    )
+)]
+#[post("/documents/upload")]
+async fn upload_document(mut payload: Multipart, data: Data<AppState>) -> Result<HttpResponse, ApiError> {
+    let storage_config = &data.config.storage;
+    create_upload_dir(storage_config).await?;
 
     let mut filename = String::new();
     while let Some(field) = payload.next().await {
-        match field {
-            Ok(mut field) => {
-                if let Some(fname) = field.content_disposition().get_filename() {
-                    filename = fname.to_string();
-                    let filepath = format!("{}/{}", UPLOAD_DIR, filename);
-
-                    if let Ok(mut f) = File::create(&filepath).await {
-                        while let Some(chunk) = field.next().await {
-                            if let Ok(data) = chunk {
-                                if let Err(e) = f.write_all(&data).await {
-                                    return HttpResponse::InternalServerError().json(json!({"status": "error", "message": format!("Write error: {:?}", e)}));
-                                }
-                            } else {
-                                return HttpResponse::InternalServerError().json(json!({"status": "error", "message": "Failed to read chunk"}));
-                            }
-                        }
-                    } else {
-                        return HttpResponse::InternalServerError().json(json!({"status": "error", "message": "Failed to create file"}));
-                    }
-                }
-            }
-            Err(e) => {
-                return HttpResponse::InternalServerError().json(json!({"status": "error", "message": format!("Error reading field: {:?}", e)}));
-            }
+        let mut field = field?;
+        if let Some(fname) = field.content_disposition().get_filename() {
+            filename = storage::sanitize_filename(fname);
+            save_field(&mut field, storage_config, &filename).await?;
         }
     }
 
-    HttpResponse::Ok().json(json!({"status": "success", "message": "File uploaded successfully", "filename": filename}))
-}
-
-#[get("/healthchecker")]
-async fn health_checker() -> impl Responder {
-    HttpResponse::Ok().json(json!({"status": "success", "message": "API is up and running smoothly."}))
+    Ok(HttpResponse::Ok().json(json!({"status": "success", "message": "File uploaded successfully", "filename": filename})))
 }
 
+#[utoipa::path(
+    tag = "documents",
+    request_body(content = DocumentUpload, content_type = "multipart/form-data"),
+    responses(
+        (status = 200, description = "Registro criado", body = DocumentResponse),
+        (status = 400, description = "Requisição inválida", body = ErrorBody),
+        (status = 413, description = "Arquivo maior que o limite configurado", body = ErrorBody),
+    )
+)]
 #[post("/documents")]
-async fn create_document(mut payload: Multipart, data: Data<AppState>) -> impl Responder {
-    create_upload_dir(); // Certifique-se de que essa função cria a pasta de uploads
+async fn create_document(mut payload: Multipart, data: Data<AppState>, actor: Actor) -> Result<HttpResponse, ApiError> {
+    let storage_config = &data.config.storage;
+    create_upload_dir(storage_config).await?;
 
     let mut student_id = String::new();
     let mut doc_type = String::new();
     let mut filename = String::new();
 
     while let Some(item) = payload.next().await {
-        let mut field = item.unwrap(); // Agora mutável
+        let mut field = item?;
 
         if field.name() == "student_id" {
-            student_id = field.fold(String::new(), |mut acc, data| async {
-                if let Ok(bytes) = data {
-                    acc.push_str(&String::from_utf8_lossy(&bytes));
-                }
-                acc
-            }).await;
+            student_id = read_text_field(&mut field).await?;
         } else if field.name() == "doc_type" {
-            doc_type = field.fold(String::new(), |mut acc, data| async {
-                if let Ok(bytes) = data {
-                    acc.push_str(&String::from_utf8_lossy(&bytes));
-                }
-                acc
-            }).await;
+            doc_type = read_text_field(&mut field).await?;
         } else if field.name() == "file" {
             if let Some(file_name) = field.content_disposition().get_filename() {
-                let file_path = format!("{}/{}", UPLOAD_DIR, file_name);
-                
-                // Usando tokio::fs::File para criar o arquivo
-                let mut f = File::create(&file_path).await.expect("Unable to create file");
-
                 // Armazenar o nome do arquivo antes de usar o campo
-                filename = file_name.to_string();
-
-                // Usar um loop para escrever os chunks no arquivo
-                while let Some(chunk) = field.next().await {
-                    let data = chunk.unwrap();
-                    f.write_all(&data).await.expect("Unable to write data");
-                }
+                filename = storage::sanitize_filename(file_name);
+                save_field(&mut field, storage_config, &filename).await?;
             }
         }
     }
 
     // Tenta converter student_id para UUID
-    let student_id_uuid = match Uuid::parse_str(&student_id) {
-        Ok(uuid) => uuid,
-        Err(_) => {
-            return HttpResponse::BadRequest().json(json!({
-                "status": "error",
-                "message": "Invalid UUID format for student_id"
-            }));
-        }
-    };
+    let student_id_uuid = Uuid::parse_str(&student_id)
+        .map_err(|_| ApiError::BadRequest("Invalid UUID format for student_id".to_string()))?;
 
     let query = r#"
         INSERT INTO documents (student_id, doc_type, filename)
@@ -124,144 +103,159 @@ async fn create_document(mut payload: Multipart, data: Data<AppState>) -> impl R
         RETURNING id, student_id, doc_type, filename, created_at
     "#;
 
-    match sqlx::query_as::<_, DocumentModel>(query)
+    let mut tx = data.db.begin().await?;
+    let document = sqlx::query_as::<_, DocumentModel>(query)
         .bind(student_id_uuid)
         .bind(&doc_type)
         .bind(&filename)
-        .fetch_one(&data.db)
-        .await
-    {
-        Ok(document) => {
-            HttpResponse::Ok().json(json!({
-                "status": "success",
-                "document": {
-                    "id": document.id,
-                    "student_id": document.student_id,
-                    "doc_type": document.doc_type,
-                    "filename": document.filename,
-                    "created_at": document.created_at
-                }
-            }))
-        }
-        Err(error) => {
-            HttpResponse::InternalServerError().json(json!({
-                "status": "error",
-                "message": format!("Failed to create document: {:?}", error)
-            }))
+        .fetch_one(&mut tx)
+        .await?;
+
+    audit::created(&mut tx, &actor, Entity::Document, document.id).await?;
+    tx.commit().await?;
+
+    Ok(HttpResponse::Ok().json(json!({
+        "status": "success",
+        "document": {
+            "id": document.id,
+            "student_id": document.student_id,
+            "doc_type": document.doc_type,
+            "filename": document.filename,
+            "created_at": document.created_at
         }
-    }
+    })))
 }
 
+const DOCUMENT_LIST: ListSpec = ListSpec {
+    from: "documents",
+    select: "id, student_id, doc_type, filename, created_at",
+    id: "id",
+    sorts: &[
+        Column::new("id", "id", SqlType::Uuid),
+        Column::new("created_at", "COALESCE(created_at, 'epoch')", SqlType::TimestampTz),
+    ],
+    default_sort: "id",
+    filters: &[
+        Column::new("student_id", "student_id", SqlType::Uuid),
+        Column::new("doc_type", "doc_type", SqlType::Text),
+    ],
+};
+
+#[utoipa::path(
+    tag = "documents",
+    params(
+        ListParams,
+        ("student_id" = Option<Uuid>, Query, description = "Filtra por `student_id`"),
+        ("doc_type" = Option<String>, Query, description = "Filtra por `doc_type`"),
+    ),
+    responses(
+        (status = 200, description = "Página de resultados", body = DocumentsPage),
+        (status = 400, description = "Requisição inválida", body = ErrorBody),
+    )
+)]
 #[get("/documents")]
-pub async fn get_all_documents(opts: Query<FilterOptions>, data: Data<AppState>) -> impl Responder {
-    let limit = opts.limit.unwrap_or(10);
-    let offset = (opts.page.unwrap_or(1) - 1) * limit;
-
-    match sqlx::query_as!(DocumentModel, "SELECT * FROM documents ORDER BY id LIMIT $1 OFFSET $2", limit as i32, offset as i32)
-        .fetch_all(&data.db)
-        .await
-    {
-        Ok(documents) => {
-            HttpResponse::Ok().json(json!({"status": "success", "documents": documents}))
-        }
-        Err(error) => {
-            HttpResponse::InternalServerError().json(json!({"status": "error", "message": format!("Failed to get documents: {:?}", error)}))
-        }
-    }
+pub async fn get_all_documents(
+    query: ListQuery,
+    data: Data<AppState>
+) -> Result<HttpResponse, ApiError> {
+    let page = fetch_page::<DocumentModel>(&data.db, &DOCUMENT_LIST, &query).await?;
+
+    Ok(HttpResponse::Ok().json(page.to_json("documents")))
 }
 
+#[utoipa::path(
+    tag = "documents",
+    responses(
+        (status = 200, description = "Registro encontrado", body = DocumentResponse),
+        (status = 404, description = "Registro não encontrado", body = ErrorBody),
+    )
+)]
 #[get("/documents/{id}")]
-pub async fn get_document_by_id(path: Path<Uuid>, data: Data<AppState>) -> impl Responder {
+pub async fn get_document_by_id(path: Path<Uuid>, data: Data<AppState>) -> Result<HttpResponse, ApiError> {
     let document_id = path.into_inner();
 
-    match sqlx::query_as!(DocumentModel, "SELECT * FROM documents WHERE id = $1", document_id)
-        .fetch_one(&data.db)
-        .await
-    {
-        Ok(document) => {
-            HttpResponse::Ok().json(json!({"status": "success", "document": document}))
-        }
-        Err(error) => {
-            HttpResponse::InternalServerError().json(json!({"status": "error", "message": format!("Failed to get document: {:?}", error)}))
-        }
-    }
+    let document = sqlx::query_as!(DocumentModel, "SELECT * FROM documents WHERE id = $1", document_id)
+        .fetch_optional(&data.db)
+        .await?
+        .ok_or_else(|| ApiError::not_found("Document"))?;
+
+    Ok(HttpResponse::Ok().json(json!({"status": "success", "document": document})))
 }
 
+#[utoipa::path(
+    tag = "documents",
+    responses(
+        (status = 204, description = "Registro removido"),
+        (status = 404, description = "Registro não encontrado", body = ErrorBody),
+    )
+)]
 #[delete("/documents/{id}")]
-async fn delete_document_by_id(path: Path<Uuid>, data: Data<AppState>) -> impl Responder {
+async fn delete_document_by_id(path: Path<Uuid>, data: Data<AppState>, actor: Actor) -> Result<HttpResponse, ApiError> {
     let document_id = path.into_inner();
 
-    match sqlx::query!("DELETE FROM documents WHERE id = $1", document_id)
-        .execute(&data.db)
-        .await
-    {
-        Ok(_) => HttpResponse::NoContent().finish(),
-        Err(err) => {
-            HttpResponse::InternalServerError().json(json!({"status": "error", "message": format!("Failed to delete document: {:?}", err)}))
-        }
-    }
+    let mut tx = data.db.begin().await?;
+    let before = audit::snapshot(&mut tx, Entity::Document, document_id)
+        .await?
+        .ok_or_else(|| ApiError::not_found("Document"))?;
+
+    sqlx::query!("DELETE FROM documents WHERE id = $1", document_id)
+        .execute(&mut tx)
+        .await?;
+
+    audit::deleted(&mut tx, &actor, Entity::Document, document_id, before).await?;
+    tx.commit().await?;
+
+    Ok(HttpResponse::NoContent().finish())
 }
 
+#[utoipa::path(
+    tag = "documents",
+    request_body = UpdateDocumentSchema,
+    responses(
+        (status = 200, description = "Registro atualizado", body = DocumentResponse),
+        (status = 400, description = "Requisição inválida", body = ErrorBody),
+        (status = 404, description = "Registro não encontrado", body = ErrorBody),
+        (status = 409, description = "Conflito com um registro existente", body = ErrorBody),
+        (status = 422, description = "Referência a um registro inexistente", body = ErrorBody),
+    )
+)]
 #[patch("/documents/{id}")]
 async fn update_document_by_id(
     path: Path<Uuid>,
-    body: Json<UpdateDocumentSchema>,
-    data: Data<AppState>
-) -> impl Responder {
+    body: ValidatedJson<UpdateDocumentSchema>,
+    data: Data<AppState>,
+    actor: Actor
+) -> Result<HttpResponse, ApiError> {
     let document_id = path.into_inner();
 
-    // Recuperar o documento existente
-    match sqlx::query_as!(
+    let mut tx = data.db.begin().await?;
+    let before = audit::snapshot(&mut tx, Entity::Document, document_id)
+        .await?
+        .ok_or_else(|| ApiError::not_found("Document"))?;
+
+    let updated_document = sqlx::query_as!(
         DocumentModel,
-        "SELECT * FROM documents WHERE id = $1",
+        "UPDATE documents SET student_id = COALESCE($1, student_id), doc_type = COALESCE($2, doc_type), filename = COALESCE($3, filename) WHERE id = $4 RETURNING *",
+        body.student_id.as_ref(),  // Mantendo como Option<Uuid>
+        body.doc_type.as_ref().map(|s| s.as_str()),  // Convertendo Option<String> para Option<&str>
+        body.filename.as_ref(),  // Agora permitindo alteração do filename
         document_id
     )
-    .fetch_one(&data.db)
-    .await
-    {
-        Ok(existing_document) => {
-            // Atualizar o documento
-            let update_result = sqlx::query_as!(
-                DocumentModel,
-                "UPDATE documents SET student_id = COALESCE($1, student_id), doc_type = COALESCE($2, doc_type), filename = COALESCE($3, filename) WHERE id = $4 RETURNING *",
-                body.student_id.as_ref(),  // Mantendo como Option<Uuid>
-                body.doc_type.as_ref().map(|s| s.as_str()),  // Convertendo Option<String> para Option<&str>
-                body.filename.as_ref(),  // Agora permitindo alteração do filename
-                document_id
-            )
-            .fetch_one(&data.db)
-            .await;
-
-            match update_result {
-                Ok(updated_document) => {
-                    let response = json!( {
-                        "status": "success",
-                        "document": updated_document
-                    });
-                    HttpResponse::Ok().json(response)
-                }
-                Err(update_error) => {
-                    let response = json!( {
-                        "status": "error",
-                        "message": format!("Failed to update document: {:?}", update_error)
-                    });
-                    HttpResponse::InternalServerError().json(response)
-                }
-            }
-        }
-        Err(fetch_error) => {
-            let response = json!( {
-                "status": "error",
-                "message": format!("Document not found: {:?}", fetch_error)
-            });
-            HttpResponse::NotFound().json(response)
-        }
-    }
+    .fetch_optional(&mut tx)
+    .await?
+    .ok_or_else(|| ApiError::not_found("Document"))?;
+
+    audit::updated(&mut tx, &actor, Entity::Document, document_id, before).await?;
+    tx.commit().await?;
+
+    Ok(HttpResponse::Ok().json(json!({
+        "status": "success",
+        "document": updated_document
+    })))
 }
 
 pub fn config_documents(conf: &mut ServiceConfig) {
     conf.service(upload_document)
-        .service(health_checker)
         .service(create_document)
         .service(get_all_documents)
         .service(get_document_by_id)
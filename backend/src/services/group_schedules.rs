@@ -0,0 +1,197 @@
+// backend/src/services/group_schedules.rs
+//! Horários fixos de treino das turmas e o calendário que sai deles
+use actix_web::{
+    get, post, patch, delete,
+    web::{Data, Path, Query, ServiceConfig},
+    HttpResponse,
+};
+use chrono::{Duration, Local};
+use serde_json::json;
+use uuid::Uuid;
+
+use crate::{
+    calendar,
+    validation::ValidatedJson,
+    error::ApiError,
+    repo::{GroupRepo, GroupScheduleRepo},
+    schema::{CalendarOptions, CreateGroupScheduleSchema, UpdateGroupScheduleSchema},
+    AppState,
+};
+
+const DEFAULT_CALENDAR_DAYS: i64 = 28;
+const MAX_CALENDAR_DAYS: i64 = 366;
+
+/// Horários da turma
+#[utoipa::path(
+    tag = "groups",
+    responses(
+        (status = 200, description = "Horários da turma, por dia da semana", body = GroupScheduleList),
+        (status = 404, description = "Turma não encontrada", body = ErrorBody),
+    )
+)]
+#[get("/groups/{id}/schedules")]
+async fn get_group_schedules(
+    path: Path<Uuid>,
+    data: Data<AppState>
+) -> Result<HttpResponse, ApiError> {
+    let group_id = path.into_inner();
+
+    GroupRepo::find(&data.db, group_id)
+        .await?
+        .ok_or_else(|| ApiError::not_found("Group"))?;
+    let schedules = GroupScheduleRepo::for_group(&data.db, group_id).await?;
+
+    Ok(HttpResponse::Ok().json(json!({
+        "status": "success",
+        "schedules": schedules
+    })))
+}
+
+/// Adiciona um horário semanal à turma
+#[utoipa::path(
+    tag = "groups",
+    request_body = CreateGroupScheduleSchema,
+    responses(
+        (status = 201, description = "Registro criado", body = GroupScheduleResponse),
+        (status = 400, description = "Requisição inválida", body = ErrorBody),
+        (status = 404, description = "Turma não encontrada", body = ErrorBody),
+    )
+)]
+#[post("/groups/{id}/schedules")]
+async fn create_group_schedule(
+    path: Path<Uuid>,
+    body: ValidatedJson<CreateGroupScheduleSchema>,
+    data: Data<AppState>
+) -> Result<HttpResponse, ApiError> {
+    let group_id = path.into_inner();
+
+    GroupRepo::find(&data.db, group_id)
+        .await?
+        .ok_or_else(|| ApiError::not_found("Group"))?;
+    let schedule = GroupScheduleRepo::create(&data.db, group_id, &body).await?;
+
+    Ok(HttpResponse::Created().json(json!({
+        "status": "success",
+        "schedule": schedule
+    })))
+}
+
+/// Altera um horário. Se a mudança tira da recorrência uma sessão já
+/// remarcada ou cancelada, responde 409: a mudança de treino teria de ser
+/// removida ou refeita antes
+#[utoipa::path(
+    tag = "groups",
+    request_body = UpdateGroupScheduleSchema,
+    responses(
+        (status = 200, description = "Registro atualizado", body = GroupScheduleResponse),
+        (status = 400, description = "Requisição inválida", body = ErrorBody),
+        (status = 404, description = "Registro não encontrado", body = ErrorBody),
+        (status = 409, description = "Mudanças de treino do horário deixariam de casar com uma sessão", body = ErrorBody),
+    )
+)]
+#[patch("/groups/{id}/schedules/{schedule_id}")]
+async fn update_group_schedule(
+    path: Path<(Uuid, Uuid)>,
+    body: ValidatedJson<UpdateGroupScheduleSchema>,
+    data: Data<AppState>
+) -> Result<HttpResponse, ApiError> {
+    let (group_id, schedule_id) = path.into_inner();
+
+    let mut tx = data.db.begin().await?;
+    let schedule = GroupScheduleRepo::update(&mut tx, group_id, schedule_id, &body)
+        .await?
+        .ok_or_else(|| ApiError::not_found("Group schedule"))?;
+
+    let orphaned: Vec<_> = GroupScheduleRepo::change_dates(&mut tx, schedule_id)
+        .await?
+        .into_iter()
+        .filter(|old_date| !calendar::is_occurrence(&schedule, *old_date))
+        .collect();
+    if let Some(first) = orphaned.first() {
+        return Err(ApiError::Conflict(format!(
+            "{} schedule change(s) would no longer match a session of this schedule (first: {}); delete or move them first",
+            orphaned.len(),
+            first
+        )));
+    }
+    tx.commit().await?;
+
+    Ok(HttpResponse::Ok().json(json!({
+        "status": "success",
+        "schedule": schedule
+    })))
+}
+
+/// Remove um horário; as mudanças de treino dele ficam só com a turma
+#[utoipa::path(
+    tag = "groups",
+    responses(
+        (status = 204, description = "Registro removido"),
+        (status = 404, description = "Registro não encontrado", body = ErrorBody),
+    )
+)]
+#[delete("/groups/{id}/schedules/{schedule_id}")]
+async fn delete_group_schedule(
+    path: Path<(Uuid, Uuid)>,
+    data: Data<AppState>
+) -> Result<HttpResponse, ApiError> {
+    let (group_id, schedule_id) = path.into_inner();
+
+    if !GroupScheduleRepo::delete(&data.db, group_id, schedule_id).await? {
+        return Err(ApiError::not_found("Group schedule"));
+    }
+
+    Ok(HttpResponse::NoContent().finish())
+}
+
+/// Sessões de treino da turma entre `from` e `to`, com cancelamentos e
+/// remarcações aplicados
+#[utoipa::path(
+    tag = "groups",
+    params(CalendarOptions),
+    responses(
+        (status = 200, description = "Sessões do período, em ordem de início", body = CalendarResponse),
+        (status = 400, description = "Requisição inválida", body = ErrorBody),
+        (status = 404, description = "Turma não encontrada", body = ErrorBody),
+    )
+)]
+#[get("/groups/{id}/calendar")]
+async fn get_group_calendar(
+    path: Path<Uuid>,
+    opts: Query<CalendarOptions>,
+    data: Data<AppState>
+) -> Result<HttpResponse, ApiError> {
+    let group_id = path.into_inner();
+    let from = opts.from.unwrap_or_else(|| Local::now().date_naive());
+    let to = opts.to.unwrap_or(from + Duration::days(DEFAULT_CALENDAR_DAYS - 1));
+    if to < from {
+        return Err(ApiError::BadRequest("to must not be before from".to_string()));
+    }
+    if (to - from).num_days() >= MAX_CALENDAR_DAYS {
+        return Err(ApiError::BadRequest(format!(
+            "The calendar covers at most {} days",
+            MAX_CALENDAR_DAYS
+        )));
+    }
+
+    GroupRepo::find(&data.db, group_id)
+        .await?
+        .ok_or_else(|| ApiError::not_found("Group"))?;
+    let sessions = calendar::sessions(&data.db, group_id, from, to).await?;
+
+    Ok(HttpResponse::Ok().json(json!({
+        "status": "success",
+        "from": from,
+        "to": to,
+        "sessions": sessions
+    })))
+}
+
+// Configuração das rotas
+pub fn config_group_schedules(conf: &mut ServiceConfig) {
+    conf.service(get_group_schedules)
+        .service(create_group_schedule)
+        .service(update_group_schedule)
+        .service(delete_group_schedule)
+        .service(get_group_calendar);
+}
@@ -1,153 +1,293 @@
 // backend/src/services/groups.rs
+use std::collections::HashMap;
+
 use actix_web::{
     get, post, patch, delete,
-    web::{Data, Json, Path, Query, ServiceConfig},
-    HttpResponse, Responder,
+    web::{Data, Path, Query, ServiceConfig},
+    HttpResponse,
 };
 use serde_json::json;
+use sqlx::PgConnection;
 use uuid::Uuid;
 
 use crate::{
-    models::GroupModel,
-    schema::{CreateGroupSchema, UpdateGroupSchema, FilterOptions},
+    audit::{self, Actor, Entity},
+    validation::{season_age, ValidatedJson},
+    error::ApiError,
+    pagination::{ListParams, ListQuery},
+    models::{GroupAssignment, GroupModel, StudentModel},
+    repo::GroupRepo,
+    schema::{AssignGroupsSchema, CreateGroupSchema, GroupAssignmentOptions, UpdateGroupSchema},
     AppState,
 };
 
+// `coach_id` precisa apontar para um usuário com o papel `coach`; usuário
+// inexistente fica para a chave estrangeira (422)
+async fn check_coach(db: &mut PgConnection, coach_id: Option<Uuid>) -> Result<(), ApiError> {
+    let Some(coach_id) = coach_id else {
+        return Ok(());
+    };
+    match GroupRepo::user_role(db, coach_id).await? {
+        Some(role) if role != "coach" => Err(ApiError::Validation(
+            "coach_id must reference a user with the coach role".to_string(),
+        )),
+        _ => Ok(()),
+    }
+}
+
 /// Criar um novo grupo
+#[utoipa::path(
+    tag = "groups",
+    request_body = CreateGroupSchema,
+    responses(
+        (status = 201, description = "Registro criado", body = GroupResponse),
+        (status = 400, description = "Requisição inválida", body = ErrorBody),
+        (status = 409, description = "Conflito com um registro existente", body = ErrorBody),
+        (status = 422, description = "Referência a um registro inexistente", body = ErrorBody),
+    )
+)]
 #[post("/groups")]
 async fn create_group(
     data: Data<AppState>,
-    body: Json<CreateGroupSchema>,
-) -> impl Responder {
-    let query = r#"
-        INSERT INTO groups (name, description)
-        VALUES ($1, $2)
-        RETURNING id, name, description, created_at
-    "#;
-
-    match sqlx::query_as::<_, GroupModel>(query)
-        .bind(&body.name)
-        .bind(&body.description)
-        .fetch_one(&data.db)
-        .await
-    {
-        Ok(group) => HttpResponse::Created().json(json!({ "status": "success", "group": group })),
-        Err(err) => HttpResponse::InternalServerError().json(json!({
-            "status": "error",
-            "message": format!("Erro ao criar grupo: {}", err)
-        })),
-    }
+    body: ValidatedJson<CreateGroupSchema>,
+) -> Result<HttpResponse, ApiError> {
+    let mut conn = data.db.acquire().await?;
+    check_coach(&mut conn, body.coach_id).await?;
+
+    let group = GroupRepo::create(&mut conn, &body).await?;
+
+    Ok(HttpResponse::Created().json(json!({ "status": "success", "group": group })))
 }
 
 /// Listar todos os grupos com paginação
+#[utoipa::path(
+    tag = "groups",
+    params(
+        ListParams,
+        ("name" = Option<String>, Query, description = "Filtra por `name`"),
+        ("season" = Option<i32>, Query, description = "Filtra por `season`"),
+        ("coach_id" = Option<Uuid>, Query, description = "Filtra por `coach_id`"),
+    ),
+    responses(
+        (status = 200, description = "Página de resultados", body = GroupsPage),
+        (status = 400, description = "Requisição inválida", body = ErrorBody),
+    )
+)]
 #[get("/groups")]
 async fn get_all_groups(
-    data: Data<AppState>,
-    opts: Query<FilterOptions>,
-) -> impl Responder {
-    let limit = opts.limit.unwrap_or(10);
-    let offset = (opts.page.unwrap_or(1) - 1) * limit;
-
-    let query = r#"
-        SELECT id, name, description, created_at
-        FROM groups
-        ORDER BY created_at DESC
-        LIMIT $1 OFFSET $2
-    "#;
-
-    match sqlx::query_as::<_, GroupModel>(query)
-        .bind(limit as i32)
-        .bind(offset as i32)
-        .fetch_all(&data.db)
-        .await
-    {
-        Ok(groups) => HttpResponse::Ok().json(json!({ "status": "success", "groups": groups })),
-        Err(err) => HttpResponse::InternalServerError().json(json!({
-            "status": "error",
-            "message": format!("Erro ao listar grupos: {}", err)
-        })),
-    }
+    query: ListQuery,
+    data: Data<AppState>
+) -> Result<HttpResponse, ApiError> {
+    let page = GroupRepo::list(&data.db, &query).await?;
+
+    Ok(HttpResponse::Ok().json(page.to_json("groups")))
 }
 
 /// Obter um grupo por ID
+#[utoipa::path(
+    tag = "groups",
+    responses(
+        (status = 200, description = "Registro encontrado", body = GroupResponse),
+        (status = 404, description = "Registro não encontrado", body = ErrorBody),
+    )
+)]
 #[get("/groups/{id}")]
 async fn get_group_by_id(
     data: Data<AppState>,
     path: Path<Uuid>,
-) -> impl Responder {
-    let group_id = path.into_inner();
-
-    let query = "SELECT id, name, description, created_at FROM groups WHERE id = $1";
-
-    match sqlx::query_as::<_, GroupModel>(query)
-        .bind(group_id)
-        .fetch_one(&data.db)
-        .await
-    {
-        Ok(group) => HttpResponse::Ok().json(json!({ "status": "success", "group": group })),
-        Err(err) => HttpResponse::NotFound().json(json!({
-            "status": "error",
-            "message": format!("Grupo não encontrado: {}", err)
-        })),
-    }
+) -> Result<HttpResponse, ApiError> {
+    let group = GroupRepo::find(&data.db, path.into_inner())
+        .await?
+        .ok_or_else(|| ApiError::not_found("Group"))?;
+
+    Ok(HttpResponse::Ok().json(json!({ "status": "success", "group": group })))
 }
 
 /// Atualizar grupo por ID
+#[utoipa::path(
+    tag = "groups",
+    request_body = UpdateGroupSchema,
+    responses(
+        (status = 200, description = "Registro atualizado", body = GroupResponse),
+        (status = 400, description = "Requisição inválida", body = ErrorBody),
+        (status = 404, description = "Registro não encontrado", body = ErrorBody),
+        (status = 409, description = "Conflito com um registro existente", body = ErrorBody),
+        (status = 422, description = "Referência a um registro inexistente", body = ErrorBody),
+    )
+)]
 #[patch("/groups/{id}")]
 async fn update_group_by_id(
     data: Data<AppState>,
     path: Path<Uuid>,
-    body: Json<UpdateGroupSchema>,
-) -> impl Responder {
-    let group_id = path.into_inner();
-
-    let query = r#"
-        UPDATE groups SET
-            name = COALESCE($1, name),
-            description = COALESCE($2, description)
-        WHERE id = $3
-        RETURNING id, name, description, created_at
-    "#;
-
-    match sqlx::query_as::<_, GroupModel>(query)
-        .bind(body.name.as_deref())
-        .bind(body.description.as_deref())
-        .bind(group_id)
-        .fetch_one(&data.db)
-        .await
-    {
-        Ok(group) => HttpResponse::Ok().json(json!({ "status": "success", "group": group })),
-        Err(err) => HttpResponse::InternalServerError().json(json!({
-            "status": "error",
-            "message": format!("Erro ao atualizar grupo: {}", err)
-        })),
-    }
+    body: ValidatedJson<UpdateGroupSchema>,
+) -> Result<HttpResponse, ApiError> {
+    let mut conn = data.db.acquire().await?;
+    check_coach(&mut conn, body.coach_id.flatten()).await?;
+
+    let group = GroupRepo::update(&mut conn, path.into_inner(), &body)
+        .await?
+        .ok_or_else(|| ApiError::not_found("Group"))?;
+
+    Ok(HttpResponse::Ok().json(json!({ "status": "success", "group": group })))
 }
 
 /// Deletar grupo por ID
+#[utoipa::path(
+    tag = "groups",
+    responses(
+        (status = 204, description = "Registro removido"),
+        (status = 404, description = "Registro não encontrado", body = ErrorBody),
+    )
+)]
 #[delete("/groups/{id}")]
 async fn delete_group_by_id(
     data: Data<AppState>,
     path: Path<Uuid>,
-) -> impl Responder {
-    let group_id = path.into_inner();
-
-    match sqlx::query("DELETE FROM groups WHERE id = $1")
-        .bind(group_id)
-        .execute(&data.db)
-        .await
-    {
-        Ok(_) => HttpResponse::NoContent().finish(),
-        Err(err) => HttpResponse::InternalServerError().json(json!({
-            "status": "error",
-            "message": format!("Erro ao deletar grupo: {}", err)
-        })),
+) -> Result<HttpResponse, ApiError> {
+    if !GroupRepo::delete(&data.db, path.into_inner()).await? {
+        return Err(ApiError::not_found("Group"));
+    }
+
+    Ok(HttpResponse::NoContent().finish())
+}
+
+// Escolhe a turma de cada aluno pela idade que ele completa na temporada:
+// entre as turmas cuja faixa etária serve e que ainda têm vaga, a com menos
+// alunos (empate: a primeira na ordem de `for_season`)
+fn plan_assignments(
+    season: i32,
+    groups: &[GroupModel],
+    mut counts: HashMap<Uuid, i64>,
+    students: Vec<StudentModel>,
+) -> Vec<GroupAssignment> {
+    students
+        .into_iter()
+        .map(|student| {
+            let age = student.birth_date.and_then(|birth_date| season_age(birth_date, season));
+            let mut assignment = GroupAssignment {
+                student_id: student.id,
+                student_name: student.name,
+                birth_date: student.birth_date,
+                season_age: age,
+                group_id: None,
+                group_name: None,
+                reason: None,
+            };
+            let Some(age) = age else {
+                assignment.reason = Some("no_birth_date".to_string());
+                return assignment;
+            };
+
+            let matching: Vec<&GroupModel> = groups
+                .iter()
+                .filter(|group| !matches!(group.min_age, Some(min_age) if age < min_age))
+                .filter(|group| !matches!(group.max_age, Some(max_age) if age > max_age))
+                .collect();
+            let chosen = matching
+                .iter()
+                .filter(|group| {
+                    let members = counts.get(&group.id).copied().unwrap_or(0);
+                    !matches!(group.capacity, Some(capacity) if members >= i64::from(capacity))
+                })
+                .min_by_key(|group| counts.get(&group.id).copied().unwrap_or(0));
+
+            match chosen {
+                Some(group) => {
+                    *counts.entry(group.id).or_insert(0) += 1;
+                    assignment.group_id = Some(group.id);
+                    assignment.group_name = Some(group.name.clone());
+                }
+                None if matching.is_empty() => assignment.reason = Some("no_matching_group".to_string()),
+                None => assignment.reason = Some("groups_full".to_string()),
+            }
+            assignment
+        })
+        .collect()
+}
+
+/// Sugestão de turma para cada aluno sem turma ou numa turma de temporada
+/// anterior, pela data de nascimento; não altera nada
+#[utoipa::path(
+    tag = "groups",
+    params(GroupAssignmentOptions),
+    responses(
+        (status = 200, description = "Turma sugerida (ou o motivo de não haver) para cada aluno", body = GroupAssignmentsResponse),
+        (status = 400, description = "Requisição inválida", body = ErrorBody),
+    )
+)]
+#[get("/groups/assignments")]
+async fn suggest_group_assignments(
+    data: Data<AppState>,
+    opts: Query<GroupAssignmentOptions>,
+) -> Result<HttpResponse, ApiError> {
+    let season = opts.season;
+
+    let groups = GroupRepo::for_season(&data.db, season, false).await?;
+    let ids: Vec<Uuid> = groups.iter().map(|group| group.id).collect();
+    let counts = GroupRepo::member_counts(&data.db, &ids).await?;
+    let students = GroupRepo::students_without_group(&data.db, season).await?;
+    let assignments = plan_assignments(season, &groups, counts, students);
+
+    Ok(HttpResponse::Ok().json(json!({
+        "status": "success",
+        "season": season,
+        "assignments": assignments
+    })))
+}
+
+/// Aplica a sugestão de `GET /groups/assignments`: cada aluno com turma
+/// sugerida passa para ela, respeitando as vagas
+#[utoipa::path(
+    tag = "groups",
+    request_body = AssignGroupsSchema,
+    responses(
+        (status = 200, description = "Alunos distribuídos; os sem turma vêm com `reason`", body = GroupAssignmentsResponse),
+        (status = 400, description = "Requisição inválida", body = ErrorBody),
+    )
+)]
+#[post("/groups/assignments")]
+async fn apply_group_assignments(
+    data: Data<AppState>,
+    body: ValidatedJson<AssignGroupsSchema>,
+    actor: Actor,
+) -> Result<HttpResponse, ApiError> {
+    let season = body.season;
+
+    let mut tx = data.db.begin().await?;
+    let groups = GroupRepo::for_season(&mut tx, season, true).await?;
+    let ids: Vec<Uuid> = groups.iter().map(|group| group.id).collect();
+    let counts = GroupRepo::member_counts(&mut tx, &ids).await?;
+    let students = GroupRepo::students_without_group(&mut tx, season).await?;
+    let assignments = plan_assignments(season, &groups, counts, students);
+
+    let mut assigned = 0;
+    for assignment in &assignments {
+        let Some(group_id) = assignment.group_id else {
+            continue;
+        };
+        let Some(before) = audit::snapshot(&mut tx, Entity::Student, assignment.student_id).await? else {
+            continue;
+        };
+        GroupRepo::assign(&mut tx, assignment.student_id, group_id).await?;
+        audit::updated(&mut tx, &actor, Entity::Student, assignment.student_id, before).await?;
+        assigned += 1;
     }
+    tx.commit().await?;
+
+    Ok(HttpResponse::Ok().json(json!({
+        "status": "success",
+        "season": season,
+        "assigned": assigned,
+        "assignments": assignments
+    })))
 }
 
 /// Registrar rotas do módulo groups
 pub fn config_groups(cfg: &mut ServiceConfig) {
-    cfg.service(create_group)
+    // `/groups/assignments` antes de `/groups/{id}`, que também casaria com ela
+    cfg.service(suggest_group_assignments)
+        .service(apply_group_assignments)
+        .service(create_group)
         .service(get_all_groups)
         .service(get_group_by_id)
         .service(update_group_by_id)
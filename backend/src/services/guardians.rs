@@ -1,201 +1,197 @@
 use actix_web::{
     get, post, delete, patch,
-    web::{Data, Json, Query, Path, ServiceConfig},
-    HttpResponse, Responder,
+    web::{Data, Path, ServiceConfig},
+    HttpResponse,
 };
 use serde_json::json;
 use crate::{
+    validation::ValidatedJson,
+    error::ApiError,
+    pagination::{Column, ListParams, ListQuery, ListSpec, SqlType, fetch_page},
     models::GuardianModel,
-    schema::{CreateGuardianSchema, UpdateGuardianSchema, FilterOptions},
+    schema::{CreateGuardianSchema, UpdateGuardianSchema},
     AppState
 };
-use sqlx::PgPool;
 use uuid::Uuid;
 
 /// Função para criar um novo responsável
+#[utoipa::path(
+    tag = "guardians",
+    request_body = CreateGuardianSchema,
+    responses(
+        (status = 201, description = "Registro criado", body = GuardianResponse),
+        (status = 400, description = "Requisição inválida", body = ErrorBody),
+        (status = 409, description = "Conflito com um registro existente", body = ErrorBody),
+        (status = 422, description = "Referência a um registro inexistente", body = ErrorBody),
+    )
+)]
 #[post("/guardians")]
 async fn create_guardian(
-    body: Json<CreateGuardianSchema>,
+    body: ValidatedJson<CreateGuardianSchema>,
     data: Data<AppState>,
-) -> impl Responder {
+) -> Result<HttpResponse, ApiError> {
     let query = r#"
-        INSERT INTO guardians (user_id, name, relationship)
-        VALUES ($1, $2, $3)
-        RETURNING id, user_id, name, relationship, guardians_date
+        INSERT INTO guardians (user_id, name, relationship, household_id)
+        VALUES ($1, $2, $3, $4)
+        RETURNING id, user_id, name, relationship, guardians_date, household_id
     "#;
 
-    match sqlx::query_as::<_, GuardianModel>(query)
+    let guardian = sqlx::query_as::<_, GuardianModel>(query)
         .bind(body.user_id)
         .bind(&body.name)
         .bind(&body.relationship)
+        .bind(body.household_id)
         .fetch_one(&data.db)
-        .await
-    {
-        Ok(guardian) => {
-            let response = json!({
-                "status": "success",
-                "guardian": {
-                    "id": guardian.id,
-                    "user_id": guardian.user_id,
-                    "name": guardian.name,
-                    "relationship": guardian.relationship,
-                    "guardians_date": guardian.guardians_date
-                }
-            });
-            HttpResponse::Created().json(response)
-        }
-        Err(error) => {
-            let response = json!({
-                "status": "error",
-                "message": format!("Failed to create guardian: {:?}", error)
-            });
-            HttpResponse::InternalServerError().json(response)
+        .await?;
+
+    Ok(HttpResponse::Created().json(json!({
+        "status": "success",
+        "guardian": {
+            "id": guardian.id,
+            "user_id": guardian.user_id,
+            "name": guardian.name,
+            "relationship": guardian.relationship,
+            "guardians_date": guardian.guardians_date,
+            "household_id": guardian.household_id
         }
-    }
+    })))
 }
 
+const GUARDIAN_LIST: ListSpec = ListSpec {
+    from: "guardians",
+    select: "id, user_id, name, relationship, guardians_date, household_id",
+    id: "id",
+    sorts: &[
+        Column::new("id", "id", SqlType::Uuid),
+        Column::new("name", "name", SqlType::Text),
+        Column::new("guardians_date", "COALESCE(guardians_date, 'epoch')", SqlType::TimestampTz),
+    ],
+    default_sort: "id",
+    filters: &[
+        Column::new("user_id", "user_id", SqlType::Uuid),
+        Column::new("relationship", "relationship", SqlType::Text),
+        Column::new("household_id", "household_id", SqlType::Uuid),
+    ],
+};
+
 /// Função para listar todos os responsáveis
+#[utoipa::path(
+    tag = "guardians",
+    params(
+        ListParams,
+        ("user_id" = Option<Uuid>, Query, description = "Filtra por `user_id`"),
+        ("relationship" = Option<String>, Query, description = "Filtra por `relationship`"),
+        ("household_id" = Option<Uuid>, Query, description = "Filtra por `household_id`"),
+    ),
+    responses(
+        (status = 200, description = "Página de resultados", body = GuardiansPage),
+        (status = 400, description = "Requisição inválida", body = ErrorBody),
+    )
+)]
 #[get("/guardians")]
 async fn get_all_guardians(
-    opts: Query<FilterOptions>,
+    query: ListQuery,
     data: Data<AppState>
-) -> impl Responder {
-    let limit = opts.limit.unwrap_or(10);
-    let offset = (opts.page.unwrap_or(1) - 1) * limit;
+) -> Result<HttpResponse, ApiError> {
+    let page = fetch_page::<GuardianModel>(&data.db, &GUARDIAN_LIST, &query).await?;
 
-    match sqlx::query_as!(
-        GuardianModel,
-        "SELECT * FROM guardians ORDER BY id LIMIT $1 OFFSET $2",
-        limit as i32,
-        offset as i32
-    )
-    .fetch_all(&data.db)
-    .await
-    {
-        Ok(guardians) => {
-            let response = json!({
-                "status": "success",
-                "guardians": guardians
-            });
-            HttpResponse::Ok().json(response)
-        }
-        Err(error) => {
-            let response = json!({
-                "status": "error",
-                "message": format!("Failed to get guardians: {:?}", error)
-            });
-            HttpResponse::InternalServerError().json(response)
-        }
-    }
+    Ok(HttpResponse::Ok().json(page.to_json("guardians")))
 }
 
 /// Função para buscar um responsável pelo ID
+#[utoipa::path(
+    tag = "guardians",
+    responses(
+        (status = 200, description = "Registro encontrado", body = GuardianResponse),
+        (status = 404, description = "Registro não encontrado", body = ErrorBody),
+    )
+)]
 #[get("/guardians/{id}")]
 async fn get_guardian_by_id(
     path: Path<Uuid>,
     data: Data<AppState>
-) -> impl Responder {
+) -> Result<HttpResponse, ApiError> {
     let guardian_id = path.into_inner();
 
-    match sqlx::query_as!(
+    let guardian = sqlx::query_as!(
         GuardianModel,
         "SELECT * FROM guardians WHERE id = $1",
         guardian_id
     )
-    .fetch_one(&data.db)
-    .await
-    {
-        Ok(guardian) => {
-            let response = json!({
-                "status": "success",
-                "guardian": guardian
-            });
-            HttpResponse::Ok().json(response)
-        }
-        Err(error) => {
-            let response = json!({
-                "status": "error",
-                "message": format!("Failed to get guardian: {:?}", error)
-            });
-            HttpResponse::InternalServerError().json(response)
-        }
-    }
+    .fetch_optional(&data.db)
+    .await?
+    .ok_or_else(|| ApiError::not_found("Guardian"))?;
+
+    Ok(HttpResponse::Ok().json(json!({
+        "status": "success",
+        "guardian": guardian
+    })))
 }
 
 /// Função para atualizar um responsável existente
+#[utoipa::path(
+    tag = "guardians",
+    request_body = UpdateGuardianSchema,
+    responses(
+        (status = 200, description = "Registro atualizado", body = GuardianResponse),
+        (status = 400, description = "Requisição inválida", body = ErrorBody),
+        (status = 404, description = "Registro não encontrado", body = ErrorBody),
+        (status = 409, description = "Conflito com um registro existente", body = ErrorBody),
+        (status = 422, description = "Referência a um registro inexistente", body = ErrorBody),
+    )
+)]
 #[patch("/guardians/{id}")]
 async fn update_guardian_by_id(
     path: Path<Uuid>,
-    body: Json<UpdateGuardianSchema>,
+    body: ValidatedJson<UpdateGuardianSchema>,
     data: Data<AppState>
-) -> impl Responder {
+) -> Result<HttpResponse, ApiError> {
     let guardian_id = path.into_inner();
 
-    match sqlx::query_as!(GuardianModel, "SELECT * FROM guardians WHERE id = $1", guardian_id)
-        .fetch_one(&data.db)
-        .await
-    {
-        Ok(guardian) => {
-            let update_result = sqlx::query_as!(
-                GuardianModel,
-                "UPDATE guardians SET user_id = COALESCE($1, user_id),  name = COALESCE($2, name), relationship = COALESCE($3, relationship) WHERE id = $4 RETURNING *",
-                body.user_id.as_ref(),
-                body.name.as_ref(),
-                body.relationship.as_ref(),
-                guardian_id
-            )
-            .fetch_one(&data.db)
-            .await;
-
-            match update_result {
-                Ok(updated_guardian) => {
-                    let response = json!({
-                        "status": "success",
-                        "guardian": updated_guardian
-                    });
-                    HttpResponse::Ok().json(response)
-                }
-                Err(update_error) => {
-                    let response = json!({
-                        "status": "error",
-                        "message": format!("Failed to update guardian: {:?}", update_error)
-                    });
-                    HttpResponse::InternalServerError().json(response)
-                }
-            }
-        }
-        Err(fetch_error) => {
-            let response = json!({
-                "status": "error",
-                "message": format!("Guardian not found: {:?}", fetch_error)
-            });
-            HttpResponse::NotFound().json(response)
-        }
-    }
+    let updated_guardian = sqlx::query_as!(
+        GuardianModel,
+        "UPDATE guardians SET user_id = COALESCE($1, user_id),  name = COALESCE($2, name), relationship = COALESCE($3, relationship), household_id = COALESCE($4, household_id) WHERE id = $5 RETURNING *",
+        body.user_id.as_ref(),
+        body.name.as_ref(),
+        body.relationship.as_ref(),
+        body.household_id.as_ref(),
+        guardian_id
+    )
+    .fetch_optional(&data.db)
+    .await?
+    .ok_or_else(|| ApiError::not_found("Guardian"))?;
+
+    Ok(HttpResponse::Ok().json(json!({
+        "status": "success",
+        "guardian": updated_guardian
+    })))
 }
 
 
 /// Função para deletar um responsável
+#[utoipa::path(
+    tag = "guardians",
+    responses(
+        (status = 204, description = "Registro removido"),
+        (status = 404, description = "Registro não encontrado", body = ErrorBody),
+    )
+)]
 #[delete("/guardians/{id}")]
 async fn delete_guardian_by_id(
     path: Path<Uuid>,
     data: Data<AppState>
-) -> impl Responder {
+) -> Result<HttpResponse, ApiError> {
     let guardian_id = path.into_inner();
 
-    match sqlx::query!("DELETE FROM guardians WHERE id = $1", guardian_id)
+    let result = sqlx::query!("DELETE FROM guardians WHERE id = $1", guardian_id)
         .execute(&data.db)
-        .await
-    {
-        Ok(_) => HttpResponse::NoContent().finish(),
-        Err(err) => {
-            let response = json!({
-                "status": "error",
-                "message": format!("Failed to delete guardian: {:?}", err)
-            });
-            HttpResponse::InternalServerError().json(response)
-        }
+        .await?;
+
+    if result.rows_affected() == 0 {
+        return Err(ApiError::not_found("Guardian"));
     }
+
+    Ok(HttpResponse::NoContent().finish())
 }
 
 /// Função de configuração dos serviços de guardian
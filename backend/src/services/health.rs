@@ -1,9 +1,148 @@
-use actix_web::web::ServiceConfig;
+use actix_web::web::{Data, ServiceConfig};
 
 use actix_web::{get, HttpResponse, Responder};
+use app_config::PublisherKind;
+use serde::Serialize;
 use serde_json::json;
+use std::{
+    future::Future,
+    path::{Path, PathBuf},
+    time::{Duration, Instant},
+};
+use tokio::process::Command;
+use utoipa::ToSchema;
+
+use crate::AppState;
+
+/// Resultado da verificação de uma dependência
+#[derive(Serialize, ToSchema)]
+pub struct ComponentHealth {
+    /// `postgres`, o publisher dos jobs (`rabbitmq`, `in_process` ou `noop`),
+    /// `disk` ou `ffmpeg`
+    name: &'static str,
+    /// `up` ou `down`
+    status: &'static str,
+    /// Se `false`, uma falha só deixa o serviço `degraded`
+    required: bool,
+    latency_ms: f64,
+    /// Resumo da falha; a causa completa fica só no log, já que a rota é pública
+    #[serde(skip_serializing_if = "Option::is_none")]
+    detail: Option<String>,
+}
+
+// Falha de uma verificação: `summary` vai na resposta, `cause` só no log
+struct Failure {
+    summary: &'static str,
+    cause: String,
+}
+
+impl Failure {
+    fn new(summary: &'static str, cause: impl ToString) -> Self {
+        Failure { summary, cause: cause.to_string() }
+    }
+}
+
+/// Corpo de `/api/health/ready`
+#[derive(Serialize, ToSchema)]
+pub struct Readiness {
+    /// `ready`, `degraded` (só falharam componentes opcionais) ou `unavailable`
+    status: &'static str,
+    components: Vec<ComponentHealth>,
+}
+
+// Roda uma verificação com limite de tempo, medindo a latência
+async fn check<F>(name: &'static str, required: bool, timeout: Duration, probe: F) -> ComponentHealth
+where
+    F: Future<Output = Result<Option<String>, Failure>>,
+{
+    let started = Instant::now();
+    let result = match tokio::time::timeout(timeout, probe).await {
+        Ok(result) => result,
+        Err(_) => Err(Failure::new("timed out", format!("no answer after {} ms", timeout.as_millis()))),
+    };
+    let latency_ms = started.elapsed().as_secs_f64() * 1000.0;
+
+    let (status, detail) = match result {
+        Ok(detail) => ("up", detail),
+        Err(failure) => {
+            tracing::warn!(component = name, cause = %failure.cause, "Health check failed: {}", failure.summary);
+            ("down", Some(failure.summary.to_string()))
+        }
+    };
+    ComponentHealth { name, status, required, latency_ms, detail }
+}
+
+async fn check_postgres(data: &AppState) -> Result<Option<String>, Failure> {
+    sqlx::query("SELECT 1")
+        .execute(&data.db)
+        .await
+        .map_err(|err| Failure::new("query failed", err))?;
+    Ok(Some(format!("pool: {} connections, {} idle", data.db.size(), data.db.num_idle())))
+}
+
+async fn check_publisher(data: &AppState) -> Result<Option<String>, Failure> {
+    if data.publisher.is_connected() {
+        Ok(None)
+    } else {
+        Err(Failure::new("not connected; reconnecting", "publisher reports no connection"))
+    }
+}
+
+// O diretório de uploads só é criado no primeiro envio; até lá mede o
+// ancestral mais próximo que já existe, que fica no mesmo disco
+fn nearest_existing(dir: &Path) -> PathBuf {
+    dir.ancestors()
+        .find(|ancestor| ancestor.exists())
+        .map(Path::to_path_buf)
+        .unwrap_or_else(|| PathBuf::from("."))
+}
+
+async fn check_disk(data: &AppState) -> Result<Option<String>, Failure> {
+    let dir = nearest_existing(Path::new(&data.config.storage.uploads_dir));
+    let min_free = data.config.health.min_free_disk_bytes;
+
+    let available = tokio::task::spawn_blocking(move || {
+        fs2::available_space(&dir).map_err(|err| Failure::new("cannot read free space", format!("{}: {}", dir.display(), err)))
+    })
+    .await
+    .map_err(|err| Failure::new("cannot read free space", err))??;
+
+    if available < min_free {
+        Err(Failure::new(
+            "free space below the configured minimum",
+            format!("{} bytes free in {} (minimum {})", available, data.config.storage.uploads_dir, min_free),
+        ))
+    } else {
+        Ok(Some(format!("{} bytes free", available)))
+    }
+}
+
+async fn check_ffmpeg(data: &AppState) -> Result<Option<String>, Failure> {
+    let ffmpeg = &data.config.transcoder.ffmpeg_path;
+    let output = Command::new(ffmpeg)
+        .arg("-version")
+        .kill_on_drop(true)
+        .output()
+        .await
+        .map_err(|err| Failure::new("cannot run ffmpeg", format!("{}: {}", ffmpeg, err)))?;
+
+    if !output.status.success() {
+        return Err(Failure::new(
+            "ffmpeg -version failed",
+            format!("{} -version exited with {}", ffmpeg, output.status),
+        ));
+    }
+    let version = String::from_utf8_lossy(&output.stdout).lines().next().map(str::to_string);
+    Ok(version)
+}
 
 // Endpoint de verificação de saúde
+#[utoipa::path(
+    tag = "health",
+    responses(
+        (status = 200, description = "API no ar", body = StatusMessage),
+    )
+)]
 #[get("/healthchecker")]
 async fn health_checker() -> impl Responder {
     const MESSAGE: &str = "Health check: API is up and running smoothly.";
@@ -14,7 +153,65 @@ async fn health_checker() -> impl Responder {
     }))
 }
 
+/// Liveness: o processo está de pé e respondendo; não consulta dependências
+#[utoipa::path(
+    tag = "health",
+    responses(
+        (status = 200, description = "Processo no ar", body = StatusMessage),
+    )
+)]
+#[get("/health/live")]
+async fn health_live() -> impl Responder {
+    HttpResponse::Ok().json(json!({
+        "status": "success",
+        "message": "alive"
+    }))
+}
+
+/// Readiness: verifica Postgres, o publisher dos jobs, espaço em disco e ffmpeg.
+/// Responde 503 quando algum componente obrigatório está fora. O ffmpeg é
+/// obrigatório quando o próprio backend roda os jobs (`in_process`).
+#[utoipa::path(
+    tag = "health",
+    responses(
+        (status = 200, description = "Pronto para receber tráfego (ou `degraded`)", body = Readiness),
+        (status = 503, description = "Algum componente obrigatório falhou", body = Readiness),
+    )
+)]
+#[get("/health/ready")]
+async fn health_ready(data: Data<AppState>) -> impl Responder {
+    let timeout = Duration::from_millis(data.config.health.check_timeout_ms);
+    let require_ffmpeg =
+        data.config.health.require_ffmpeg || data.config.jobs.publisher == PublisherKind::InProcess;
+
+    let (postgres, publisher, disk, ffmpeg) = tokio::join!(
+        check("postgres", true, timeout, check_postgres(&data)),
+        check(data.publisher.name(), true, timeout, check_publisher(&data)),
+        check("disk", true, timeout, check_disk(&data)),
+        check("ffmpeg", require_ffmpeg, timeout, check_ffmpeg(&data)),
+    );
+    let components = vec![postgres, publisher, disk, ffmpeg];
+
+    let down = |required: bool| components.iter().any(|c| c.required == required && c.status == "down");
+    let status = if down(true) {
+        "unavailable"
+    } else if down(false) {
+        "degraded"
+    } else {
+        "ready"
+    };
+
+    let body = Readiness { status, components };
+    if status == "unavailable" {
+        HttpResponse::ServiceUnavailable().json(body)
+    } else {
+        HttpResponse::Ok().json(body)
+    }
+}
+
 // Configuração das rotas para verificação de saúde
 pub fn config_health(conf: &mut ServiceConfig) {
-    conf.service(health_checker);
+    conf.service(health_checker)
+        .service(health_live)
+        .service(health_ready);
 }
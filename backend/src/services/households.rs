@@ -0,0 +1,191 @@
+// backend/src/services/households.rs
+//! Famílias: irmãos, pais e responsáveis agrupados, com endereços e telefones
+//! guardados uma vez só (`household_id`) em vez de repetidos em cada pessoa
+use actix_web::{
+    get, post, patch, delete,
+    web::{Data, Path, ServiceConfig},
+    HttpResponse,
+};
+use serde_json::json;
+use uuid::Uuid;
+
+use crate::{
+    audit::{self, Actor, Entity},
+    validation::ValidatedJson,
+    error::ApiError,
+    pagination::{ListParams, ListQuery},
+    repo::HouseholdRepo,
+    schema::{CreateHouseholdSchema, UpdateHouseholdSchema},
+    AppState,
+};
+
+#[utoipa::path(
+    tag = "households",
+    request_body = CreateHouseholdSchema,
+    responses(
+        (status = 201, description = "Registro criado", body = HouseholdResponse),
+        (status = 400, description = "Requisição inválida", body = ErrorBody),
+    )
+)]
+#[post("/households")]
+async fn create_household(
+    body: ValidatedJson<CreateHouseholdSchema>,
+    data: Data<AppState>,
+    actor: Actor
+) -> Result<HttpResponse, ApiError> {
+    let mut tx = data.db.begin().await?;
+    let household = HouseholdRepo::create(&mut tx, &body).await?;
+
+    audit::created(&mut tx, &actor, Entity::Household, household.id).await?;
+    tx.commit().await?;
+
+    Ok(HttpResponse::Created().json(json!({
+        "status": "success",
+        "household": household
+    })))
+}
+
+#[utoipa::path(
+    tag = "households",
+    params(
+        ListParams,
+        ("name" = Option<String>, Query, description = "Filtra por `name`"),
+    ),
+    responses(
+        (status = 200, description = "Página de resultados", body = HouseholdsPage),
+        (status = 400, description = "Requisição inválida", body = ErrorBody),
+    )
+)]
+#[get("/households")]
+async fn get_all_households(
+    query: ListQuery,
+    data: Data<AppState>
+) -> Result<HttpResponse, ApiError> {
+    let page = HouseholdRepo::list(&data.db, &query).await?;
+
+    Ok(HttpResponse::Ok().json(page.to_json("households")))
+}
+
+#[utoipa::path(
+    tag = "households",
+    responses(
+        (status = 200, description = "Registro encontrado", body = HouseholdResponse),
+        (status = 404, description = "Registro não encontrado", body = ErrorBody),
+    )
+)]
+#[get("/households/{id}")]
+async fn get_household_by_id(
+    path: Path<Uuid>,
+    data: Data<AppState>
+) -> Result<HttpResponse, ApiError> {
+    let household = HouseholdRepo::find(&data.db, path.into_inner())
+        .await?
+        .ok_or_else(|| ApiError::not_found("Household"))?;
+
+    Ok(HttpResponse::Ok().json(json!({
+        "status": "success",
+        "household": household
+    })))
+}
+
+/// Pais, responsáveis, alunos e contatos da família
+#[utoipa::path(
+    tag = "households",
+    responses(
+        (status = 200, description = "Integrantes da família", body = HouseholdMembersResponse),
+        (status = 404, description = "Registro não encontrado", body = ErrorBody),
+    )
+)]
+#[get("/households/{id}/members")]
+async fn get_household_members(
+    path: Path<Uuid>,
+    data: Data<AppState>
+) -> Result<HttpResponse, ApiError> {
+    let household_id = path.into_inner();
+
+    HouseholdRepo::find(&data.db, household_id)
+        .await?
+        .ok_or_else(|| ApiError::not_found("Household"))?;
+    let members = HouseholdRepo::members(&data.db, household_id).await?;
+
+    Ok(HttpResponse::Ok().json(json!({
+        "status": "success",
+        "members": members
+    })))
+}
+
+#[utoipa::path(
+    tag = "households",
+    request_body = UpdateHouseholdSchema,
+    responses(
+        (status = 200, description = "Registro atualizado", body = HouseholdResponse),
+        (status = 400, description = "Requisição inválida", body = ErrorBody),
+        (status = 404, description = "Registro não encontrado", body = ErrorBody),
+    )
+)]
+#[patch("/households/{id}")]
+async fn update_household_by_id(
+    path: Path<Uuid>,
+    body: ValidatedJson<UpdateHouseholdSchema>,
+    data: Data<AppState>,
+    actor: Actor
+) -> Result<HttpResponse, ApiError> {
+    let household_id = path.into_inner();
+
+    let mut tx = data.db.begin().await?;
+    let before = audit::snapshot(&mut tx, Entity::Household, household_id)
+        .await?
+        .ok_or_else(|| ApiError::not_found("Household"))?;
+
+    let household = HouseholdRepo::update(&mut tx, household_id, &body)
+        .await?
+        .ok_or_else(|| ApiError::not_found("Household"))?;
+
+    audit::updated(&mut tx, &actor, Entity::Household, household_id, before).await?;
+    tx.commit().await?;
+
+    Ok(HttpResponse::Ok().json(json!({
+        "status": "success",
+        "household": household
+    })))
+}
+
+/// Remove a família e os seus contatos; pais, responsáveis e alunos continuam
+/// cadastrados, sem família
+#[utoipa::path(
+    tag = "households",
+    responses(
+        (status = 204, description = "Registro removido"),
+        (status = 404, description = "Registro não encontrado", body = ErrorBody),
+    )
+)]
+#[delete("/households/{id}")]
+async fn delete_household_by_id(
+    path: Path<Uuid>,
+    data: Data<AppState>,
+    actor: Actor
+) -> Result<HttpResponse, ApiError> {
+    let household_id = path.into_inner();
+
+    let mut tx = data.db.begin().await?;
+    let before = audit::snapshot(&mut tx, Entity::Household, household_id)
+        .await?
+        .ok_or_else(|| ApiError::not_found("Household"))?;
+
+    HouseholdRepo::delete(&mut tx, household_id).await?;
+
+    audit::deleted(&mut tx, &actor, Entity::Household, household_id, before).await?;
+    tx.commit().await?;
+
+    Ok(HttpResponse::NoContent().finish())
+}
+
+// Configuração das rotas
+pub fn config_households(conf: &mut ServiceConfig) {
+    conf.service(create_household)
+        .service(get_all_households)
+        .service(get_household_by_id)
+        .service(get_household_members)
+        .service(update_household_by_id)
+        .service(delete_household_by_id);
+}
@@ -1,200 +1,138 @@
 use actix_web::{
-    get, post, delete, patch, web::{Data, Json, scope, Query, Path, ServiceConfig}, HttpResponse, Responder
+    get, post, web::{Data, Path, ServiceConfig}, HttpResponse
 };
 use serde_json::json;
 use crate::{
+    audit::Actor,
+    validation::ValidatedJson,
+    error::ApiError,
+    pagination::{Column, ListParams, ListQuery, ListSpec, SqlType, fetch_page},
     models::LogModel,
-    schema::{CreateLogSchema, UpdateLogSchema, FilterOptions},
+    schema::CreateLogSchema,
     AppState
 };
-use sqlx::PgPool;
 use uuid::Uuid;
 
+const LOG_COLUMNS: &str =
+    "id, user_id, action, description, timestamp, entity_type, entity_id, before, after, ip_address, request_id";
+
+// `logs` é somente-inserção: não há rotas de alteração nem de remoção
+#[utoipa::path(
+    tag = "logs",
+    request_body = CreateLogSchema,
+    responses(
+        (status = 200, description = "Registro criado", body = LogResponse),
+        (status = 400, description = "Requisição inválida", body = ErrorBody),
+        (status = 409, description = "Conflito com um registro existente", body = ErrorBody),
+        (status = 422, description = "Referência a um registro inexistente", body = ErrorBody),
+    )
+)]
 #[post("/logs")]
 async fn create_log(
-    body: Json<CreateLogSchema>,
+    body: ValidatedJson<CreateLogSchema>,
+    actor: Actor,
     data: Data<AppState>
-) -> impl Responder {
-    let query = r#"
-        INSERT INTO logs (user_id, action, description)
-        VALUES ($1, $2, $3)
-        RETURNING id, user_id, action, description, timestamp
-    "#;
+) -> Result<HttpResponse, ApiError> {
+    let query = format!(
+        r#"
+        INSERT INTO logs (user_id, action, description, ip_address, request_id)
+        VALUES ($1, $2, $3, $4, $5)
+        RETURNING {}
+        "#,
+        LOG_COLUMNS
+    );
 
-    match sqlx::query_as::<_, LogModel>(query)
-        .bind(&body.user_id)
+    let log = sqlx::query_as::<_, LogModel>(&query)
+        .bind(actor.user_id)
         .bind(&body.action)
         .bind(&body.description)
+        .bind(&actor.ip)
+        .bind(&actor.request_id)
         .fetch_one(&data.db)
-        .await
-    {
-        Ok(log) => {
-            let response = json!({
-                "status": "success",
-                "log": {
-                    "id": log.id,
-                    "user_id": log.user_id,
-                    "action": log.action,
-                    "description": log.description,
-                    "timestamp": log.timestamp
-                }
-            });
-            HttpResponse::Ok().json(response)
-        }
-        Err(error) => {
-            let response = json!({
-                "status": "error",
-                "message": format!("Failed to create log: {:?}", error)
-            });
-            HttpResponse::InternalServerError().json(response)
-        }
-    }
+        .await?;
+
+    Ok(HttpResponse::Ok().json(json!({
+        "status": "success",
+        "log": log
+    })))
 }
 
+const LOG_LIST: ListSpec = ListSpec {
+    from: "logs",
+    select: LOG_COLUMNS,
+    id: "id",
+    sorts: &[
+        Column::new("id", "id", SqlType::Uuid),
+        Column::new("timestamp", "COALESCE(logs.timestamp, 'epoch')", SqlType::TimestampTz),
+    ],
+    default_sort: "-timestamp",
+    filters: &[
+        Column::new("user_id", "user_id", SqlType::Uuid),
+        Column::new("action", "action", SqlType::Text),
+        Column::new("entity_type", "entity_type", SqlType::Text),
+        Column::new("entity_id", "entity_id", SqlType::Uuid),
+        Column::new("request_id", "request_id", SqlType::Text),
+        Column::since("from", "logs.timestamp", SqlType::TimestampTz),
+        Column::until("to", "logs.timestamp", SqlType::TimestampTz),
+    ],
+};
+
+#[utoipa::path(
+    tag = "logs",
+    params(
+        ListParams,
+        ("user_id" = Option<Uuid>, Query, description = "Filtra pelo autor declarado em `X-User-Id`"),
+        ("action" = Option<String>, Query, description = "Filtra por `action` (`create`, `update`, `delete`)"),
+        ("entity_type" = Option<String>, Query, description = "Filtra por `entity_type` (`student`, `parent`, `document`, `warning`, `user`, `student_guardian`, `household`)"),
+        ("entity_id" = Option<Uuid>, Query, description = "Filtra por `entity_id`; com `entity_type`, o histórico de um registro"),
+        ("request_id" = Option<String>, Query, description = "Filtra por `request_id`"),
+        ("from" = Option<String>, Query, description = "A partir deste instante, inclusive (RFC 3339)"),
+        ("to" = Option<String>, Query, description = "Antes deste instante (RFC 3339)"),
+    ),
+    responses(
+        (status = 200, description = "Página de resultados, mais recentes primeiro", body = LogsPage),
+        (status = 400, description = "Requisição inválida", body = ErrorBody),
+    )
+)]
 #[get("/logs")]
 pub async fn get_all_logs(
-    opts: Query<FilterOptions>,
+    query: ListQuery,
     data: Data<AppState>
-) -> impl Responder {
-    let limit = opts.limit.unwrap_or(10);
-    let offset = (opts.page.unwrap_or(1) - 1) * limit;
+) -> Result<HttpResponse, ApiError> {
+    let page = fetch_page::<LogModel>(&data.db, &LOG_LIST, &query).await?;
 
-    match sqlx::query_as!(
-        LogModel,
-        "SELECT * FROM logs ORDER BY id LIMIT $1 OFFSET $2",
-        limit as i32,
-        offset as i32
-    )
-    .fetch_all(&data.db)
-    .await
-    {
-        Ok(logs) => {
-            let response = json!({
-                "status": "success",
-                "logs": logs
-            });
-            HttpResponse::Ok().json(response)
-        }
-        Err(error) => {
-            let response = json!({
-                "status": "error",
-                "message": format!("Failed to get logs: {:?}", error)
-            });
-            HttpResponse::InternalServerError().json(response)
-        }
-    }
+    Ok(HttpResponse::Ok().json(page.to_json("logs")))
 }
 
+#[utoipa::path(
+    tag = "logs",
+    responses(
+        (status = 200, description = "Registro encontrado", body = LogResponse),
+        (status = 404, description = "Registro não encontrado", body = ErrorBody),
+    )
+)]
 #[get("/logs/{id}")]
 async fn get_log_by_id(
     path: Path<Uuid>,
     data: Data<AppState>
-) -> impl Responder {
+) -> Result<HttpResponse, ApiError> {
     let log_id = path.into_inner();
 
-    match sqlx::query_as!(
-        LogModel,
-        "SELECT * FROM logs WHERE id = $1",
-        log_id
-    )
-    .fetch_one(&data.db)
-    .await
-    {
-        Ok(log) => {
-            let response = json!({
-                "status": "success",
-                "log": log
-            });
-            HttpResponse::Ok().json(response)
-        }
-        Err(error) => {
-            let response = json!({
-                "status": "error",
-                "message": format!("Failed to get log: {:?}", error)
-            });
-            HttpResponse::InternalServerError().json(response)
-        }
-    }
-}
+    let log = sqlx::query_as::<_, LogModel>(&format!("SELECT {} FROM logs WHERE id = $1", LOG_COLUMNS))
+        .bind(log_id)
+        .fetch_optional(&data.db)
+        .await?
+        .ok_or_else(|| ApiError::not_found("Log"))?;
 
-#[patch("/logs/{id}")]
-async fn update_log_by_id(
-    path: Path<Uuid>,
-    body: Json<UpdateLogSchema>,
-    data: Data<AppState>
-) -> impl Responder {
-    let log_id = path.into_inner();
-
-    match sqlx::query_as!(LogModel, "SELECT * FROM logs WHERE id = $1", log_id)
-        .fetch_one(&data.db)
-        .await
-    {
-        Ok(log) => {
-            let update_result = sqlx::query_as!(
-                LogModel,
-                "UPDATE logs SET user_id = COALESCE($1, user_id), action = COALESCE($2, action), description = COALESCE($3, description) WHERE id = $4 RETURNING *",
-                body.user_id.as_ref(),
-                body.action.as_ref(),
-                body.description.as_ref(),
-                log_id
-            )
-            .fetch_one(&data.db)
-            .await;
-
-            match update_result {
-                Ok(updated_log) => {
-                    let response = json!({
-                        "status": "success",
-                        "log": updated_log
-                    });
-                    HttpResponse::Ok().json(response)
-                }
-                Err(update_error) => {
-                    let response = json!({
-                        "status": "error",
-                        "message": format!("Failed to update log: {:?}", update_error)
-                    });
-                    HttpResponse::InternalServerError().json(response)
-                }
-            }
-        }
-        Err(fetch_error) => {
-            let response = json!({
-                "status": "error",
-                "message": format!("Log not found: {:?}", fetch_error)
-            });
-            HttpResponse::NotFound().json(response)
-        }
-    }
-}
-
-#[delete("/logs/{id}")]
-async fn delete_log_by_id(
-    path: Path<Uuid>,
-    data: Data<AppState>
-) -> impl Responder {
-    let log_id = path.into_inner();
-
-    match sqlx::query!("DELETE FROM logs WHERE id = $1", log_id)
-        .execute(&data.db)
-        .await
-    {
-        Ok(_) => HttpResponse::NoContent().finish(),
-        Err(err) => {
-            let response = json!({
-                "status": "error",
-                "message": format!("Failed to delete log: {:?}", err)
-            });
-            HttpResponse::InternalServerError().json(response)
-        }
-    }
+    Ok(HttpResponse::Ok().json(json!({
+        "status": "success",
+        "log": log
+    })))
 }
 
 // Configuração das rotas para tarefas
 pub fn config_logs(conf: &mut ServiceConfig) {
     conf.service(create_log)
        .service(get_all_logs)
-       .service(get_log_by_id)
-       .service(update_log_by_id)
-       .service(delete_log_by_id);
-}
\ No newline at end of file
+       .service(get_log_by_id);
+}
@@ -1,86 +1,106 @@
 use actix_web::{
     get, post, delete, patch,
-    web::{Data, Json, Path, Query, ServiceConfig},
-    HttpResponse, Responder,
+    web::{Data, Path, ServiceConfig},
+    HttpResponse,
 };
 use serde_json::json;
 use uuid::Uuid;
 
 use crate::{
+    validation::ValidatedJson,
     AppState,
+    error::ApiError,
+    pagination::{Column, ListParams, ListQuery, ListSpec, SqlType, fetch_page},
     models::MessageModel,
-    schema::{CreateMessageSchema, UpdateMessageSchema, FilterOptions},
+    schema::{CreateMessageSchema, UpdateMessageSchema},
 };
 
 /// Criar nova mensagem
+#[utoipa::path(
+    tag = "chat",
+    request_body = CreateMessageSchema,
+    responses(
+        (status = 201, description = "Registro criado", body = MessageResponse),
+        (status = 400, description = "Requisição inválida", body = ErrorBody),
+        (status = 409, description = "Conflito com um registro existente", body = ErrorBody),
+        (status = 422, description = "Referência a um registro inexistente", body = ErrorBody),
+    )
+)]
 #[post("/messages")]
 async fn create_message(
-    body: Json<CreateMessageSchema>,
+    body: ValidatedJson<CreateMessageSchema>,
     data: Data<AppState>,
-) -> impl Responder {
+) -> Result<HttpResponse, ApiError> {
     let query = r#"
         INSERT INTO messages (room_id, sender_id, content)
         VALUES ($1, $2, $3)
         RETURNING id, room_id, sender_id, content, sent_at
     "#;
 
-    match sqlx::query_as::<_, MessageModel>(query)
+    let message = sqlx::query_as::<_, MessageModel>(query)
         .bind(body.room_id)
         .bind(body.sender_id)
         .bind(&body.content)
         .fetch_one(&data.db)
-        .await
-    {
-        Ok(message) => HttpResponse::Created().json(json!({
-            "status": "success",
-            "message": message
-        })),
-        Err(e) => HttpResponse::InternalServerError().json(json!({
-            "status": "error",
-            "message": format!("Error creating message: {:?}", e)
-        })),
-    }
+        .await?;
+
+    Ok(HttpResponse::Created().json(json!({
+        "status": "success",
+        "message": message
+    })))
 }
 
+const MESSAGE_LIST: ListSpec = ListSpec {
+    from: "messages",
+    select: "id, room_id, sender_id, content, sent_at",
+    id: "id",
+    sorts: &[
+        Column::new("id", "id", SqlType::Uuid),
+        Column::new("sent_at", "COALESCE(sent_at, 'epoch')", SqlType::TimestampTz),
+    ],
+    default_sort: "-sent_at",
+    filters: &[
+        Column::new("room_id", "room_id", SqlType::Uuid),
+        Column::new("sender_id", "sender_id", SqlType::Uuid),
+    ],
+};
+
 /// Buscar todas as mensagens
+#[utoipa::path(
+    tag = "chat",
+    params(
+        ListParams,
+        ("room_id" = Option<Uuid>, Query, description = "Filtra por `room_id`"),
+        ("sender_id" = Option<Uuid>, Query, description = "Filtra por `sender_id`"),
+    ),
+    responses(
+        (status = 200, description = "Página de resultados", body = MessagesPage),
+        (status = 400, description = "Requisição inválida", body = ErrorBody),
+    )
+)]
 #[get("/messages")]
 async fn get_all_messages(
-    opts: Query<FilterOptions>,
-    data: Data<AppState>,
-) -> impl Responder {
-    let limit = opts.limit.unwrap_or(10);
-    let offset = (opts.page.unwrap_or(1) - 1) * limit;
-
-    let query = r#"
-        SELECT id, room_id, sender_id, content, sent_at
-        FROM messages
-        ORDER BY sent_at DESC
-        LIMIT $1 OFFSET $2
-    "#;
+    query: ListQuery,
+    data: Data<AppState>
+) -> Result<HttpResponse, ApiError> {
+    let page = fetch_page::<MessageModel>(&data.db, &MESSAGE_LIST, &query).await?;
 
-    match sqlx::query_as::<_, MessageModel>(query)
-        .bind(limit as i64)
-        .bind(offset as i64)
-        .fetch_all(&data.db)
-        .await
-    {
-        Ok(messages) => HttpResponse::Ok().json(json!({
-            "status": "success",
-            "messages": messages
-        })),
-        Err(e) => HttpResponse::InternalServerError().json(json!({
-            "status": "error",
-            "message": format!("Error fetching messages: {:?}", e)
-        })),
-    }
+    Ok(HttpResponse::Ok().json(page.to_json("messages")))
 }
 
 /// Buscar mensagem por ID
+#[utoipa::path(
+    tag = "chat",
+    responses(
+        (status = 200, description = "Registro encontrado", body = MessageResponse),
+        (status = 404, description = "Registro não encontrado", body = ErrorBody),
+    )
+)]
 #[get("/messages/{id}")]
 async fn get_message_by_id(
     path: Path<Uuid>,
     data: Data<AppState>,
-) -> impl Responder {
+) -> Result<HttpResponse, ApiError> {
     let id = path.into_inner();
 
     let query = r#"
@@ -89,29 +109,36 @@ async fn get_message_by_id(
         WHERE id = $1
     "#;
 
-    match sqlx::query_as::<_, MessageModel>(query)
+    let message = sqlx::query_as::<_, MessageModel>(query)
         .bind(id)
-        .fetch_one(&data.db)
-        .await
-    {
-        Ok(message) => HttpResponse::Ok().json(json!({
-            "status": "success",
-            "message": message
-        })),
-        Err(e) => HttpResponse::NotFound().json(json!({
-            "status": "error",
-            "message": format!("Message not found: {:?}", e)
-        })),
-    }
+        .fetch_optional(&data.db)
+        .await?
+        .ok_or_else(|| ApiError::not_found("Message"))?;
+
+    Ok(HttpResponse::Ok().json(json!({
+        "status": "success",
+        "message": message
+    })))
 }
 
 /// Atualizar uma mensagem
+#[utoipa::path(
+    tag = "chat",
+    request_body = UpdateMessageSchema,
+    responses(
+        (status = 200, description = "Registro atualizado", body = MessageResponse),
+        (status = 400, description = "Requisição inválida", body = ErrorBody),
+        (status = 404, description = "Registro não encontrado", body = ErrorBody),
+        (status = 409, description = "Conflito com um registro existente", body = ErrorBody),
+        (status = 422, description = "Referência a um registro inexistente", body = ErrorBody),
+    )
+)]
 #[patch("/messages/{id}")]
 async fn update_message_by_id(
     path: Path<Uuid>,
-    body: Json<UpdateMessageSchema>,
+    body: ValidatedJson<UpdateMessageSchema>,
     data: Data<AppState>,
-) -> impl Responder {
+) -> Result<HttpResponse, ApiError> {
     let id = path.into_inner();
 
     let query = r#"
@@ -121,41 +148,43 @@ async fn update_message_by_id(
         RETURNING id, room_id, sender_id, content, sent_at
     "#;
 
-    match sqlx::query_as::<_, MessageModel>(query)
+    let updated = sqlx::query_as::<_, MessageModel>(query)
         .bind(body.content.as_ref())
         .bind(id)
-        .fetch_one(&data.db)
-        .await
-    {
-        Ok(updated) => HttpResponse::Ok().json(json!({
-            "status": "success",
-            "message": updated
-        })),
-        Err(e) => HttpResponse::InternalServerError().json(json!({
-            "status": "error",
-            "message": format!("Failed to update message: {:?}", e)
-        })),
-    }
+        .fetch_optional(&data.db)
+        .await?
+        .ok_or_else(|| ApiError::not_found("Message"))?;
+
+    Ok(HttpResponse::Ok().json(json!({
+        "status": "success",
+        "message": updated
+    })))
 }
 
 /// Deletar uma mensagem
+#[utoipa::path(
+    tag = "chat",
+    responses(
+        (status = 204, description = "Registro removido"),
+        (status = 404, description = "Registro não encontrado", body = ErrorBody),
+    )
+)]
 #[delete("/messages/{id}")]
 async fn delete_message_by_id(
     path: Path<Uuid>,
     data: Data<AppState>,
-) -> impl Responder {
+) -> Result<HttpResponse, ApiError> {
     let id = path.into_inner();
 
-    match sqlx::query!("DELETE FROM messages WHERE id = $1", id)
+    let result = sqlx::query!("DELETE FROM messages WHERE id = $1", id)
         .execute(&data.db)
-        .await
-    {
-        Ok(_) => HttpResponse::NoContent().finish(),
-        Err(e) => HttpResponse::InternalServerError().json(json!({
-            "status": "error",
-            "message": format!("Failed to delete message: {:?}", e)
-        })),
+        .await?;
+
+    if result.rows_affected() == 0 {
+        return Err(ApiError::not_found("Message"));
     }
+
+    Ok(HttpResponse::NoContent().finish())
 }
 
 /// Registrar todas as rotas do módulo messages
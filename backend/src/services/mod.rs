@@ -1,26 +1,25 @@
 //backend/src/servides/mod.rs
+use actix_files::Files;
 use actix_web::web::ServiceConfig;
+use app_config::StorageConfig;
 
-pub mod videos;
-pub mod videomedias;
+pub mod video;
 pub mod tags;
 pub mod users;
 pub mod parents;
 pub mod students;
 pub mod guardians;
+pub mod student_guardians;
+pub mod households;
 pub mod addresses;
 pub mod phones;
-pub mod videouploads;
 pub mod videotags;
-pub mod playes;
 pub mod photos;
 pub mod documents;
 pub mod file_metadatas;
 pub mod logs;
 pub mod health;
 pub mod tasks;
-pub mod meus_videos;
-pub mod all_videos;
 
 // Novos módulos
 pub mod attendances;
@@ -29,30 +28,29 @@ pub mod chat_rooms;
 pub mod messages;
 pub mod schedule_changes;
 pub mod groups;
+pub mod group_schedules;
+pub mod calendar_feeds;
 
-pub fn config(cfg: &mut ServiceConfig) {
+pub fn config(cfg: &mut ServiceConfig, storage: &StorageConfig) {
     cfg.service(
         actix_web::web::scope("/api")
-            .configure(videos::config_videos)
-            .configure(videomedias::configurar_videos_media)
+            .configure(video::config_video)
             .configure(tags::config_tags)
             .configure(videotags::config_videotags)
             .configure(users::config_users)
             .configure(parents::config_parents)
             .configure(students::config_students)
             .configure(guardians::config_guardians)
+            .configure(student_guardians::config_student_guardians)
+            .configure(households::config_households)
             .configure(addresses::config_addresses)
             .configure(phones::config_phones)
-            .configure(videouploads::video_uploads_scope)
-            .configure(playes::config_playes)
             .configure(health::config_health)
             .configure(tasks::config_tasks)
             .configure(documents::config_documents)
             .configure(photos::config_photos)
             .configure(file_metadatas::config_file_metadatas)
             .configure(logs::config_logs)
-            .configure(meus_videos::config_meus_videos)
-            .configure(all_videos::config_all_videos)
             // Novos serviços
             .configure(attendances::config_attendances)
             .configure(warnings::config_warnings)
@@ -60,5 +58,10 @@ pub fn config(cfg: &mut ServiceConfig) {
             .configure(messages::config_messages)
             .configure(schedule_changes::config_schedule_changes)
             .configure(groups::config_groups)
+            .configure(group_schedules::config_group_schedules)
+            .configure(calendar_feeds::config_calendar_feeds)
+            // Arquivos enviados, também acessíveis sob `/api`
+            .service(Files::new("/static", &storage.static_dir).show_files_listing())
+            .service(Files::new("/uploads", &storage.uploads_dir).show_files_listing())
     );
 }
@@ -1,205 +1,213 @@
 //backend/src/services/parents.rs
 use actix_web::{
     get, post, delete, patch,
-    web::{Data, Json, Query, Path, ServiceConfig},
-    HttpResponse, Responder,
+    web::{Data, Path, ServiceConfig},
+    HttpResponse,
 };
 use serde_json::json;
 use crate::{
+    audit::{self, Actor, Entity},
+    validation::ValidatedJson,
+    error::ApiError,
+    pagination::{Column, ListParams, ListQuery, ListSpec, SqlType, fetch_page},
     models::ParentModel,
-    schema::{CreateParentSchema, UpdateParentSchema, FilterOptions},
+    schema::{CreateParentSchema, UpdateParentSchema},
     AppState,
 };
-use sqlx::PgPool;
 use uuid::Uuid;
 
+#[utoipa::path(
+    tag = "parents",
+    request_body = CreateParentSchema,
+    responses(
+        (status = 201, description = "Registro criado", body = ParentResponse),
+        (status = 400, description = "Requisição inválida", body = ErrorBody),
+        (status = 409, description = "Conflito com um registro existente", body = ErrorBody),
+        (status = 422, description = "Referência a um registro inexistente", body = ErrorBody),
+    )
+)]
 #[post("/parents")]
 async fn create_parent(
-    body: Json<CreateParentSchema>,
+    body: ValidatedJson<CreateParentSchema>,
     data: Data<AppState>,
-) -> impl Responder {
+    actor: Actor,
+) -> Result<HttpResponse, ApiError> {
     let query = r#"
-        INSERT INTO parents (user_id, name, email)
-        VALUES ($1, $2, $3)
-        RETURNING id, user_id, name, email, parents_date
+        INSERT INTO parents (user_id, name, email, household_id)
+        VALUES ($1, $2, $3, $4)
+        RETURNING id, user_id, name, email, parents_date, household_id
     "#;
 
-    match sqlx::query_as::<_, ParentModel>(query)
+    let mut tx = data.db.begin().await?;
+    let parent = sqlx::query_as::<_, ParentModel>(query)
         .bind(body.user_id)
         .bind(&body.name)
         .bind(&body.email)
-        .fetch_one(&data.db)
-        .await
-    {
-        Ok(parent) => {
-            let response = json!({
-                "status": "success",
-                "parent": {
-                    "id": parent.id,
-                    "user_id": parent.user_id,
-                    "name": parent.name,
-                    "email": parent.email,
-                    "parents_date": parent.parents_date
-                }
-            });
-            HttpResponse::Created().json(response)
-        }
-        Err(error) => {
-            let response = json!({
-                "status": "error",
-                "message": format!("Failed to create parent: {:?}", error)
-            });
-            HttpResponse::InternalServerError().json(response)
+        .bind(body.household_id)
+        .fetch_one(&mut tx)
+        .await?;
+
+    audit::created(&mut tx, &actor, Entity::Parent, parent.id).await?;
+    tx.commit().await?;
+
+    Ok(HttpResponse::Created().json(json!({
+        "status": "success",
+        "parent": {
+            "id": parent.id,
+            "user_id": parent.user_id,
+            "name": parent.name,
+            "email": parent.email,
+            "parents_date": parent.parents_date,
+            "household_id": parent.household_id
         }
-    }
+    })))
 }
 
+const PARENT_LIST: ListSpec = ListSpec {
+    from: "parents",
+    select: "id, user_id, name, email, parents_date, household_id",
+    id: "id",
+    sorts: &[
+        Column::new("id", "id", SqlType::Uuid),
+        Column::new("name", "name", SqlType::Text),
+        Column::new("email", "email", SqlType::Text),
+        Column::new("parents_date", "COALESCE(parents_date, 'epoch')", SqlType::TimestampTz),
+    ],
+    default_sort: "id",
+    filters: &[
+        Column::new("user_id", "user_id", SqlType::Uuid),
+        Column::new("email", "email", SqlType::Text),
+        Column::new("household_id", "household_id", SqlType::Uuid),
+    ],
+};
+
+#[utoipa::path(
+    tag = "parents",
+    params(
+        ListParams,
+        ("user_id" = Option<Uuid>, Query, description = "Filtra por `user_id`"),
+        ("email" = Option<String>, Query, description = "Filtra por `email`"),
+        ("household_id" = Option<Uuid>, Query, description = "Filtra por `household_id`"),
+    ),
+    responses(
+        (status = 200, description = "Página de resultados", body = ParentsPage),
+        (status = 400, description = "Requisição inválida", body = ErrorBody),
+    )
+)]
 #[get("/parents")]
 pub async fn get_all_parents(
-    opts: Query<FilterOptions>,
-    data: Data<AppState>,
-) -> impl Responder {
-    let limit = opts.limit.unwrap_or(10) as i64; // Convert limit to i64
-    let offset = (opts.page.unwrap_or(1) - 1) as i64 * limit; // Convert offset to i64
+    query: ListQuery,
+    data: Data<AppState>
+) -> Result<HttpResponse, ApiError> {
+    let page = fetch_page::<ParentModel>(&data.db, &PARENT_LIST, &query).await?;
 
-    match sqlx::query_as!(
-        ParentModel,
-        "SELECT * FROM parents ORDER BY id LIMIT $1 OFFSET $2",
-        limit as i32,
-        offset as i32
-    )
-    .fetch_all(&data.db)
-    .await
-    {
-        Ok(parents) => {
-            let response = json!({
-                "status": "success",
-                "parents": parents
-            });
-            HttpResponse::Ok().json(response)
-        }
-        Err(error) => {
-            let response = json!({
-                "status": "error",
-                "message": format!("Failed to get parents: {:?}", error)
-            });
-            HttpResponse::InternalServerError().json(response)
-        }
-    }
+    Ok(HttpResponse::Ok().json(page.to_json("parents")))
 }
 
+#[utoipa::path(
+    tag = "parents",
+    responses(
+        (status = 200, description = "Registro encontrado", body = ParentResponse),
+        (status = 404, description = "Registro não encontrado", body = ErrorBody),
+    )
+)]
 #[get("/parents/{id}")]
 async fn get_parent_by_id(
     path: Path<Uuid>, // Path will be directly used
     data: Data<AppState>,
-) -> impl Responder {
+) -> Result<HttpResponse, ApiError> {
     let parent_id = path.into_inner();
 
-    match sqlx::query_as!(
+    let parent = sqlx::query_as!(
         ParentModel,
         "SELECT * FROM parents WHERE id = $1",
         parent_id
     )
-    .fetch_one(&data.db)
-    .await
-    {
-        Ok(parent) => {
-            let response = json!({
-                "status": "success",
-                "parent": parent
-            });
-            HttpResponse::Ok().json(response)
-        }
-        Err(error) => {
-            let response = json!({
-                "status": "error",
-                "message": format!("Failed to get parent: {:?}", error)
-            });
-            HttpResponse::InternalServerError().json(response)
-        }
-    }
+    .fetch_optional(&data.db)
+    .await?
+    .ok_or_else(|| ApiError::not_found("Parent"))?;
+
+    Ok(HttpResponse::Ok().json(json!({
+        "status": "success",
+        "parent": parent
+    })))
 }
 
+#[utoipa::path(
+    tag = "parents",
+    request_body = UpdateParentSchema,
+    responses(
+        (status = 200, description = "Registro atualizado", body = ParentResponse),
+        (status = 400, description = "Requisição inválida", body = ErrorBody),
+        (status = 404, description = "Registro não encontrado", body = ErrorBody),
+        (status = 409, description = "Conflito com um registro existente", body = ErrorBody),
+        (status = 422, description = "Referência a um registro inexistente", body = ErrorBody),
+    )
+)]
 #[patch("/parents/{id}")]
 async fn update_parent_by_id(
     path: Path<Uuid>,
-    body: Json<UpdateParentSchema>,
+    body: ValidatedJson<UpdateParentSchema>,
     data: Data<AppState>,
-) -> impl Responder {
+    actor: Actor,
+) -> Result<HttpResponse, ApiError> {
     let parent_id = path.into_inner();
 
-    match sqlx::query_as!(ParentModel, "SELECT * FROM parents WHERE id = $1", parent_id)
-        .fetch_one(&data.db)
-        .await
-    {
-        Ok(parent) => {
-            let update_result = sqlx::query_as!(
-                ParentModel,
-                "UPDATE parents SET user_id = COALESCE($1, user_id), name = COALESCE($2, name), email = COALESCE($3, email) WHERE id = $4 RETURNING *",
-                body.user_id.as_ref(),
-                body.name.as_ref(),
-                body.email.as_ref(),
-                parent_id
-            )
-            .fetch_one(&data.db)
-            .await;
-
-            match update_result {
-                Ok(updated_parent) => {
-                    let response = json!({
-                        "status": "success",
-                        "parent": updated_parent
-                    });
-                    HttpResponse::Ok().json(response)
-                }
-                Err(update_error) => {
-                    let response = json!({
-                        "status": "error",
-                        "message": format!("Failed to update parent: {:?}", update_error)
-                    });
-                    HttpResponse::InternalServerError().json(response)
-                }
-            }
-        }
-        Err(fetch_error) => {
-            let response = json!({
-                "status": "error",
-                "message": format!("User not found: {:?}", fetch_error)
-            });
-            HttpResponse::NotFound().json(response)
-        }
-    }
+    let mut tx = data.db.begin().await?;
+    let before = audit::snapshot(&mut tx, Entity::Parent, parent_id)
+        .await?
+        .ok_or_else(|| ApiError::not_found("Parent"))?;
+
+    let updated_parent = sqlx::query_as!(
+        ParentModel,
+        "UPDATE parents SET user_id = COALESCE($1, user_id), name = COALESCE($2, name), email = COALESCE($3, email), household_id = COALESCE($4, household_id) WHERE id = $5 RETURNING *",
+        body.user_id.as_ref(),
+        body.name.as_ref(),
+        body.email.as_ref(),
+        body.household_id.as_ref(),
+        parent_id
+    )
+    .fetch_optional(&mut tx)
+    .await?
+    .ok_or_else(|| ApiError::not_found("Parent"))?;
+
+    audit::updated(&mut tx, &actor, Entity::Parent, parent_id, before).await?;
+    tx.commit().await?;
+
+    Ok(HttpResponse::Ok().json(json!({
+        "status": "success",
+        "parent": updated_parent
+    })))
 }
 
+#[utoipa::path(
+    tag = "parents",
+    responses(
+        (status = 204, description = "Registro removido"),
+        (status = 404, description = "Registro não encontrado", body = ErrorBody),
+    )
+)]
 #[delete("/parents/{id}")]
 async fn delete_parent_by_id(
     path: Path<Uuid>,
     data: Data<AppState>,
-) -> impl Responder {
+    actor: Actor,
+) -> Result<HttpResponse, ApiError> {
     let parent_id = path.into_inner();
 
-    match sqlx::query!("DELETE FROM parents WHERE id = $1", parent_id)
-        .execute(&data.db)
-        .await
-    {
-        Ok(result) if result.rows_affected() > 0 => {
-            HttpResponse::NoContent().finish()
-        }
-        Ok(_) => {
-            let response = json!({
-                "status": "error",
-                "message": "Parent not found."
-            });
-            HttpResponse::NotFound().json(response)
-        }
-        Err(err) => {
-            let response = json!({
-                "status": "error",
-                "message": format!("Failed to delete parent: {:?}", err)
-            });
-            HttpResponse::InternalServerError().json(response)
-        }
-    }
+    let mut tx = data.db.begin().await?;
+    let before = audit::snapshot(&mut tx, Entity::Parent, parent_id)
+        .await?
+        .ok_or_else(|| ApiError::not_found("Parent"))?;
+
+    sqlx::query!("DELETE FROM parents WHERE id = $1", parent_id)
+        .execute(&mut tx)
+        .await?;
+
+    audit::deleted(&mut tx, &actor, Entity::Parent, parent_id, before).await?;
+    tx.commit().await?;
+
+    Ok(HttpResponse::NoContent().finish())
 }
 
 // Configuração das rotas para tarefas
@@ -1,285 +1,219 @@
 use actix_web::{
-    get, post, delete, patch, web::{Data, Json, Path, Query, ServiceConfig},
-    HttpResponse, Responder
+    get, post, delete, patch, web::{Data, Path, ServiceConfig},
+    HttpResponse,
 };
-use actix_files::Files;
 use actix_multipart::Multipart;
 use futures_util::StreamExt;
-use tokio::io::AsyncWriteExt;
+use std::path::Path as FsPath;
 use serde_json::json;
-use sqlx::PgPool;
 use uuid::Uuid;
 use crate::{
-    models::{PhotoModel, StudentModel},
-    schema::{CreatePhotoSchema, UpdatePhotoSchema, FilterOptions},
+    validation::ValidatedJson,
+    error::ApiError,
+    pagination::{Column, ListParams, ListQuery, ListSpec, SqlType, fetch_page},
+    models::PhotoModel,
+    schema::{CreatePhotoSchema, UpdatePhotoSchema},
+    storage,
     AppState
 };
 
 // Função para criar uma nova foto
+#[utoipa::path(
+    tag = "photos",
+    request_body = CreatePhotoSchema,
+    responses(
+        (status = 200, description = "Registro criado", body = PhotoResponse),
+        (status = 400, description = "Requisição inválida", body = ErrorBody),
+        (status = 409, description = "Conflito com um registro existente", body = ErrorBody),
+        (status = 422, description = "Referência a um registro inexistente", body = ErrorBody),
+    )
+)]
 #[post("/photos")]
 async fn create_photo(
-    body: Json<CreatePhotoSchema>,
+    body: ValidatedJson<CreatePhotoSchema>,
     data: Data<AppState>
-) -> impl Responder {
+) -> Result<HttpResponse, ApiError> {
     let query = r#"
         INSERT INTO photos (student_id, filename, description)
         VALUES ($1, $2, $3)
         RETURNING id, student_id, filename, description, created_at
     "#;
 
-    match sqlx::query_as::<_, PhotoModel>(query)
-        .bind(&body.student_id)
+    let photo = sqlx::query_as::<_, PhotoModel>(query)
+        .bind(body.student_id)
         .bind(&body.filename)
         .bind(&body.description)
         .fetch_one(&data.db)
-        .await
-    {
-        Ok(photo) => {
-            let response = json!({
-                "status": "success",
-                "photo": {
-                    "id": photo.id,
-                    "student_id": photo.student_id,
-                    "filename": photo.filename,
-                    "description": photo.description,
-                    "created_at": photo.created_at
-                }
-            });
-            HttpResponse::Ok().json(response)
-        }
-        Err(error) => {
-            let response = json!({
-                "status": "error",
-                "message": format!("Failed to create photo: {:?}", error)
-            });
-            HttpResponse::InternalServerError().json(response)
+        .await?;
+
+    Ok(HttpResponse::Ok().json(json!({
+        "status": "success",
+        "photo": {
+            "id": photo.id,
+            "student_id": photo.student_id,
+            "filename": photo.filename,
+            "description": photo.description,
+            "created_at": photo.created_at
         }
-    }
+    })))
 }
 
 // Função para obter todas as fotos
+const PHOTO_LIST: ListSpec = ListSpec {
+    from: "photos",
+    select: "id, student_id, filename, description, created_at",
+    id: "id",
+    sorts: &[
+        Column::new("id", "id", SqlType::Uuid),
+        Column::new("created_at", "COALESCE(created_at, 'epoch')", SqlType::TimestampTz),
+    ],
+    default_sort: "id",
+    filters: &[Column::new("student_id", "student_id", SqlType::Uuid)],
+};
+
+#[utoipa::path(
+    tag = "photos",
+    params(
+        ListParams,
+        ("student_id" = Option<Uuid>, Query, description = "Filtra por `student_id`"),
+    ),
+    responses(
+        (status = 200, description = "Página de resultados", body = PhotosPage),
+        (status = 400, description = "Requisição inválida", body = ErrorBody),
+    )
+)]
 #[get("/photos")]
 pub async fn get_all_photos(
-    opts: Query<FilterOptions>,
+    query: ListQuery,
     data: Data<AppState>
-) -> impl Responder {
-    let limit = opts.limit.unwrap_or(10);
-    let offset = (opts.page.unwrap_or(1) - 1) * limit;
+) -> Result<HttpResponse, ApiError> {
+    let page = fetch_page::<PhotoModel>(&data.db, &PHOTO_LIST, &query).await?;
 
-    match sqlx::query_as!(
-        PhotoModel,
-        "SELECT * FROM photos ORDER BY id LIMIT $1 OFFSET $2",
-        limit as i32,
-        offset as i32
-    )
-    .fetch_all(&data.db)
-    .await
-    {
-        Ok(photos) => {
-            let response = json!({
-                "status": "success",
-                "photos": photos
-            });
-            HttpResponse::Ok().json(response)
-        }
-        Err(error) => {
-            let response = json!({
-                "status": "error",
-                "message": format!("Failed to get photos: {:?}", error)
-            });
-            HttpResponse::InternalServerError().json(response)
-        }
-    }
+    Ok(HttpResponse::Ok().json(page.to_json("photos")))
 }
 
 // Função para obter uma foto por ID
+#[utoipa::path(
+    tag = "photos",
+    responses(
+        (status = 200, description = "Registro encontrado", body = PhotoResponse),
+        (status = 404, description = "Registro não encontrado", body = ErrorBody),
+    )
+)]
 #[get("/photos/{id}")]
 async fn get_photo_by_id(
     path: Path<Uuid>,
     data: Data<AppState>
-) -> impl Responder {
+) -> Result<HttpResponse, ApiError> {
     let photo_id = path.into_inner();
 
-    match sqlx::query_as!(
+    let photo = sqlx::query_as!(
         PhotoModel,
         "SELECT * FROM photos WHERE id = $1",
         photo_id
     )
-    .fetch_one(&data.db)
-    .await
-    {
-        Ok(photo) => {
-            let response = json!({
-                "status": "success",
-                "photo": photo
-            });
-            HttpResponse::Ok().json(response)
-        }
-        Err(error) => {
-            let response = json!({
-                "status": "error",
-                "message": format!("Failed to get photo: {:?}", error)
-            });
-            HttpResponse::InternalServerError().json(response)
-        }
-    }
+    .fetch_optional(&data.db)
+    .await?
+    .ok_or_else(|| ApiError::not_found("Photo"))?;
+
+    Ok(HttpResponse::Ok().json(json!({
+        "status": "success",
+        "photo": photo
+    })))
 }
 
 // Função para atualizar uma foto
+#[utoipa::path(
+    tag = "photos",
+    request_body = UpdatePhotoSchema,
+    responses(
+        (status = 200, description = "Registro atualizado", body = PhotoResponse),
+        (status = 400, description = "Requisição inválida", body = ErrorBody),
+        (status = 404, description = "Registro não encontrado", body = ErrorBody),
+        (status = 409, description = "Conflito com um registro existente", body = ErrorBody),
+        (status = 422, description = "Referência a um registro inexistente", body = ErrorBody),
+    )
+)]
 #[patch("/photos/{id}")]
 async fn update_photo_by_id(
     path: Path<Uuid>,
-    body: Json<UpdatePhotoSchema>,
+    body: ValidatedJson<UpdatePhotoSchema>,
     data: Data<AppState>
-) -> impl Responder {
+) -> Result<HttpResponse, ApiError> {
     let photo_id = path.into_inner();
 
-    match sqlx::query_as!(
+    let updated_photo = sqlx::query_as!(
         PhotoModel,
-        "SELECT * FROM photos WHERE id = $1",
+        "UPDATE photos SET student_id = COALESCE($1, student_id), filename = COALESCE($2, filename), description = COALESCE($3, description) WHERE id = $4 RETURNING *",
+        body.student_id.as_ref(),
+        body.filename.as_ref(),
+        body.description.as_ref(),
         photo_id
     )
-    .fetch_one(&data.db)
-    .await
-    {
-        Ok(_) => {
-            let update_result = sqlx::query_as!(
-                PhotoModel,
-                "UPDATE photos SET student_id = COALESCE($1, student_id), filename = COALESCE($2, filename), description = COALESCE($3, description) WHERE id = $4 RETURNING *",
-                body.student_id.as_ref(),
-                body.filename.as_ref(),
-                body.description.as_ref(),
-                photo_id
-            )
-            .fetch_one(&data.db)
-            .await;
-
-            match update_result {
-                Ok(updated_photo) => {
-                    let response = json!({
-                        "status": "success",
-                        "photo": updated_photo
-                    });
-                    HttpResponse::Ok().json(response)
-                }
-                Err(update_error) => {
-                    let response = json!({
-                        "status": "error",
-                        "message": format!("Failed to update photo: {:?}", update_error)
-                    });
-                    HttpResponse::InternalServerError().json(response)
-                }
-            }
-        }
-        Err(fetch_error) => {
-            let response = json!({
-                "status": "error",
-                "message": format!("Photo not found: {:?}", fetch_error)
-            });
-            HttpResponse::NotFound().json(response)
-        }
-    }
+    .fetch_optional(&data.db)
+    .await?
+    .ok_or_else(|| ApiError::not_found("Photo"))?;
+
+    Ok(HttpResponse::Ok().json(json!({
+        "status": "success",
+        "photo": updated_photo
+    })))
 }
 
 // Função para deletar uma foto por ID
+#[utoipa::path(
+    tag = "photos",
+    responses(
+        (status = 204, description = "Registro removido"),
+        (status = 404, description = "Registro não encontrado", body = ErrorBody),
+    )
+)]
 #[delete("/photos/{id}")]
 async fn delete_photo_by_id(
     path: Path<Uuid>,
     data: Data<AppState>
-) -> impl Responder {
+) -> Result<HttpResponse, ApiError> {
     let photo_id = path.into_inner();
 
-    match sqlx::query!("DELETE FROM photos WHERE id = $1", photo_id)
+    let result = sqlx::query!("DELETE FROM photos WHERE id = $1", photo_id)
         .execute(&data.db)
-        .await
-    {
-        Ok(_) => HttpResponse::NoContent().finish(),
-        Err(err) => {
-            let response = json!({
-                "status": "error",
-                "message": format!("Failed to delete photo: {:?}", err)
-            });
-            HttpResponse::InternalServerError().json(response)
-        }
+        .await?;
+
+    if result.rows_affected() == 0 {
+        return Err(ApiError::not_found("Photo"));
     }
+
+    Ok(HttpResponse::NoContent().finish())
 }
 
 // Função para fazer upload de imagens
-#[post("/upload")]
-async fn upload_image(mut payload: Multipart) -> impl Responder {
+#[utoipa::path(
+    tag = "photos",
+    request_body(content = FileUpload, content_type = "multipart/form-data"),
+    responses(
+        (status = 200, description = "Imagens salvas"),
+        (status = 400, description = "Requisição inválida", body = ErrorBody),
+        (status = 413, description = "Arquivo maior que o limite configurado", body = ErrorBody),
+    )
+)]
+#[post("/photos/upload")]
+async fn upload_image(mut payload: Multipart, data: Data<AppState>) -> Result<HttpResponse, ApiError> {
+    let storage_config = &data.config.storage;
+    tokio::fs::create_dir_all(&storage_config.static_dir).await?;
     while let Some(field) = payload.next().await {
-        match field {
-            Ok(mut field) => {
-                let filename = field
-                    .content_disposition()
-                    .get_filename()
-                    .map_or("temp".to_string(), |f| f.to_string());
-
-                let filepath = format!("./static/{}", filename);
-                println!("Saving file to: {}", filepath); // Adiciona log para depuração
+        let mut field = field?;
+        let filename = field
+            .content_disposition()
+            .get_filename()
+            .map_or("temp".to_string(), storage::sanitize_filename);
 
-                // Cria o arquivo e verifica se a criação foi bem-sucedida
-                let file_result = match tokio::fs::File::create(&filepath).await {
-                    Ok(f) => f,
-                    Err(e) => return HttpResponse::InternalServerError().json(json!({
-                        "status": "error",
-                        "message": format!("Failed to create file: {:?}", e)
-                    })),
-                };
+        let filepath = FsPath::new(&storage_config.static_dir).join(&filename);
+        log::debug!("Saving file to: {}", filepath.display());
 
-                let mut file = tokio::io::BufWriter::new(file_result);
-
-                // Lê e escreve o conteúdo do arquivo
-                while let Some(chunk) = field.next().await {
-                    let data = match chunk {
-                        Ok(d) => d,
-                        Err(e) => return HttpResponse::InternalServerError().json(json!({
-                            "status": "error",
-                            "message": format!("Failed to read chunk: {:?}", e)
-                        })),
-                    };
-
-                    if let Err(e) = file.write_all(&data).await {
-                        return HttpResponse::InternalServerError().json(json!({
-                            "status": "error",
-                            "message": format!("Failed to write data: {:?}", e)
-                        }));
-                    }
-                }
-            }
-            Err(e) => return HttpResponse::InternalServerError().json(json!({
-                "status": "error",
-                "message": format!("Failed to process file: {:?}", e)
-            })),
-        }
+        storage::save_field(&mut field, &filepath, storage_config.max_upload_bytes).await?;
     }
 
-    HttpResponse::Ok().finish()
-}
-
-
-// Função para obter todos os alunos
-#[get("/students")]
-async fn get_students(data: Data<AppState>) -> impl Responder {
-    match sqlx::query_as!(StudentModel, "SELECT * FROM students")
-        .fetch_all(&data.db)
-        .await
-    {
-        Ok(students) => {
-            let response = json!({
-                "status": "success",
-                "students": students
-            });
-            HttpResponse::Ok().json(response)
-        }
-        Err(error) => {
-            let response = json!({
-                "status": "error",
-                "message": format!("Failed to get students: {:?}", error)
-            });
-            HttpResponse::InternalServerError().json(response)
-        }
-    }
+    Ok(HttpResponse::Ok().finish())
 }
 
 // Configuração das rotas
@@ -289,7 +223,5 @@ pub fn config_photos(conf: &mut ServiceConfig) {
        .service(get_photo_by_id)
        .service(update_photo_by_id)
        .service(delete_photo_by_id)
-       .service(upload_image)
-       .service(Files::new("/static", "./static").show_files_listing())
-       .service(get_students);
+       .service(upload_image);
 }
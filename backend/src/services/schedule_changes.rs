@@ -1,133 +1,229 @@
 //backend/src/services/schedule_changes.rs
 use actix_web::{
     get, post, patch, delete,
-    web::{Data, Json, Path, Query, ServiceConfig},
-    HttpResponse, Responder,
+    web::{Data, Path, ServiceConfig},
+    HttpResponse,
 };
+use chrono::NaiveDateTime;
 use uuid::Uuid;
 use serde_json::json;
+use sqlx::PgConnection;
 
 use crate::{
+    calendar,
+    validation::ValidatedJson,
     AppState,
+    error::ApiError,
+    pagination::{Column, ListParams, ListQuery, ListSpec, SqlType, fetch_page},
     models::ScheduleChangeModel,
-    schema::{CreateScheduleChangeSchema, UpdateScheduleChangeSchema, FilterOptions},
+    repo::GroupScheduleRepo,
+    schema::{CreateScheduleChangeSchema, UpdateScheduleChangeSchema},
 };
 
+const SCHEDULE_CHANGE_COLUMNS: &str = "id, group_id, old_date, new_date, reason, created_at, schedule_id";
+
+// Com `schedule_id`, a mudança é exceção de uma sessão do horário: `old_date`
+// precisa ser o início previsto dela e a turma é a do horário. Devolve a turma
+// a gravar.
+async fn check_occurrence(
+    db: &mut PgConnection,
+    schedule_id: Option<Uuid>,
+    group_id: Option<Uuid>,
+    old_date: Option<NaiveDateTime>,
+) -> Result<Option<Uuid>, ApiError> {
+    let Some(schedule_id) = schedule_id else {
+        return Ok(group_id);
+    };
+    let schedule = GroupScheduleRepo::find(db, schedule_id)
+        .await?
+        .ok_or_else(|| ApiError::Unprocessable("Group schedule does not exist".to_string()))?;
+
+    if group_id.is_some_and(|group_id| group_id != schedule.group_id) {
+        return Err(ApiError::Validation("group_id does not match the schedule's group".to_string()));
+    }
+    match old_date {
+        Some(old_date) if calendar::is_occurrence(&schedule, old_date) => Ok(Some(schedule.group_id)),
+        Some(old_date) => Err(ApiError::Validation(format!(
+            "old_date {} is not a session of the schedule",
+            old_date
+        ))),
+        None => Err(ApiError::Validation("old_date is required with schedule_id".to_string())),
+    }
+}
+
 /// Criar mudança de treino
+#[utoipa::path(
+    tag = "groups",
+    request_body = CreateScheduleChangeSchema,
+    responses(
+        (status = 201, description = "Registro criado", body = ScheduleChangeResponse),
+        (status = 400, description = "Requisição inválida", body = ErrorBody),
+        (status = 409, description = "Conflito com um registro existente", body = ErrorBody),
+        (status = 422, description = "Referência a um registro inexistente", body = ErrorBody),
+    )
+)]
 #[post("/schedule_changes")]
 async fn create_schedule_change(
     data: Data<AppState>,
-    body: Json<CreateScheduleChangeSchema>
-) -> impl Responder {
-    let query = r#"
-        INSERT INTO schedule_changes (group_id, old_date, new_date, reason)
-        VALUES ($1, $2, $3, $4)
-        RETURNING id, group_id, old_date, new_date, reason, created_at
-    "#;
-
-    match sqlx::query_as::<_, ScheduleChangeModel>(query)
-        .bind(body.group_id)
+    body: ValidatedJson<CreateScheduleChangeSchema>
+) -> Result<HttpResponse, ApiError> {
+    let mut conn = data.db.acquire().await?;
+    let group_id = check_occurrence(&mut conn, body.schedule_id, body.group_id, body.old_date).await?;
+
+    let query = format!(
+        r#"
+        INSERT INTO schedule_changes (group_id, schedule_id, old_date, new_date, reason)
+        VALUES ($1, $2, $3, $4, $5)
+        RETURNING {}
+        "#,
+        SCHEDULE_CHANGE_COLUMNS
+    );
+
+    let change = sqlx::query_as::<_, ScheduleChangeModel>(&query)
+        .bind(group_id)
+        .bind(body.schedule_id)
         .bind(body.old_date)
         .bind(body.new_date)
         .bind(&body.reason)
-        .fetch_one(&data.db)
-        .await
-    {
-        Ok(change) => HttpResponse::Created().json(json!({ "status": "success", "schedule_change": change })),
-        Err(e) => HttpResponse::InternalServerError().json(json!({
-            "status": "error",
-            "message": format!("Failed to create schedule change: {:?}", e)
-        })),
-    }
+        .fetch_one(&mut conn)
+        .await?;
+
+    Ok(HttpResponse::Created().json(json!({ "status": "success", "schedule_change": change })))
 }
 
+const SCHEDULE_CHANGE_LIST: ListSpec = ListSpec {
+    from: "schedule_changes",
+    select: SCHEDULE_CHANGE_COLUMNS,
+    id: "id",
+    sorts: &[
+        Column::new("id", "id", SqlType::Uuid),
+        Column::new("new_date", "COALESCE(new_date, 'epoch')", SqlType::Timestamp),
+        Column::new("created_at", "COALESCE(created_at, 'epoch')", SqlType::TimestampTz),
+    ],
+    default_sort: "-created_at",
+    filters: &[
+        Column::new("group_id", "group_id", SqlType::Uuid),
+        Column::new("schedule_id", "schedule_id", SqlType::Uuid),
+    ],
+};
+
 /// Listar mudanças de treino com paginação
+#[utoipa::path(
+    tag = "groups",
+    params(
+        ListParams,
+        ("group_id" = Option<Uuid>, Query, description = "Filtra por `group_id`"),
+        ("schedule_id" = Option<Uuid>, Query, description = "Filtra por `schedule_id`"),
+    ),
+    responses(
+        (status = 200, description = "Página de resultados", body = ScheduleChangesPage),
+        (status = 400, description = "Requisição inválida", body = ErrorBody),
+    )
+)]
 #[get("/schedule_changes")]
 async fn get_schedule_changes(
-    data: Data<AppState>,
-    opts: Query<FilterOptions>
-) -> impl Responder {
-    let limit = opts.limit.unwrap_or(10);
-    let offset = (opts.page.unwrap_or(1) - 1) * limit;
-
-    let query = r#"
-        SELECT id, group_id, old_date, new_date, reason, created_at
-        FROM schedule_changes
-        ORDER BY created_at DESC
-        LIMIT $1 OFFSET $2
-    "#;
-
-    match sqlx::query_as::<_, ScheduleChangeModel>(query)
-        .bind(limit as i64)
-        .bind(offset as i64)
-        .fetch_all(&data.db)
-        .await
-    {
-        Ok(changes) => HttpResponse::Ok().json(json!({ "status": "success", "schedule_changes": changes })),
-        Err(e) => HttpResponse::InternalServerError().json(json!({
-            "status": "error",
-            "message": format!("Failed to get schedule changes: {:?}", e)
-        })),
-    }
+    query: ListQuery,
+    data: Data<AppState>
+) -> Result<HttpResponse, ApiError> {
+    let page = fetch_page::<ScheduleChangeModel>(&data.db, &SCHEDULE_CHANGE_LIST, &query).await?;
+
+    Ok(HttpResponse::Ok().json(page.to_json("schedule_changes")))
 }
 
 /// Atualizar mudança de treino
+#[utoipa::path(
+    tag = "groups",
+    request_body = UpdateScheduleChangeSchema,
+    responses(
+        (status = 200, description = "Registro atualizado", body = ScheduleChangeResponse),
+        (status = 400, description = "Requisição inválida", body = ErrorBody),
+        (status = 404, description = "Registro não encontrado", body = ErrorBody),
+        (status = 409, description = "Conflito com um registro existente", body = ErrorBody),
+        (status = 422, description = "Referência a um registro inexistente", body = ErrorBody),
+    )
+)]
 #[patch("/schedule_changes/{id}")]
 async fn update_schedule_change(
     data: Data<AppState>,
     path: Path<Uuid>,
-    body: Json<UpdateScheduleChangeSchema>
-) -> impl Responder {
+    body: ValidatedJson<UpdateScheduleChangeSchema>
+) -> Result<HttpResponse, ApiError> {
     let id = path.into_inner();
 
-    let query = r#"
+    let mut tx = data.db.begin().await?;
+    let current = sqlx::query_as::<_, ScheduleChangeModel>(&format!(
+        "SELECT {} FROM schedule_changes WHERE id = $1 FOR UPDATE",
+        SCHEDULE_CHANGE_COLUMNS
+    ))
+    .bind(id)
+    .fetch_optional(&mut tx)
+    .await?
+    .ok_or_else(|| ApiError::not_found("Schedule change"))?;
+
+    // Confere o registro como vai ficar, com os campos não informados mantidos
+    let group_id = check_occurrence(
+        &mut tx,
+        body.schedule_id.or(current.schedule_id),
+        body.group_id.or(current.group_id),
+        body.old_date.or(current.old_date),
+    )
+    .await?;
+
+    let query = format!(
+        r#"
         UPDATE schedule_changes
-        SET group_id = COALESCE($1, group_id),
-            old_date = COALESCE($2, old_date),
-            new_date = COALESCE($3, new_date),
-            reason = COALESCE($4, reason)
-        WHERE id = $5
-        RETURNING id, group_id, old_date, new_date, reason, created_at
-    "#;
-
-    match sqlx::query_as::<_, ScheduleChangeModel>(query)
-        .bind(body.group_id)
+        SET group_id = $1,
+            schedule_id = COALESCE($2, schedule_id),
+            old_date = COALESCE($3, old_date),
+            new_date = COALESCE($4, new_date),
+            reason = COALESCE($5, reason)
+        WHERE id = $6
+        RETURNING {}
+        "#,
+        SCHEDULE_CHANGE_COLUMNS
+    );
+
+    let change = sqlx::query_as::<_, ScheduleChangeModel>(&query)
+        .bind(group_id)
+        .bind(body.schedule_id)
         .bind(body.old_date)
         .bind(body.new_date)
         .bind(&body.reason)
         .bind(id)
-        .fetch_one(&data.db)
-        .await
-    {
-        Ok(change) => HttpResponse::Ok().json(json!({ "status": "success", "schedule_change": change })),
-        Err(e) => HttpResponse::InternalServerError().json(json!({
-            "status": "error",
-            "message": format!("Failed to update schedule change: {:?}", e)
-        })),
-    }
+        .fetch_one(&mut tx)
+        .await?;
+    tx.commit().await?;
+
+    Ok(HttpResponse::Ok().json(json!({ "status": "success", "schedule_change": change })))
 }
 
 /// Deletar mudança de treino
+#[utoipa::path(
+    tag = "groups",
+    responses(
+        (status = 204, description = "Registro removido"),
+        (status = 404, description = "Registro não encontrado", body = ErrorBody),
+    )
+)]
 #[delete("/schedule_changes/{id}")]
 async fn delete_schedule_change(
     data: Data<AppState>,
     path: Path<Uuid>
-) -> impl Responder {
+) -> Result<HttpResponse, ApiError> {
     let id = path.into_inner();
 
     let query = "DELETE FROM schedule_changes WHERE id = $1";
 
-    match sqlx::query(query)
+    let result = sqlx::query(query)
         .bind(id)
         .execute(&data.db)
-        .await
-    {
-        Ok(_) => HttpResponse::NoContent().finish(),
-        Err(e) => HttpResponse::InternalServerError().json(json!({
-            "status": "error",
-            "message": format!("Failed to delete schedule change: {:?}", e)
-        })),
+        .await?;
+
+    if result.rows_affected() == 0 {
+        return Err(ApiError::not_found("Schedule change"));
     }
+
+    Ok(HttpResponse::NoContent().finish())
 }
 
 /// Configuração das rotas
@@ -0,0 +1,164 @@
+// backend/src/services/student_guardians.rs
+//! Vínculos entre alunos e responsáveis: quem responde por cada aluno, quem
+//! pode buscá-lo e a ordem de contato em emergências
+use actix_web::{
+    get, put, delete,
+    web::{Data, Path, Query, ServiceConfig},
+    HttpResponse,
+};
+use serde_json::json;
+use uuid::Uuid;
+
+use crate::{
+    audit::{self, Actor, Entity},
+    validation::ValidatedJson,
+    error::ApiError,
+    repo::{StudentGuardianRepo, StudentRepo},
+    schema::{LinkGuardianSchema, StudentGuardianFilter},
+    AppState,
+};
+
+/// Responsáveis de um aluno; `?can_pick_up=true` lista quem pode buscá-lo
+#[utoipa::path(
+    tag = "guardians",
+    params(StudentGuardianFilter),
+    responses(
+        (status = 200, description = "Responsáveis do aluno, contatos de emergência primeiro", body = LinkedGuardianList),
+        (status = 400, description = "Requisição inválida", body = ErrorBody),
+        (status = 404, description = "Aluno não encontrado", body = ErrorBody),
+    )
+)]
+#[get("/students/{id}/guardians")]
+async fn get_student_guardians(
+    path: Path<Uuid>,
+    filter: Query<StudentGuardianFilter>,
+    data: Data<AppState>
+) -> Result<HttpResponse, ApiError> {
+    let student_id = path.into_inner();
+
+    StudentRepo::find(&data.db, student_id)
+        .await?
+        .ok_or_else(|| ApiError::not_found("Student"))?;
+    let guardians = StudentGuardianRepo::for_student(&data.db, student_id, &filter).await?;
+
+    Ok(HttpResponse::Ok().json(json!({
+        "status": "success",
+        "guardians": guardians
+    })))
+}
+
+/// Cria o vínculo (201) ou substitui o existente (200)
+#[utoipa::path(
+    tag = "guardians",
+    request_body = LinkGuardianSchema,
+    responses(
+        (status = 200, description = "Vínculo atualizado", body = StudentGuardianResponse),
+        (status = 201, description = "Vínculo criado", body = StudentGuardianResponse),
+        (status = 400, description = "Requisição inválida", body = ErrorBody),
+        (status = 422, description = "Aluno ou responsável inexistente", body = ErrorBody),
+    )
+)]
+#[put("/students/{id}/guardians/{guardian_id}")]
+async fn link_guardian(
+    path: Path<(Uuid, Uuid)>,
+    body: ValidatedJson<LinkGuardianSchema>,
+    data: Data<AppState>,
+    actor: Actor
+) -> Result<HttpResponse, ApiError> {
+    let (student_id, guardian_id) = path.into_inner();
+
+    let mut tx = data.db.begin().await?;
+    let before = match StudentGuardianRepo::find(&mut tx, student_id, guardian_id).await? {
+        Some(existing) => audit::snapshot(&mut tx, Entity::StudentGuardian, existing.id).await?,
+        None => None,
+    };
+
+    let link = StudentGuardianRepo::upsert(&mut tx, student_id, guardian_id, &body).await?;
+
+    let mut response = match before {
+        Some(before) => {
+            audit::updated(&mut tx, &actor, Entity::StudentGuardian, link.id, before).await?;
+            HttpResponse::Ok()
+        }
+        None => {
+            audit::created(&mut tx, &actor, Entity::StudentGuardian, link.id).await?;
+            HttpResponse::Created()
+        }
+    };
+    tx.commit().await?;
+
+    Ok(response.json(json!({
+        "status": "success",
+        "student_guardian": link
+    })))
+}
+
+/// Remove o vínculo; o responsável continua cadastrado
+#[utoipa::path(
+    tag = "guardians",
+    responses(
+        (status = 204, description = "Vínculo removido"),
+        (status = 404, description = "Vínculo não encontrado", body = ErrorBody),
+    )
+)]
+#[delete("/students/{id}/guardians/{guardian_id}")]
+async fn unlink_guardian(
+    path: Path<(Uuid, Uuid)>,
+    data: Data<AppState>,
+    actor: Actor
+) -> Result<HttpResponse, ApiError> {
+    let (student_id, guardian_id) = path.into_inner();
+
+    let mut tx = data.db.begin().await?;
+    let link = StudentGuardianRepo::find(&mut tx, student_id, guardian_id)
+        .await?
+        .ok_or_else(|| ApiError::not_found("Student guardian"))?;
+    let before = audit::snapshot(&mut tx, Entity::StudentGuardian, link.id)
+        .await?
+        .ok_or_else(|| ApiError::not_found("Student guardian"))?;
+
+    StudentGuardianRepo::delete(&mut tx, link.id).await?;
+
+    audit::deleted(&mut tx, &actor, Entity::StudentGuardian, link.id, before).await?;
+    tx.commit().await?;
+
+    Ok(HttpResponse::NoContent().finish())
+}
+
+/// Alunos pelos quais o responsável responde
+#[utoipa::path(
+    tag = "guardians",
+    responses(
+        (status = 200, description = "Alunos do responsável", body = LinkedStudentList),
+        (status = 404, description = "Responsável não encontrado", body = ErrorBody),
+    )
+)]
+#[get("/guardians/{id}/students")]
+async fn get_guardian_students(
+    path: Path<Uuid>,
+    data: Data<AppState>
+) -> Result<HttpResponse, ApiError> {
+    let guardian_id = path.into_inner();
+
+    let exists = sqlx::query_scalar::<_, bool>("SELECT EXISTS (SELECT 1 FROM guardians WHERE id = $1)")
+        .bind(guardian_id)
+        .fetch_one(&data.db)
+        .await?;
+    if !exists {
+        return Err(ApiError::not_found("Guardian"));
+    }
+    let students = StudentGuardianRepo::for_guardian(&data.db, guardian_id).await?;
+
+    Ok(HttpResponse::Ok().json(json!({
+        "status": "success",
+        "students": students
+    })))
+}
+
+// Configuração das rotas
+pub fn config_student_guardians(conf: &mut ServiceConfig) {
+    conf.service(get_student_guardians)
+        .service(link_guardian)
+        .service(unlink_guardian)
+        .service(get_guardian_students);
+}
@@ -1,222 +1,276 @@
 //backend/src/services/students.rs
 use actix_web::{
     get, post, delete, patch,
-    web::{Data, Json, Query, Path, ServiceConfig},
-    HttpResponse, Responder,
+    web::{Data, Path, Query, ServiceConfig},
+    HttpResponse,
 };
-use serde_json::json;
+use chrono::{Duration, Utc};
+use serde_json::{json, Map, Value};
+use std::future::Future;
 use crate::{
-    models::StudentModel,
-    schema::{CreateStudentSchema, UpdateStudentSchema, FilterOptions},
+    audit::{self, Actor, Entity},
+    validation::ValidatedJson,
+    error::ApiError,
+    models::StudentAttendance,
+    pagination::{ListParams, ListQuery},
+    repo::{AttendanceRepo, StudentGuardianRepo, StudentRepo},
+    schema::{CreateStudentSchema, StudentGuardianFilter, StudentProfileOptions, UpdateStudentSchema},
     AppState,
 };
 use uuid::Uuid;
 
 /// Handler para criar um estudante
+#[utoipa::path(
+    tag = "students",
+    request_body = CreateStudentSchema,
+    responses(
+        (status = 201, description = "Registro criado", body = StudentResponse),
+        (status = 400, description = "Requisição inválida", body = ErrorBody),
+        (status = 409, description = "Conflito com um registro existente", body = ErrorBody),
+        (status = 422, description = "Referência a um registro inexistente", body = ErrorBody),
+    )
+)]
 #[post("/students")]
 async fn create_student(
-    body: Json<CreateStudentSchema>,
-    data: Data<AppState>
-) -> impl Responder {
-    let query = r#"
-        INSERT INTO students (user_id, name, email, age, birth_date, shirt_size, parent_id, group_id)
-        VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
-        RETURNING id, user_id, name, email, age, birth_date, shirt_size, parent_id, group_id, students_date
-    "#;
-
-    match sqlx::query_as::<_, StudentModel>(query)
-        .bind(body.user_id)
-        .bind(&body.name)
-        .bind(&body.email)
-        .bind(body.age)
-        .bind(body.birth_date)
-        .bind(&body.shirt_size)
-        .bind(body.parent_id)       // Option<Uuid>
-        .bind(body.group_id)        // Option<Uuid>
-        .fetch_one(&data.db)
-        .await
-    {
-        Ok(student) => {
-            HttpResponse::Created().json(json!({
-                "status": "success",
-                "student": student
-            }))
-        }
-        Err(error) => {
-            HttpResponse::InternalServerError().json(json!({
-                "status": "error",
-                "message": format!("Failed to create student: {:?}", error)
-            }))
-        }
-    }
+    body: ValidatedJson<CreateStudentSchema>,
+    data: Data<AppState>,
+    actor: Actor
+) -> Result<HttpResponse, ApiError> {
+    let mut tx = data.db.begin().await?;
+    let student = StudentRepo::create(&mut tx, &body).await?;
+
+    audit::created(&mut tx, &actor, Entity::Student, student.id).await?;
+    tx.commit().await?;
+
+    Ok(HttpResponse::Created().json(json!({
+        "status": "success",
+        "student": student
+    })))
 }
 
 /// Handler para obter todos os estudantes
+#[utoipa::path(
+    tag = "students",
+    params(
+        ListParams,
+        ("group_id" = Option<Uuid>, Query, description = "Filtra por `group_id`"),
+        ("parent_id" = Option<Uuid>, Query, description = "Filtra por `parent_id`"),
+        ("household_id" = Option<Uuid>, Query, description = "Filtra por `household_id`"),
+        ("user_id" = Option<Uuid>, Query, description = "Filtra por `user_id`"),
+        ("shirt_size" = Option<String>, Query, description = "Filtra por `shirt_size`"),
+    ),
+    responses(
+        (status = 200, description = "Página de resultados", body = StudentsPage),
+        (status = 400, description = "Requisição inválida", body = ErrorBody),
+    )
+)]
 #[get("/students")]
 async fn get_all_students(
-    opts: Query<FilterOptions>,
+    query: ListQuery,
     data: Data<AppState>
-) -> impl Responder {
-    let limit = opts.limit.unwrap_or(10);
-    let offset = (opts.page.unwrap_or(1) - 1) * limit;
-
-    match sqlx::query_as!(
-        StudentModel,
-        r#"
-        SELECT id, user_id, name, email, age, birth_date, shirt_size, parent_id, group_id, students_date
-        FROM students
-        ORDER BY id
-        LIMIT $1 OFFSET $2
-        "#,
-        limit as i32,
-        offset as i32
-    )
-    .fetch_all(&data.db)
-    .await
-    {
-        Ok(students) => {
-            HttpResponse::Ok().json(json!({
-                "status": "success",
-                "students": students
-            }))
-        }
-        Err(error) => {
-            HttpResponse::InternalServerError().json(json!({
-                "status": "error",
-                "message": format!("Failed to get students: {:?}", error)
-            }))
-        }
-    }
+) -> Result<HttpResponse, ApiError> {
+    let page = StudentRepo::list(&data.db, &query).await?;
+
+    Ok(HttpResponse::Ok().json(page.to_json("students")))
 }
 
 /// Handler para obter um estudante por ID
+#[utoipa::path(
+    tag = "students",
+    responses(
+        (status = 200, description = "Registro encontrado", body = StudentResponse),
+        (status = 404, description = "Registro não encontrado", body = ErrorBody),
+    )
+)]
 #[get("/students/{id}")]
 async fn get_student_by_id(
     path: Path<Uuid>,
     data: Data<AppState>
-) -> impl Responder {
+) -> Result<HttpResponse, ApiError> {
     let student_id = path.into_inner();
 
-    match sqlx::query_as!(
-        StudentModel,
-        r#"
-        SELECT id, user_id, name, email, age, birth_date, shirt_size, parent_id, group_id, students_date
-        FROM students
-        WHERE id = $1
-        "#,
-        student_id
+    let student = StudentRepo::find(&data.db, student_id)
+        .await?
+        .ok_or_else(|| ApiError::not_found("Student"))?;
+
+    Ok(HttpResponse::Ok().json(json!({
+        "status": "success",
+        "student": student
+    })))
+}
+
+/// Seções opcionais do perfil (valores aceitos em `include`)
+const PROFILE_SECTIONS: &[&str] = &["parent", "group", "contacts", "guardians", "attendance", "warnings", "media"];
+/// Registros de presença listados em `attendance.recent`
+const RECENT_ATTENDANCES: i64 = 5;
+const DEFAULT_ATTENDANCE_DAYS: i64 = 30;
+const MAX_ATTENDANCE_DAYS: i64 = 366;
+
+// Seções pedidas em `include` (todas quando o parâmetro não vem)
+fn profile_sections(include: Option<&str>) -> Result<Vec<&'static str>, ApiError> {
+    let Some(include) = include else {
+        return Ok(PROFILE_SECTIONS.to_vec());
+    };
+
+    let mut sections = Vec::new();
+    for name in include.split(',').map(str::trim).filter(|name| !name.is_empty()) {
+        let section = PROFILE_SECTIONS.iter().find(|section| **section == name).ok_or_else(|| {
+            ApiError::BadRequest(format!("Unknown include '{}'; allowed: {}", name, PROFILE_SECTIONS.join(", ")))
+        })?;
+        sections.push(*section);
+    }
+    Ok(sections)
+}
+
+// Busca a seção só se ela foi pedida
+async fn section<F, T>(sections: &[&str], name: &str, fetch: F) -> Result<Option<Value>, ApiError>
+where
+    F: Future<Output = Result<T, sqlx::Error>>,
+    T: serde::Serialize,
+{
+    if !sections.contains(&name) {
+        return Ok(None);
+    }
+    Ok(Some(json!(fetch.await?)))
+}
+
+/// Perfil do aluno numa chamada só: o aluno e, conforme `include`, o pai ou a
+/// mãe, a turma, os contatos, os responsáveis, o resumo de presenças, as advertências e a
+/// contagem de arquivos
+#[utoipa::path(
+    tag = "students",
+    params(StudentProfileOptions),
+    responses(
+        (status = 200, description = "Perfil do aluno; só as seções pedidas aparecem", body = StudentProfileResponse),
+        (status = 400, description = "Requisição inválida", body = ErrorBody),
+        (status = 404, description = "Registro não encontrado", body = ErrorBody),
     )
-    .fetch_one(&data.db)
-    .await
-    {
-        Ok(student) => {
-            HttpResponse::Ok().json(json!({
-                "status": "success",
-                "student": student
-            }))
-        }
-        Err(error) => {
-            HttpResponse::InternalServerError().json(json!({
-                "status": "error",
-                "message": format!("Failed to get student: {:?}", error)
-            }))
+)]
+#[get("/students/{id}/profile")]
+async fn get_student_profile(
+    path: Path<Uuid>,
+    opts: Query<StudentProfileOptions>,
+    data: Data<AppState>
+) -> Result<HttpResponse, ApiError> {
+    let sections = profile_sections(opts.include.as_deref())?;
+    let days = opts.attendance_days.unwrap_or(DEFAULT_ATTENDANCE_DAYS);
+    if !(1..=MAX_ATTENDANCE_DAYS).contains(&days) {
+        return Err(ApiError::BadRequest(format!(
+            "attendance_days must be between 1 and {}",
+            MAX_ATTENDANCE_DAYS
+        )));
+    }
+
+    let student = StudentRepo::find(&data.db, path.into_inner())
+        .await?
+        .ok_or_else(|| ApiError::not_found("Student"))?;
+
+    let db = &data.db;
+    let since = Utc::now().date_naive() - Duration::days(days - 1);
+    let all_guardians = StudentGuardianFilter::default();
+    let (parent, group, contacts, guardians, attendance, warnings, media) = tokio::try_join!(
+        section(&sections, "parent", StudentRepo::parent(db, &student)),
+        section(&sections, "group", StudentRepo::group(db, &student)),
+        section(&sections, "contacts", async {
+            let (addresses, phones) = tokio::try_join!(
+                StudentRepo::addresses(db, &student),
+                StudentRepo::phones(db, &student),
+            )?;
+            Ok::<_, sqlx::Error>(json!({"addresses": addresses, "phones": phones}))
+        }),
+        section(&sections, "guardians", StudentGuardianRepo::for_student(db, student.id, &all_guardians)),
+        section(&sections, "attendance", async {
+            let (summary, recent) = tokio::try_join!(
+                AttendanceRepo::summary(db, student.id, since),
+                AttendanceRepo::recent(db, student.id, RECENT_ATTENDANCES),
+            )?;
+            Ok::<_, sqlx::Error>(StudentAttendance { summary, recent })
+        }),
+        section(&sections, "warnings", StudentRepo::warning_summary(db, student.id)),
+        section(&sections, "media", StudentRepo::media_counts(db, student.id)),
+    )?;
+
+    let mut profile = Map::new();
+    profile.insert("student".to_string(), json!(student));
+    let fetched = [parent, group, contacts, guardians, attendance, warnings, media];
+    for (name, value) in PROFILE_SECTIONS.iter().zip(fetched) {
+        if let Some(value) = value {
+            profile.insert(name.to_string(), value);
         }
     }
+
+    Ok(HttpResponse::Ok().json(json!({
+        "status": "success",
+        "profile": profile
+    })))
 }
 
 /// Handler para atualizar um estudante por ID
+#[utoipa::path(
+    tag = "students",
+    request_body = UpdateStudentSchema,
+    responses(
+        (status = 200, description = "Registro atualizado", body = StudentResponse),
+        (status = 400, description = "Requisição inválida", body = ErrorBody),
+        (status = 404, description = "Registro não encontrado", body = ErrorBody),
+        (status = 409, description = "Conflito com um registro existente", body = ErrorBody),
+        (status = 422, description = "Referência a um registro inexistente", body = ErrorBody),
+    )
+)]
 #[patch("/students/{id}")]
 async fn update_student_by_id(
     path: Path<Uuid>,
-    body: Json<UpdateStudentSchema>,
-    data: Data<AppState>
-) -> impl Responder {
+    body: ValidatedJson<UpdateStudentSchema>,
+    data: Data<AppState>,
+    actor: Actor
+) -> Result<HttpResponse, ApiError> {
     let student_id = path.into_inner();
 
-    // Verifica se estudante existe
-    match sqlx::query_as!(
-        StudentModel,
-        r#"
-        SELECT id, user_id, name, email, age, birth_date, shirt_size, parent_id, group_id, students_date
-        FROM students
-        WHERE id = $1
-        "#,
-        student_id
-    )
-    .fetch_one(&data.db)
-    .await
-    {
-        Ok(_) => {
-            let update_result = sqlx::query_as!(
-                StudentModel,
-                r#"
-                UPDATE students SET
-                    user_id = COALESCE($1, user_id),
-                    name = COALESCE($2, name),
-                    email = COALESCE($3, email),
-                    age = COALESCE($4, age),
-                    birth_date = COALESCE($5, birth_date),
-                    shirt_size = COALESCE($6, shirt_size),
-                    parent_id = COALESCE($7, parent_id),
-                    group_id = COALESCE($8, group_id)
-                WHERE id = $9
-                RETURNING id, user_id, name, email, age, birth_date, shirt_size, parent_id, group_id, students_date
-                "#,
-                body.user_id,
-                body.name.as_ref(),
-                body.email.as_ref(),
-                body.age,
-                body.birth_date,
-                body.shirt_size.as_ref(),
-                body.parent_id,
-                body.group_id,
-                student_id
-            )
-            .fetch_one(&data.db)
-            .await;
-
-            match update_result {
-                Ok(updated_student) => {
-                    HttpResponse::Ok().json(json!({
-                        "status": "success",
-                        "student": updated_student
-                    }))
-                }
-                Err(error) => {
-                    HttpResponse::InternalServerError().json(json!({
-                        "status": "error",
-                        "message": format!("Failed to update student: {:?}", error)
-                    }))
-                }
-            }
-        }
-        Err(error) => {
-            HttpResponse::NotFound().json(json!({
-                "status": "error",
-                "message": format!("Student not found: {:?}", error)
-            }))
-        }
-    }
+    let mut tx = data.db.begin().await?;
+    let before = audit::snapshot(&mut tx, Entity::Student, student_id)
+        .await?
+        .ok_or_else(|| ApiError::not_found("Student"))?;
+
+    let updated_student = StudentRepo::update(&mut tx, student_id, &body)
+        .await?
+        .ok_or_else(|| ApiError::not_found("Student"))?;
+
+    audit::updated(&mut tx, &actor, Entity::Student, student_id, before).await?;
+    tx.commit().await?;
+
+    Ok(HttpResponse::Ok().json(json!({
+        "status": "success",
+        "student": updated_student
+    })))
 }
 
 /// Handler para deletar um estudante por ID
+#[utoipa::path(
+    tag = "students",
+    responses(
+        (status = 204, description = "Registro removido"),
+        (status = 404, description = "Registro não encontrado", body = ErrorBody),
+    )
+)]
 #[delete("/students/{id}")]
 async fn delete_student_by_id(
     path: Path<Uuid>,
-    data: Data<AppState>
-) -> impl Responder {
+    data: Data<AppState>,
+    actor: Actor
+) -> Result<HttpResponse, ApiError> {
     let student_id = path.into_inner();
 
-    match sqlx::query!("DELETE FROM students WHERE id = $1", student_id)
-        .execute(&data.db)
-        .await
-    {
-        Ok(_) => HttpResponse::NoContent().finish(),
-        Err(error) => HttpResponse::InternalServerError().json(json!({
-            "status": "error",
-            "message": format!("Failed to delete student: {:?}", error)
-        })),
-    }
+    let mut tx = data.db.begin().await?;
+    let before = audit::snapshot(&mut tx, Entity::Student, student_id)
+        .await?
+        .ok_or_else(|| ApiError::not_found("Student"))?;
+
+    StudentRepo::delete(&mut tx, student_id).await?;
+
+    audit::deleted(&mut tx, &actor, Entity::Student, student_id, before).await?;
+    tx.commit().await?;
+
+    Ok(HttpResponse::NoContent().finish())
 }
 
 // Configuração das rotas
@@ -224,6 +278,7 @@ pub fn config_students(conf: &mut ServiceConfig) {
     conf.service(create_student)
         .service(get_all_students)
         .service(get_student_by_id)
+        .service(get_student_profile)
         .service(update_student_by_id)
         .service(delete_student_by_id);
 }
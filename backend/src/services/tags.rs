@@ -1,98 +1,167 @@
 use actix_web::{
-    get, post, delete, patch, web::{Data, Json, Path, ServiceConfig}, HttpResponse, Responder
+    get, post, delete, patch, web::{Data, Path, ServiceConfig}, HttpResponse
 };
+use sqlx::PgExecutor;
 use serde_json::json;
 use uuid::Uuid;
 use crate::{
-    models::TagModel,
-    schema::{CreateTagSchema, UpdateTagSchema, FilterOptions},
+    validation::ValidatedJson,
+    error::ApiError,
+    pagination::{Column, ListParams, ListQuery, ListSpec, SqlType, fetch_page},
+    models::{TagModel, TagUsageModel, VideoModel},
+    schema::{CreateTagSchema, UpdateTagSchema, MergeTagSchema},
     AppState
 };
 
+// Listas ligadas a uma tag só existem se a tag existe
+async fn ensure_tag<'e>(db: impl PgExecutor<'e>, tag_id: Uuid) -> Result<(), ApiError> {
+    let exists = sqlx::query_scalar::<_, bool>("SELECT EXISTS (SELECT 1 FROM tags WHERE id = $1)")
+        .bind(tag_id)
+        .fetch_one(db)
+        .await?;
+    if exists {
+        Ok(())
+    } else {
+        Err(ApiError::not_found("Tag"))
+    }
+}
+
 /// Função para criar um nova tag
+#[utoipa::path(
+    tag = "tags",
+    request_body = CreateTagSchema,
+    responses(
+        (status = 200, description = "Registro criado", body = TagResponse),
+        (status = 400, description = "Requisição inválida", body = ErrorBody),
+        (status = 409, description = "Conflito com um registro existente", body = ErrorBody),
+        (status = 422, description = "Referência a um registro inexistente", body = ErrorBody),
+    )
+)]
 #[post("/tags")]
 pub async fn create_tag(
-    body: Json<CreateTagSchema>,
+    body: ValidatedJson<CreateTagSchema>,
     data: Data<AppState>,
-) -> impl Responder {
+) -> Result<HttpResponse, ApiError> {
     let query = r#"
         INSERT INTO tags (name)
         VALUES ($1)
         RETURNING id, name
     "#;
 
-    match sqlx::query_as::<_, TagModel>(query)
-        .bind(&body.name)
+    let tag = sqlx::query_as::<_, TagModel>(query)
+        .bind(body.name.trim())
         .fetch_one(&data.db)
-        .await
-    {
-        Ok(tag) => {
-            let response = json!({
-                "status": "sucesso",
-                "tag": {
-                    "id": tag.id,
-                    "name": tag.name,
-                }
-            });
-            HttpResponse::Ok().json(response)
-        }
-        Err(error) => {
-            let response = json!({
-                "status": "erro",
-                "mensagem": format!("Falha ao criar name: {:?}", error),
-            });
-            HttpResponse::InternalServerError().json(response)
-        }
-    }
+        .await?;
+
+    Ok(HttpResponse::Ok().json(json!({
+        "status": "success",
+        "tag": tag
+    })))
 }
 
+const TAG_LIST: ListSpec = ListSpec {
+    from: "tags",
+    select: "id, name",
+    id: "id",
+    sorts: &[
+        Column::new("id", "id", SqlType::Uuid),
+        Column::new("name", "name", SqlType::Text),
+    ],
+    default_sort: "id",
+    filters: &[Column::new("name", "name", SqlType::Text)],
+};
+
 /// Rota para obter todas as tags
+#[utoipa::path(
+    tag = "tags",
+    params(
+        ListParams,
+        ("name" = Option<String>, Query, description = "Filtra por `name`"),
+    ),
+    responses(
+        (status = 200, description = "Página de resultados", body = TagsPage),
+        (status = 400, description = "Requisição inválida", body = ErrorBody),
+    )
+)]
 #[get("/tags")]
-async fn get_all_tags(data: Data<AppState>) -> impl Responder {
-    let query = "SELECT * FROM tags ORDER BY id";
-
-    match sqlx::query_as::<_, TagModel>(query).fetch_all(&data.db).await {
-        Ok(tags) => HttpResponse::Ok().json(json!({
-            "status": "success",
-            "tags": tags
-        })),
-        Err(error) => HttpResponse::InternalServerError().json(json!({
-            "status": "error",
-            "message": format!("Failed to get tags: {:?}", error)
-        })),
-    }
+async fn get_all_tags(
+    query: ListQuery,
+    data: Data<AppState>
+) -> Result<HttpResponse, ApiError> {
+    let page = fetch_page::<TagModel>(&data.db, &TAG_LIST, &query).await?;
+
+    Ok(HttpResponse::Ok().json(page.to_json("tags")))
+}
+
+/// Rota para obter as tags com a quantidade de vídeos em que são usadas
+#[utoipa::path(
+    tag = "tags",
+    responses(
+        (status = 200, description = "Tags com a contagem de vídeos", body = TagUsageList),
+    )
+)]
+#[get("/tags/usage")]
+async fn get_tags_usage(data: Data<AppState>) -> Result<HttpResponse, ApiError> {
+    let query = r#"
+        SELECT t.id, t.name, COUNT(vt.video_id) AS video_count
+        FROM tags t
+        LEFT JOIN video_tags vt ON vt.tag_id = t.id
+        GROUP BY t.id, t.name
+        ORDER BY video_count DESC, t.name
+    "#;
+
+    let tags = sqlx::query_as::<_, TagUsageModel>(query).fetch_all(&data.db).await?;
+
+    Ok(HttpResponse::Ok().json(json!({
+        "status": "success",
+        "tags": tags
+    })))
 }
 
 /// Rota para obter uma tag por ID
+#[utoipa::path(
+    tag = "tags",
+    responses(
+        (status = 200, description = "Registro encontrado", body = TagResponse),
+        (status = 404, description = "Registro não encontrado", body = ErrorBody),
+    )
+)]
 #[get("/tags/{id}")]
 async fn get_tag_by_id(
     path: Path<Uuid>,
     data: Data<AppState>
-) -> impl Responder {
+) -> Result<HttpResponse, ApiError> {
     let tag_id = path.into_inner();
 
-    match sqlx::query_as!(TagModel, "SELECT * FROM tags WHERE id = $1", tag_id)
-        .fetch_one(&data.db)
-        .await
-    {
-        Ok(tag) => HttpResponse::Ok().json(json!({
-            "status": "success",
-            "tag": tag
-        })),
-        Err(error) => HttpResponse::InternalServerError().json(json!({
-            "status": "error",
-            "message": format!("Failed to get tag: {:?}", error)
-        })),
-    }
+    let tag = sqlx::query_as!(TagModel, "SELECT * FROM tags WHERE id = $1", tag_id)
+        .fetch_optional(&data.db)
+        .await?
+        .ok_or_else(|| ApiError::not_found("Tag"))?;
+
+    Ok(HttpResponse::Ok().json(json!({
+        "status": "success",
+        "tag": tag
+    })))
 }
 
 /// Rota para atualizar uma tag por ID
+#[utoipa::path(
+    tag = "tags",
+    request_body = UpdateTagSchema,
+    responses(
+        (status = 200, description = "Registro atualizado", body = TagResponse),
+        (status = 400, description = "Requisição inválida", body = ErrorBody),
+        (status = 404, description = "Registro não encontrado", body = ErrorBody),
+        (status = 409, description = "Conflito com um registro existente", body = ErrorBody),
+        (status = 422, description = "Referência a um registro inexistente", body = ErrorBody),
+    )
+)]
 #[patch("/tags/{id}")]
 async fn update_tag_by_id(
     path: Path<Uuid>,
-    body: Json<UpdateTagSchema>,
+    body: ValidatedJson<UpdateTagSchema>,
     data: Data<AppState>
-) -> impl Responder {
+) -> Result<HttpResponse, ApiError> {
     let tag_id = path.into_inner();
 
     let query = r#"
@@ -100,49 +169,152 @@ async fn update_tag_by_id(
         WHERE id = $2 RETURNING *
     "#;
 
-    match sqlx::query_as::<_, TagModel>(query)
-        .bind(&body.name)
+    let updated_tag = sqlx::query_as::<_, TagModel>(query)
+        .bind(body.name.as_deref().map(str::trim))
         .bind(tag_id)
-        .fetch_one(&data.db)
-        .await
-    {
-        Ok(updated_tag) => HttpResponse::Ok().json(json!({
-            "status": "success",
-            "tag": updated_tag
-        })),
-        Err(error) => HttpResponse::InternalServerError().json(json!({
-            "status": "error",
-            "message": format!("Failed to update tag: {:?}", error)
-        })),
+        .fetch_optional(&data.db)
+        .await?
+        .ok_or_else(|| ApiError::not_found("Tag"))?;
+
+    Ok(HttpResponse::Ok().json(json!({
+        "status": "success",
+        "tag": updated_tag
+    })))
+}
+
+/// Rota para listar os vídeos marcados com uma tag
+#[utoipa::path(
+    tag = "tags",
+    responses(
+        (status = 200, description = "Vídeos marcados com a tag", body = VideoList),
+        (status = 404, description = "Registro não encontrado", body = ErrorBody),
+    )
+)]
+#[get("/tags/{id}/videos")]
+async fn get_tag_videos(
+    path: Path<Uuid>,
+    data: Data<AppState>
+) -> Result<HttpResponse, ApiError> {
+    let tag_id = path.into_inner();
+    ensure_tag(&data.db, tag_id).await?;
+
+    let query = r#"
+        SELECT v.id, v.title, v.description, v.thumbnail_path, v.slug, v.published_at,
+               v.is_published, v.num_likes, v.num_views, v.author_id, v.video_date
+        FROM videos v
+        JOIN video_tags vt ON vt.video_id = v.id
+        WHERE vt.tag_id = $1
+        ORDER BY v.video_date DESC
+    "#;
+
+    let videos = sqlx::query_as::<_, VideoModel>(query)
+        .bind(tag_id)
+        .fetch_all(&data.db)
+        .await?;
+
+    Ok(HttpResponse::Ok().json(json!({
+        "status": "success",
+        "videos": videos
+    })))
+}
+
+/// Rota para juntar uma tag em outra: os vídeos passam para a tag de destino e a de origem é removida
+#[utoipa::path(
+    tag = "tags",
+    request_body = MergeTagSchema,
+    responses(
+        (status = 200, description = "Tag de destino após a fusão", body = TagResponse),
+        (status = 400, description = "Requisição inválida", body = ErrorBody),
+        (status = 404, description = "Registro não encontrado", body = ErrorBody),
+        (status = 409, description = "Conflito com um registro existente", body = ErrorBody),
+        (status = 422, description = "Referência a um registro inexistente", body = ErrorBody),
+    )
+)]
+#[post("/tags/{id}/merge")]
+async fn merge_tag(
+    path: Path<Uuid>,
+    body: ValidatedJson<MergeTagSchema>,
+    data: Data<AppState>
+) -> Result<HttpResponse, ApiError> {
+    let source_id = path.into_inner();
+    let target_id = body.target_id;
+
+    if source_id == target_id {
+        return Err(ApiError::Validation("target_id must be a different tag".to_string()));
     }
+
+    let mut tx = data.db.begin().await?;
+
+    let target = sqlx::query_as::<_, TagModel>("SELECT * FROM tags WHERE id = $1")
+        .bind(target_id)
+        .fetch_optional(&mut tx)
+        .await?
+        .ok_or_else(|| ApiError::not_found("Target tag"))?;
+
+    let move_query = r#"
+        INSERT INTO video_tags (video_id, tag_id)
+        SELECT video_id, $2 FROM video_tags WHERE tag_id = $1
+        ON CONFLICT DO NOTHING
+    "#;
+
+    sqlx::query(move_query)
+        .bind(source_id)
+        .bind(target_id)
+        .execute(&mut tx)
+        .await?;
+
+    // video_tags da origem caem em cascata
+    let deleted = sqlx::query("DELETE FROM tags WHERE id = $1")
+        .bind(source_id)
+        .execute(&mut tx)
+        .await?;
+
+    if deleted.rows_affected() == 0 {
+        return Err(ApiError::not_found("Source tag"));
+    }
+
+    tx.commit().await?;
+
+    Ok(HttpResponse::Ok().json(json!({
+        "status": "success",
+        "tag": target
+    })))
 }
 
 /// Rota para deletar uma tag por ID
+#[utoipa::path(
+    tag = "tags",
+    responses(
+        (status = 204, description = "Registro removido"),
+        (status = 404, description = "Registro não encontrado", body = ErrorBody),
+    )
+)]
 #[delete("/tags/{id}")]
 async fn delete_tag_by_id(
     path: Path<Uuid>,
     data: Data<AppState>
-) -> impl Responder {
+) -> Result<HttpResponse, ApiError> {
     let tag_id = path.into_inner();
 
-    match sqlx::query!("DELETE FROM tags WHERE id = $1", tag_id)
+    let result = sqlx::query!("DELETE FROM tags WHERE id = $1", tag_id)
         .execute(&data.db)
-        .await
-    {
-        Ok(_) => HttpResponse::NoContent().finish(),
-        Err(error) => HttpResponse::InternalServerError().json(json!({
-            "status": "error",
-            "message": format!("Failed to delete tag: {:?}", error)
-        })),
+        .await?;
+
+    if result.rows_affected() == 0 {
+        return Err(ApiError::not_found("Tag"));
     }
+
+    Ok(HttpResponse::NoContent().finish())
 }
 
 /// Configuração das rotas de tag
 pub fn config_tags(conf: &mut ServiceConfig) {
     conf.service(create_tag)
        .service(get_all_tags)
+       .service(get_tags_usage)
        .service(get_tag_by_id)
+       .service(get_tag_videos)
+       .service(merge_tag)
        .service(update_tag_by_id)
        .service(delete_tag_by_id);
 }
-
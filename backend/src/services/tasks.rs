@@ -1,190 +1,174 @@
 use actix_web::{
-    get, post, delete, patch, web::{Data, Json, scope, Query, Path, ServiceConfig}, HttpResponse, Responder
+    get, post, delete, patch, web::{Data, Path, ServiceConfig}, HttpResponse
 };
 use serde_json::json;
 use crate::{
+    validation::ValidatedJson,
+    error::ApiError,
+    pagination::{Column, ListParams, ListQuery, ListSpec, SqlType, fetch_page},
     models::TaskModel,
-    schema::{CreateTaskSchema, UpdateTaskSchema, FilterOptions},
+    schema::{CreateTaskSchema, UpdateTaskSchema},
     AppState
 };
-use sqlx::PgPool;
 use uuid::Uuid;
 
+#[utoipa::path(
+    tag = "tasks",
+    request_body = CreateTaskSchema,
+    responses(
+        (status = 200, description = "Registro criado", body = TaskResponse),
+        (status = 400, description = "Requisição inválida", body = ErrorBody),
+        (status = 409, description = "Conflito com um registro existente", body = ErrorBody),
+        (status = 422, description = "Referência a um registro inexistente", body = ErrorBody),
+    )
+)]
 #[post("/tasks")]
 async fn create_task(
-    body: Json<CreateTaskSchema>,
+    body: ValidatedJson<CreateTaskSchema>,
     data: Data<AppState>
-) -> impl Responder {
+) -> Result<HttpResponse, ApiError> {
     let query = r#"
         INSERT INTO tasks (title, content)
         VALUES ($1, $2)
         RETURNING id, title, content, created_at
     "#;
 
-    match sqlx::query_as::<_, TaskModel>(query)
+    let task = sqlx::query_as::<_, TaskModel>(query)
         .bind(&body.title)
         .bind(&body.content)
         .fetch_one(&data.db)
-        .await
-    {
-        Ok(task) => {
-            let response = json!({
-                "status": "success",
-                "task": {
-                    "id": task.id,
-                    "title": task.title,
-                    "content": task.content,
-                    "created_at": task.created_at
-                }
-            });
-            HttpResponse::Ok().json(response)
-        }
-        Err(error) => {
-            let response = json!({
-                "status": "error",
-                "message": format!("Failed to create task: {:?}", error)
-            });
-            HttpResponse::InternalServerError().json(response)
+        .await?;
+
+    Ok(HttpResponse::Ok().json(json!({
+        "status": "success",
+        "task": {
+            "id": task.id,
+            "title": task.title,
+            "content": task.content,
+            "created_at": task.created_at
         }
-    }
+    })))
 }
 
+const TASK_LIST: ListSpec = ListSpec {
+    from: "tasks",
+    select: "id, title, content, created_at",
+    id: "id",
+    sorts: &[
+        Column::new("id", "id", SqlType::Uuid),
+        Column::new("title", "title", SqlType::Text),
+        Column::new("created_at", "COALESCE(created_at, 'epoch')", SqlType::TimestampTz),
+    ],
+    default_sort: "id",
+    filters: &[],
+};
+
+#[utoipa::path(
+    tag = "tasks",
+    params(ListParams),
+    responses(
+        (status = 200, description = "Página de resultados", body = TasksPage),
+        (status = 400, description = "Requisição inválida", body = ErrorBody),
+    )
+)]
 #[get("/tasks")]
 pub async fn get_all_tasks(
-    opts: Query<FilterOptions>,
+    query: ListQuery,
     data: Data<AppState>
-) -> impl Responder {
-    let limit = opts.limit.unwrap_or(10);
-    let offset = (opts.page.unwrap_or(1) - 1) * limit;
+) -> Result<HttpResponse, ApiError> {
+    let page = fetch_page::<TaskModel>(&data.db, &TASK_LIST, &query).await?;
 
-    match sqlx::query_as!(
-        TaskModel,
-        "SELECT * FROM tasks ORDER BY id LIMIT $1 OFFSET $2",
-        limit as i32,
-        offset as i32
-    )
-    .fetch_all(&data.db)
-    .await
-    {
-        Ok(tasks) => {
-            let response = json!({
-                "status": "success",
-                "tasks": tasks
-            });
-            HttpResponse::Ok().json(response)
-        }
-        Err(error) => {
-            let response = json!({
-                "status": "error",
-                "message": format!("Failed to get tasks: {:?}", error)
-            });
-            HttpResponse::InternalServerError().json(response)
-        }
-    }
+    Ok(HttpResponse::Ok().json(page.to_json("tasks")))
 }
 
+#[utoipa::path(
+    tag = "tasks",
+    responses(
+        (status = 200, description = "Registro encontrado", body = TaskResponse),
+        (status = 404, description = "Registro não encontrado", body = ErrorBody),
+    )
+)]
 #[get("/tasks/{id}")]
 async fn get_task_by_id(
     path: Path<Uuid>,
     data: Data<AppState>
-) -> impl Responder {
+) -> Result<HttpResponse, ApiError> {
     let task_id = path.into_inner();
 
-    match sqlx::query_as!(
+    let task = sqlx::query_as!(
         TaskModel,
         "SELECT * FROM tasks WHERE id = $1",
         task_id
     )
-    .fetch_one(&data.db)
-    .await
-    {
-        Ok(task) => {
-            let response = json!({
-                "status": "success",
-                "task": task
-            });
-            HttpResponse::Ok().json(response)
-        }
-        Err(error) => {
-            let response = json!({
-                "status": "error",
-                "message": format!("Failed to get task: {:?}", error)
-            });
-            HttpResponse::InternalServerError().json(response)
-        }
-    }
+    .fetch_optional(&data.db)
+    .await?
+    .ok_or_else(|| ApiError::not_found("Task"))?;
+
+    Ok(HttpResponse::Ok().json(json!({
+        "status": "success",
+        "task": task
+    })))
 }
 
+#[utoipa::path(
+    tag = "tasks",
+    request_body = UpdateTaskSchema,
+    responses(
+        (status = 200, description = "Registro atualizado", body = TaskResponse),
+        (status = 400, description = "Requisição inválida", body = ErrorBody),
+        (status = 404, description = "Registro não encontrado", body = ErrorBody),
+        (status = 409, description = "Conflito com um registro existente", body = ErrorBody),
+        (status = 422, description = "Referência a um registro inexistente", body = ErrorBody),
+    )
+)]
 #[patch("/tasks/{id}")]
 async fn update_task_by_id(
     path: Path<Uuid>,
-    body: Json<UpdateTaskSchema>,
+    body: ValidatedJson<UpdateTaskSchema>,
     data: Data<AppState>
-) -> impl Responder {
+) -> Result<HttpResponse, ApiError> {
     let task_id = path.into_inner();
 
-    match sqlx::query_as!(TaskModel, "SELECT * FROM tasks WHERE id = $1", task_id)
-        .fetch_one(&data.db)
-        .await
-    {
-        Ok(task) => {
-            let update_result = sqlx::query_as!(
-                TaskModel,
-                "UPDATE tasks SET title = COALESCE($1, title), content = COALESCE($2, content) WHERE id = $3 RETURNING *",
-                body.title.as_ref(),
-                body.content.as_ref(),
-                task_id
-            )
-            .fetch_one(&data.db)
-            .await;
-
-            match update_result {
-                Ok(updated_task) => {
-                    let response = json!({
-                        "status": "success",
-                        "task": updated_task
-                    });
-                    HttpResponse::Ok().json(response)
-                }
-                Err(update_error) => {
-                    let response = json!({
-                        "status": "error",
-                        "message": format!("Failed to update task: {:?}", update_error)
-                    });
-                    HttpResponse::InternalServerError().json(response)
-                }
-            }
-        }
-        Err(fetch_error) => {
-            let response = json!({
-                "status": "error",
-                "message": format!("Task not found: {:?}", fetch_error)
-            });
-            HttpResponse::NotFound().json(response)
-        }
-    }
+    let updated_task = sqlx::query_as!(
+        TaskModel,
+        "UPDATE tasks SET title = COALESCE($1, title), content = COALESCE($2, content) WHERE id = $3 RETURNING *",
+        body.title.as_ref(),
+        body.content.as_ref(),
+        task_id
+    )
+    .fetch_optional(&data.db)
+    .await?
+    .ok_or_else(|| ApiError::not_found("Task"))?;
+
+    Ok(HttpResponse::Ok().json(json!({
+        "status": "success",
+        "task": updated_task
+    })))
 }
 
+#[utoipa::path(
+    tag = "tasks",
+    responses(
+        (status = 204, description = "Registro removido"),
+        (status = 404, description = "Registro não encontrado", body = ErrorBody),
+    )
+)]
 #[delete("/tasks/{id}")]
 async fn delete_task_by_id(
     path: Path<Uuid>,
     data: Data<AppState>
-) -> impl Responder {
+) -> Result<HttpResponse, ApiError> {
     let task_id = path.into_inner();
 
-    match sqlx::query!("DELETE FROM tasks WHERE id = $1", task_id)
+    let result = sqlx::query!("DELETE FROM tasks WHERE id = $1", task_id)
         .execute(&data.db)
-        .await
-    {
-        Ok(_) => HttpResponse::NoContent().finish(),
-        Err(err) => {
-            let response = json!({
-                "status": "error",
-                "message": format!("Failed to delete task: {:?}", err)
-            });
-            HttpResponse::InternalServerError().json(response)
-        }
+        .await?;
+
+    if result.rows_affected() == 0 {
+        return Err(ApiError::not_found("Task"));
     }
+
+    Ok(HttpResponse::NoContent().finish())
 }
 
 // Configuração das rotas para tarefas normais
@@ -194,4 +178,4 @@ pub fn config_tasks(conf: &mut ServiceConfig) {
        .service(get_task_by_id)
        .service(update_task_by_id)
        .service(delete_task_by_id);
-}
\ No newline at end of file
+}
@@ -1,107 +1,117 @@
 //backend/src/services/users.rs
 use actix_web::{
     get, post, delete, patch,
-    web::{Data, Json, Query, Path, ServiceConfig},
-    HttpResponse, Responder,
+    web::{Data, Path, ServiceConfig},
+    HttpResponse,
 };
 use serde_json::json;
 use crate::{
+    audit::{self, Actor, Entity},
+    validation::ValidatedJson,
+    error::ApiError,
+    pagination::{Column, ListParams, ListQuery, ListSpec, SqlType, fetch_page},
     models::UserModel,
-    schema::{CreateUserSchema, UpdateUserSchema, FilterOptions},
+    schema::{CreateUserSchema, UpdateUserSchema},
     AppState,
 };
 use uuid::Uuid;
 
 /// Endpoint para criar um novo usuário
+#[utoipa::path(
+    tag = "users",
+    request_body = CreateUserSchema,
+    responses(
+        (status = 201, description = "Registro criado", body = UserResponse),
+        (status = 400, description = "Requisição inválida", body = ErrorBody),
+        (status = 409, description = "Conflito com um registro existente", body = ErrorBody),
+        (status = 422, description = "Referência a um registro inexistente", body = ErrorBody),
+    )
+)]
 #[post("/users")]
 async fn create_user(
-    body: Json<CreateUserSchema>,
+    body: ValidatedJson<CreateUserSchema>,
     data: Data<AppState>,
-) -> impl Responder {
+    actor: Actor,
+) -> Result<HttpResponse, ApiError> {
     let query = r#"
         INSERT INTO users (username, password_hash, role)
         VALUES ($1, $2, $3)
         RETURNING id, username, password_hash, role, users_date
     "#;
 
-    match sqlx::query_as::<_, UserModel>(query)
+    let mut tx = data.db.begin().await?;
+    let user = sqlx::query_as::<_, UserModel>(query)
         .bind(&body.username)
         .bind(&body.password_hash)
         .bind(&body.role)
-        .fetch_one(&data.db)
-        .await
-    {
-        Ok(user) => {
-            let response = json!({
-                "status": "success",
-                "user": {
-                    "id": user.id,
-                    "username": user.username,
-                    "role": user.role,
-                    "users_date": user.users_date
-                }
-            });
-            HttpResponse::Created().json(response)
-        }
-        Err(error) => {
-            let response = json!({
-                "status": "error",
-                "message": format!("Failed to create user: {:?}", error)
-            });
-            HttpResponse::InternalServerError().json(response)
+        .fetch_one(&mut tx)
+        .await?;
+
+    audit::created(&mut tx, &actor, Entity::User, user.id).await?;
+    tx.commit().await?;
+
+    Ok(HttpResponse::Created().json(json!({
+        "status": "success",
+        "user": {
+            "id": user.id,
+            "username": user.username,
+            "role": user.role,
+            "users_date": user.users_date
         }
-    }
+    })))
 }
 
+const USER_LIST: ListSpec = ListSpec {
+    from: "users",
+    select: "id, username, password_hash, role, users_date",
+    id: "id",
+    sorts: &[
+        Column::new("id", "id", SqlType::Uuid),
+        Column::new("username", "username", SqlType::Text),
+        Column::new("users_date", "COALESCE(users_date, 'epoch')", SqlType::TimestampTz),
+    ],
+    default_sort: "id",
+    filters: &[Column::new("role", "role", SqlType::Text)],
+};
+
 /// Endpoint para obter todos os usuários
+#[utoipa::path(
+    tag = "users",
+    params(
+        ListParams,
+        ("role" = Option<String>, Query, description = "Filtra por `role`"),
+    ),
+    responses(
+        (status = 200, description = "Página de resultados", body = UsersPage),
+        (status = 400, description = "Requisição inválida", body = ErrorBody),
+    )
+)]
 #[get("/users")]
 pub async fn get_all_users(
-    opts: Query<FilterOptions>,
-    data: Data<AppState>,
-) -> impl Responder {
-    let limit = opts.limit.unwrap_or(10);
-    let offset = (opts.page.unwrap_or(1) - 1) * limit;
+    query: ListQuery,
+    data: Data<AppState>
+) -> Result<HttpResponse, ApiError> {
+    let page = fetch_page::<UserModel>(&data.db, &USER_LIST, &query).await?;
 
-    match sqlx::query_as!(
-        UserModel,
-        r#"
-        SELECT id, username, password_hash, role, users_date
-        FROM users
-        ORDER BY id
-        LIMIT $1 OFFSET $2
-        "#,
-        limit as i32,
-        offset as i32
-    )
-    .fetch_all(&data.db)
-    .await
-    {
-        Ok(users) => {
-            let response = json!({
-                "status": "success",
-                "users": users
-            });
-            HttpResponse::Ok().json(response)
-        }
-        Err(error) => {
-            let response = json!({
-                "status": "error",
-                "message": format!("Failed to get users: {:?}", error)
-            });
-            HttpResponse::InternalServerError().json(response)
-        }
-    }
+    Ok(HttpResponse::Ok().json(page.to_json("users")))
 }
 
 /// Endpoint para obter um usuário por ID
+#[utoipa::path(
+    tag = "users",
+    responses(
+        (status = 200, description = "Registro encontrado", body = UserResponse),
+        (status = 404, description = "Registro não encontrado", body = ErrorBody),
+    )
+)]
 #[get("/users/{id}")]
 async fn get_user_by_id(
     path: Path<Uuid>,
     data: Data<AppState>,
-) -> impl Responder {
+) -> Result<HttpResponse, ApiError> {
     let user_id = path.into_inner();
 
-    match sqlx::query_as!(
+    let user = sqlx::query_as!(
         UserModel,
         r#"
         SELECT id, username, password_hash, role, users_date
@@ -110,115 +120,99 @@ async fn get_user_by_id(
         "#,
         user_id
     )
-    .fetch_one(&data.db)
-    .await
-    {
-        Ok(user) => {
-            let response = json!({
-                "status": "success",
-                "user": user
-            });
-            HttpResponse::Ok().json(response)
-        }
-        Err(error) => {
-            let response = json!({
-                "status": "error",
-                "message": format!("User not found: {:?}", error)
-            });
-            HttpResponse::NotFound().json(response)
-        }
-    }
+    .fetch_optional(&data.db)
+    .await?
+    .ok_or_else(|| ApiError::not_found("User"))?;
+
+    Ok(HttpResponse::Ok().json(json!({
+        "status": "success",
+        "user": user
+    })))
 }
 
 /// Endpoint para atualizar um usuário por ID
+#[utoipa::path(
+    tag = "users",
+    request_body = UpdateUserSchema,
+    responses(
+        (status = 200, description = "Registro atualizado", body = UserResponse),
+        (status = 400, description = "Requisição inválida", body = ErrorBody),
+        (status = 404, description = "Registro não encontrado", body = ErrorBody),
+        (status = 409, description = "Conflito com um registro existente", body = ErrorBody),
+        (status = 422, description = "Referência a um registro inexistente", body = ErrorBody),
+    )
+)]
 #[patch("/users/{id}")]
 async fn update_user_by_id(
     path: Path<Uuid>,
-    body: Json<UpdateUserSchema>,
+    body: ValidatedJson<UpdateUserSchema>,
     data: Data<AppState>,
-) -> impl Responder {
+    actor: Actor,
+) -> Result<HttpResponse, ApiError> {
     let user_id = path.into_inner();
 
-    // Verifica se usuário existe
-    match sqlx::query_as!(
+    let mut tx = data.db.begin().await?;
+    let before = audit::snapshot(&mut tx, Entity::User, user_id)
+        .await?
+        .ok_or_else(|| ApiError::not_found("User"))?;
+
+    let updated_user = sqlx::query_as!(
         UserModel,
         r#"
-        SELECT id, username, password_hash, role, users_date
-        FROM users
-        WHERE id = $1
+        UPDATE users SET
+            username = COALESCE($1, username),
+            password_hash = COALESCE($2, password_hash),
+            role = COALESCE($3, role)
+        WHERE id = $4
+        RETURNING id, username, password_hash, role, users_date
         "#,
+        body.username.as_ref(),
+        body.password_hash.as_ref(),
+        body.role.as_ref(),
         user_id
     )
-    .fetch_one(&data.db)
-    .await
-    {
-        Ok(_) => {
-            let update_result = sqlx::query_as!(
-                UserModel,
-                r#"
-                UPDATE users SET 
-                    username = COALESCE($1, username), 
-                    password_hash = COALESCE($2, password_hash), 
-                    role = COALESCE($3, role)
-                WHERE id = $4 
-                RETURNING id, username, password_hash, role, users_date
-                "#,
-                body.username.as_ref(),
-                body.password_hash.as_ref(),
-                body.role.as_ref(),
-                user_id
-            )
-            .fetch_one(&data.db)
-            .await;
-
-            match update_result {
-                Ok(updated_user) => {
-                    let response = json!({
-                        "status": "success",
-                        "user": updated_user
-                    });
-                    HttpResponse::Ok().json(response)
-                }
-                Err(update_error) => {
-                    let response = json!({
-                        "status": "error",
-                        "message": format!("Failed to update user: {:?}", update_error)
-                    });
-                    HttpResponse::InternalServerError().json(response)
-                }
-            }
-        }
-        Err(fetch_error) => {
-            let response = json!({
-                "status": "error",
-                "message": format!("User not found: {:?}", fetch_error)
-            });
-            HttpResponse::NotFound().json(response)
-        }
-    }
+    .fetch_optional(&mut tx)
+    .await?
+    .ok_or_else(|| ApiError::not_found("User"))?;
+
+    audit::updated(&mut tx, &actor, Entity::User, user_id, before).await?;
+    tx.commit().await?;
+
+    Ok(HttpResponse::Ok().json(json!({
+        "status": "success",
+        "user": updated_user
+    })))
 }
 
 /// Endpoint para deletar um usuário por ID
+#[utoipa::path(
+    tag = "users",
+    responses(
+        (status = 204, description = "Registro removido"),
+        (status = 404, description = "Registro não encontrado", body = ErrorBody),
+    )
+)]
 #[delete("/users/{id}")]
 async fn delete_user_by_id(
     path: Path<Uuid>,
     data: Data<AppState>,
-) -> impl Responder {
+    actor: Actor,
+) -> Result<HttpResponse, ApiError> {
     let user_id = path.into_inner();
 
-    match sqlx::query!("DELETE FROM users WHERE id = $1", user_id)
-        .execute(&data.db)
-        .await
-    {
-        Ok(_) => HttpResponse::NoContent().finish(),
-        Err(err) => {
-            let response = json!({
-                "status": "error",
-                "message": format!("Failed to delete user: {:?}", err)
-            });
-            HttpResponse::InternalServerError().json(response)
-        }
-    }
+    let mut tx = data.db.begin().await?;
+    let before = audit::snapshot(&mut tx, Entity::User, user_id)
+        .await?
+        .ok_or_else(|| ApiError::not_found("User"))?;
+
+    sqlx::query!("DELETE FROM users WHERE id = $1", user_id)
+        .execute(&mut tx)
+        .await?;
+
+    audit::deleted(&mut tx, &actor, Entity::User, user_id, before).await?;
+    tx.commit().await?;
+
+    Ok(HttpResponse::NoContent().finish())
 }
 
 /// Configuração das rotas de usuários
@@ -0,0 +1,551 @@
+//backend/src/services/video/legacy.rs
+//! Rotas antigas ainda chamadas pelo frontend. Respondem no formato antigo,
+//! com os cabeçalhos `Deprecation` e `Link` apontando para a rota nova. O
+//! `Link` leva os ids reais; quando a rota nova depende de um id que a
+//! requisição não tem (ex.: listagem sem `video_id`, rendition inexistente),
+//! ele é omitido.
+use actix_multipart::Multipart;
+use actix_web::{
+    get, post, delete, patch,
+    http::header::{self, HeaderName, HeaderValue},
+    web::{Data, Path, ServiceConfig},
+    HttpResponse, Responder, ResponseError,
+};
+use serde_json::json;
+use uuid::Uuid;
+use crate::{
+    validation::ValidatedJson,
+    error::ApiError,
+    models::VideoMediaModel,
+    pagination::{ListParams, ListQuery},
+    schema::{CreateMeusVideoSchema, UpdateMeusVideoSchema, CreateVideoMediaSchema, UpdateVideoMediaSchema, CreateVideoSchema},
+    request_id::RequestId,
+    AppState,
+};
+use super::{media, submissions, uploads, videos};
+
+// Marca a resposta (de sucesso ou de erro) como depreciada, indicando a rota que a substitui
+fn deprecated(result: Result<HttpResponse, ApiError>, successor: Option<&str>) -> HttpResponse {
+    let mut response = result.unwrap_or_else(|error| error.error_response());
+    let headers = response.headers_mut();
+    headers.insert(HeaderName::from_static("deprecation"), HeaderValue::from_static("true"));
+    let link = successor.and_then(|url| HeaderValue::from_str(&format!("<{}>; rel=\"successor-version\"", url)).ok());
+    if let Some(link) = link {
+        headers.insert(header::LINK, link);
+    }
+    response
+}
+
+fn media_url(video_id: Uuid) -> String {
+    format!("/api/videos/{}/media", video_id)
+}
+
+fn media_item_url(media: &VideoMediaModel) -> String {
+    format!("/api/videos/{}/media/{}", media.video_id, media.id)
+}
+
+// Listagens antigas: só há sucessor quando a lista é filtrada por um vídeo
+fn media_list_url(query: &ListQuery) -> Option<String> {
+    query
+        .filters
+        .iter()
+        .find(|(name, _)| name == "video_id")
+        .and_then(|(_, value)| value.parse().ok())
+        .map(media_url)
+}
+
+async fn legacy_upload(payload: Multipart, data: &AppState) -> Result<HttpResponse, ApiError> {
+    let response = match uploads::save_upload(payload, &data.config.storage).await? {
+        Some(file_url) => json!({
+            "status": "success",
+            "message": "File uploaded successfully.",
+            "file_url": file_url
+        }),
+        None => json!({
+            "status": "success",
+            "message": "File uploaded successfully."
+        }),
+    };
+    Ok(HttpResponse::Ok().json(response))
+}
+
+#[utoipa::path(
+    tag = "legacy",
+    operation_id = "legacy_upload_file",
+    request_body(content = FileUpload, content_type = "multipart/form-data"),
+    responses(
+        (status = 200, description = "Arquivo salvo", body = UploadResponse),
+        (status = 400, description = "Requisição inválida", body = ErrorBody),
+        (status = 413, description = "Arquivo maior que o limite configurado", body = ErrorBody),
+    )
+)]
+#[post("/upload")]
+async fn upload_file(payload: Multipart, data: Data<AppState>) -> impl Responder {
+    deprecated(legacy_upload(payload, &data).await, Some("/api/videos/uploads"))
+}
+
+#[utoipa::path(
+    tag = "legacy",
+    request_body(content = FileUpload, content_type = "multipart/form-data"),
+    responses(
+        (status = 200, description = "Arquivo salvo", body = UploadResponse),
+        (status = 400, description = "Requisição inválida", body = ErrorBody),
+        (status = 413, description = "Arquivo maior que o limite configurado", body = ErrorBody),
+    )
+)]
+#[post("/all_videos/upload")]
+async fn upload_all_videos_file(payload: Multipart, data: Data<AppState>) -> impl Responder {
+    deprecated(legacy_upload(payload, &data).await, Some("/api/videos/uploads"))
+}
+
+// ---- /all_videos -> /videos/{id}/media ----
+
+#[utoipa::path(
+    tag = "legacy",
+    request_body = CreateVideoMediaSchema,
+    responses(
+        (status = 200, description = "Rendition criada", body = LegacyVideoResponse),
+        (status = 400, description = "Requisição inválida", body = ErrorBody),
+        (status = 409, description = "Conflito com um registro existente", body = ErrorBody),
+        (status = 422, description = "Referência a um registro inexistente", body = ErrorBody),
+    )
+)]
+#[post("/all_videos")]
+async fn create_all_video(
+    body: ValidatedJson<CreateVideoMediaSchema>,
+    data: Data<AppState>
+) -> impl Responder {
+    let result = media::insert_media(&data.db, &body)
+        .await
+        .map(|video| HttpResponse::Ok().json(json!({"status": "success", "video": video})))
+        .map_err(ApiError::from);
+    deprecated(result, Some(&media_url(body.video_id)))
+}
+
+#[utoipa::path(
+    tag = "legacy",
+    params(
+        ListParams,
+        ("video_id" = Option<Uuid>, Query, description = "Filtra por `video_id`"),
+        ("status" = Option<String>, Query, description = "Filtra por `status`"),
+    ),
+    responses(
+        (status = 200, description = "Página de resultados", body = AllVideosPage),
+        (status = 400, description = "Requisição inválida", body = ErrorBody),
+    )
+)]
+#[get("/all_videos")]
+async fn get_all_all_videos(
+    query: ListQuery,
+    data: Data<AppState>
+) -> impl Responder {
+    let result = media::list_media(&data.db, &query)
+        .await
+        .map(|page| HttpResponse::Ok().json(page.to_json("all_videos")));
+    deprecated(result, media_list_url(&query).as_deref())
+}
+
+#[utoipa::path(
+    tag = "legacy",
+    responses(
+        (status = 200, description = "Rendition encontrada", body = LegacyVideoResponse),
+        (status = 404, description = "Registro não encontrado", body = ErrorBody),
+    )
+)]
+#[get("/all_videos/{id}")]
+async fn get_all_video_by_id(
+    path: Path<Uuid>,
+    data: Data<AppState>
+) -> impl Responder {
+    let (result, successor) = match media::find_media(&data.db, path.into_inner()).await {
+        Ok(Some(video)) => {
+            let successor = media_item_url(&video);
+            (Ok(HttpResponse::Ok().json(json!({"status": "success", "video": video}))), Some(successor))
+        }
+        Ok(None) => (Err(ApiError::not_found("Video")), None),
+        Err(error) => (Err(error.into()), None),
+    };
+    deprecated(result, successor.as_deref())
+}
+
+#[utoipa::path(
+    tag = "legacy",
+    request_body = UpdateVideoMediaSchema,
+    responses(
+        (status = 200, description = "Registro atualizado", body = AllVideoResponse),
+        (status = 400, description = "Requisição inválida", body = ErrorBody),
+        (status = 404, description = "Registro não encontrado", body = ErrorBody),
+        (status = 409, description = "Conflito com um registro existente", body = ErrorBody),
+        (status = 422, description = "Referência a um registro inexistente", body = ErrorBody),
+    )
+)]
+#[patch("/all_videos/{id}")]
+async fn update_all_video_by_id(
+    path: Path<Uuid>,
+    body: ValidatedJson<UpdateVideoMediaSchema>,
+    data: Data<AppState>
+) -> impl Responder {
+    let (result, successor) = match media::update_media(&data.db, path.into_inner(), &body).await {
+        Ok(Some(all_video)) => {
+            let successor = media_item_url(&all_video);
+            (Ok(HttpResponse::Ok().json(json!({"status": "success", "all_video": all_video}))), Some(successor))
+        }
+        Ok(None) => (Err(ApiError::not_found("Video")), None),
+        Err(error) => (Err(error.into()), None),
+    };
+    deprecated(result, successor.as_deref())
+}
+
+#[utoipa::path(
+    tag = "legacy",
+    responses(
+        (status = 204, description = "Registro removido"),
+        (status = 404, description = "Registro não encontrado", body = ErrorBody),
+    )
+)]
+#[delete("/all_videos/{id}")]
+async fn delete_all_video_by_id(
+    path: Path<Uuid>,
+    data: Data<AppState>
+) -> impl Responder {
+    let media_id = path.into_inner();
+    let (result, successor) = match media::delete_media(&data.db, media_id).await {
+        Ok(Some(video_id)) => (
+            Ok(HttpResponse::NoContent().finish()),
+            Some(format!("/api/videos/{}/media/{}", video_id, media_id)),
+        ),
+        Ok(None) => (Err(ApiError::not_found("Video")), None),
+        Err(error) => (Err(error.into()), None),
+    };
+    deprecated(result, successor.as_deref())
+}
+
+// ---- /videomedia(s) e /videosmedias -> /videos/{id}/media ----
+
+#[utoipa::path(
+    tag = "legacy",
+    request_body = CreateVideoMediaSchema,
+    responses(
+        (status = 200, description = "Rendition criada", body = VideomediaResponse),
+        (status = 400, description = "Requisição inválida", body = ErrorBody),
+        (status = 409, description = "Conflito com um registro existente", body = ErrorBody),
+        (status = 422, description = "Referência a um registro inexistente", body = ErrorBody),
+    )
+)]
+#[post("/videomedia")]
+async fn create_videomedia(
+    body: ValidatedJson<CreateVideoMediaSchema>,
+    data: Data<AppState>
+) -> impl Responder {
+    let result = media::insert_media(&data.db, &body)
+        .await
+        .map(|videomedia| HttpResponse::Ok().json(json!({"status": "success", "videomedia": videomedia})))
+        .map_err(ApiError::from);
+    deprecated(result, Some(&media_url(body.video_id)))
+}
+
+#[utoipa::path(
+    tag = "legacy",
+    params(
+        ListParams,
+        ("video_id" = Option<Uuid>, Query, description = "Filtra por `video_id`"),
+        ("status" = Option<String>, Query, description = "Filtra por `status`"),
+    ),
+    responses(
+        (status = 200, description = "Página de resultados", body = VideomediasPage),
+        (status = 400, description = "Requisição inválida", body = ErrorBody),
+    )
+)]
+#[get("/videomedias")]
+async fn get_all_videomedias(
+    query: ListQuery,
+    data: Data<AppState>
+) -> impl Responder {
+    let result = media::list_media(&data.db, &query)
+        .await
+        .map(|page| HttpResponse::Ok().json(page.to_json("videomedias")));
+    deprecated(result, media_list_url(&query).as_deref())
+}
+
+#[utoipa::path(
+    tag = "legacy",
+    responses(
+        (status = 200, description = "Rendition encontrada", body = VideomediaResponse),
+        (status = 404, description = "Registro não encontrado", body = ErrorBody),
+    )
+)]
+#[get("/videosmedias/{id}")]
+async fn get_videomedia_by_id(
+    path: Path<Uuid>,
+    data: Data<AppState>
+) -> impl Responder {
+    let (result, successor) = match media::find_media(&data.db, path.into_inner()).await {
+        Ok(Some(videomedia)) => {
+            let successor = media_item_url(&videomedia);
+            (Ok(HttpResponse::Ok().json(json!({"status": "success", "videomedia": videomedia}))), Some(successor))
+        }
+        Ok(None) => (Err(ApiError::not_found("Video media")), None),
+        Err(error) => (Err(error.into()), None),
+    };
+    deprecated(result, successor.as_deref())
+}
+
+#[utoipa::path(
+    tag = "legacy",
+    request_body = UpdateVideoMediaSchema,
+    responses(
+        (status = 200, description = "Registro atualizado", body = VideomediaResponse),
+        (status = 400, description = "Requisição inválida", body = ErrorBody),
+        (status = 404, description = "Registro não encontrado", body = ErrorBody),
+        (status = 409, description = "Conflito com um registro existente", body = ErrorBody),
+        (status = 422, description = "Referência a um registro inexistente", body = ErrorBody),
+    )
+)]
+#[patch("/videosmedias/{id}")]
+async fn update_videomedia_by_id(
+    path: Path<Uuid>,
+    body: ValidatedJson<UpdateVideoMediaSchema>,
+    data: Data<AppState>
+) -> impl Responder {
+    let (result, successor) = match media::update_media(&data.db, path.into_inner(), &body).await {
+        Ok(Some(videomedia)) => {
+            let successor = media_item_url(&videomedia);
+            (Ok(HttpResponse::Ok().json(json!({"status": "success", "videomedia": videomedia}))), Some(successor))
+        }
+        Ok(None) => (Err(ApiError::not_found("Video media")), None),
+        Err(error) => (Err(error.into()), None),
+    };
+    deprecated(result, successor.as_deref())
+}
+
+#[utoipa::path(
+    tag = "legacy",
+    responses(
+        (status = 204, description = "Registro removido"),
+        (status = 404, description = "Registro não encontrado", body = ErrorBody),
+    )
+)]
+#[delete("/videosmedias/{id}")]
+async fn delete_videomedia_by_id(
+    path: Path<Uuid>,
+    data: Data<AppState>
+) -> impl Responder {
+    let media_id = path.into_inner();
+    let (result, successor) = match media::delete_media(&data.db, media_id).await {
+        Ok(Some(video_id)) => (
+            Ok(HttpResponse::NoContent().finish()),
+            Some(format!("/api/videos/{}/media/{}", video_id, media_id)),
+        ),
+        Ok(None) => (Err(ApiError::not_found("Video media")), None),
+        Err(error) => (Err(error.into()), None),
+    };
+    deprecated(result, successor.as_deref())
+}
+
+// ---- /upload_chunk e /finish_upload -> /videos/{id}/upload ----
+
+#[utoipa::path(
+    tag = "legacy",
+    request_body(content = FileUpload, content_type = "multipart/form-data"),
+    responses(
+        (status = 200, description = "Pedaço salvo", body = ChunkResponse),
+        (status = 400, description = "Requisição inválida", body = ErrorBody),
+        (status = 404, description = "Registro não encontrado", body = ErrorBody),
+        (status = 413, description = "Pedaço maior que o limite configurado", body = ErrorBody),
+    )
+)]
+#[post("/upload_chunk/{id}/{chunk_index}")]
+async fn upload_chunk(
+    path: Path<(Uuid, usize)>,
+    payload: Multipart,
+    data: Data<AppState>,
+) -> impl Responder {
+    let (video_id, chunk_index) = path.into_inner();
+    let result = uploads::receive_chunk(&data, video_id, chunk_index, payload)
+        .await
+        .map(|()| HttpResponse::Ok().json(json!({ "status": "success", "chunk_index": chunk_index })));
+    deprecated(result, Some(&format!("/api/videos/{}/upload/chunks/{}", video_id, chunk_index)))
+}
+
+#[utoipa::path(
+    tag = "legacy",
+    request_body = FinishUpload,
+    responses(
+        (status = 200, description = "Upload consolidado", body = StatusMessage),
+        (status = 400, description = "Requisição inválida", body = ErrorBody),
+        (status = 404, description = "Registro não encontrado", body = ErrorBody),
+    )
+)]
+#[post("/finish_upload/{id}")]
+async fn finish_upload(
+    path: Path<Uuid>,
+    body: ValidatedJson<uploads::FinishUpload>,
+    data: Data<AppState>,
+    request_id: RequestId,
+) -> impl Responder {
+    let video_id = path.into_inner();
+    let result = uploads::finish_upload(&data, video_id, &body, &request_id)
+        .await
+        .map(|()| HttpResponse::Ok().json(json!({ "status": "success", "message": "Upload finalizado com sucesso" })));
+    deprecated(result, Some(&format!("/api/videos/{}/upload/complete", video_id)))
+}
+
+// ---- /playes -> /videos ----
+
+#[utoipa::path(
+    tag = "legacy",
+    request_body = CreateVideoSchema,
+    responses(
+        (status = 201, description = "Registro criado", body = PlayeResponse),
+        (status = 400, description = "Requisição inválida", body = ErrorBody),
+        (status = 409, description = "Conflito com um registro existente", body = ErrorBody),
+        (status = 422, description = "Referência a um registro inexistente", body = ErrorBody),
+    )
+)]
+#[post("/playes")]
+async fn create_playe(
+    body: ValidatedJson<CreateVideoSchema>,
+    data: Data<AppState>,
+) -> impl Responder {
+    let result = videos::insert_video(&body, &data)
+        .await
+        .map(|playe| HttpResponse::Created().json(json!({"status": "success", "playe": playe})));
+    deprecated(result, Some("/api/videos"))
+}
+
+// ---- /meusvideos -> /submissions ----
+
+#[utoipa::path(
+    tag = "legacy",
+    request_body = CreateMeusVideoSchema,
+    responses(
+        (status = 200, description = "Registro criado", body = MeusvideoResponse),
+        (status = 400, description = "Requisição inválida", body = ErrorBody),
+        (status = 409, description = "Conflito com um registro existente", body = ErrorBody),
+        (status = 422, description = "Referência a um registro inexistente", body = ErrorBody),
+    )
+)]
+#[post("/meusvideos")]
+async fn create_meusvideo(
+    body: ValidatedJson<CreateMeusVideoSchema>,
+    data: Data<AppState>
+) -> impl Responder {
+    let result = submissions::insert_submission(&data.db, &body)
+        .await
+        .map(|meusvideo| HttpResponse::Ok().json(json!({"status": "success", "meusvideo": meusvideo})))
+        .map_err(ApiError::from);
+    deprecated(result, Some("/api/submissions"))
+}
+
+#[utoipa::path(
+    tag = "legacy",
+    params(
+        ListParams,
+        ("student_id" = Option<Uuid>, Query, description = "Filtra por `student_id`"),
+    ),
+    responses(
+        (status = 200, description = "Página de resultados", body = MeusvideosPage),
+        (status = 400, description = "Requisição inválida", body = ErrorBody),
+    )
+)]
+#[get("/meusvideos")]
+async fn get_all_meusvideos(
+    query: ListQuery,
+    data: Data<AppState>
+) -> impl Responder {
+    let result = submissions::list_submissions(&data.db, &query)
+        .await
+        .map(|page| HttpResponse::Ok().json(page.to_json("meusvideos")));
+    deprecated(result, Some("/api/submissions"))
+}
+
+#[utoipa::path(
+    tag = "legacy",
+    responses(
+        (status = 200, description = "Registro encontrado", body = MeusvideoResponse),
+        (status = 404, description = "Registro não encontrado", body = ErrorBody),
+    )
+)]
+#[get("/meusvideos/{id}")]
+async fn get_meusvideo_by_id(
+    path: Path<Uuid>,
+    data: Data<AppState>
+) -> impl Responder {
+    let id = path.into_inner();
+    let result = match submissions::find_submission(&data.db, id).await {
+        Ok(Some(meusvideo)) => Ok(HttpResponse::Ok().json(json!({"status": "success", "meusvideo": meusvideo}))),
+        Ok(None) => Err(ApiError::not_found("Submission")),
+        Err(error) => Err(error.into()),
+    };
+    deprecated(result, Some(&format!("/api/submissions/{}", id)))
+}
+
+#[utoipa::path(
+    tag = "legacy",
+    request_body = UpdateMeusVideoSchema,
+    responses(
+        (status = 200, description = "Registro atualizado", body = MeusvideoResponse),
+        (status = 400, description = "Requisição inválida", body = ErrorBody),
+        (status = 404, description = "Registro não encontrado", body = ErrorBody),
+        (status = 409, description = "Conflito com um registro existente", body = ErrorBody),
+        (status = 422, description = "Referência a um registro inexistente", body = ErrorBody),
+    )
+)]
+#[patch("/meusvideos/{id}")]
+async fn update_meusvideo_by_id(
+    path: Path<Uuid>,
+    body: ValidatedJson<UpdateMeusVideoSchema>,
+    data: Data<AppState>
+) -> impl Responder {
+    let id = path.into_inner();
+    let result = match submissions::update_submission(&data.db, id, &body).await {
+        Ok(Some(meusvideo)) => Ok(HttpResponse::Ok().json(json!({"status": "success", "meusvideo": meusvideo}))),
+        Ok(None) => Err(ApiError::not_found("Submission")),
+        Err(error) => Err(error.into()),
+    };
+    deprecated(result, Some(&format!("/api/submissions/{}", id)))
+}
+
+#[utoipa::path(
+    tag = "legacy",
+    responses(
+        (status = 204, description = "Registro removido"),
+        (status = 404, description = "Registro não encontrado", body = ErrorBody),
+    )
+)]
+#[delete("/meusvideos/{id}")]
+async fn delete_meusvideo_by_id(
+    path: Path<Uuid>,
+    data: Data<AppState>
+) -> impl Responder {
+    let id = path.into_inner();
+    let result = match submissions::delete_submission(&data.db, id).await {
+        Ok(true) => Ok(HttpResponse::NoContent().finish()),
+        Ok(false) => Err(ApiError::not_found("Submission")),
+        Err(error) => Err(error.into()),
+    };
+    deprecated(result, Some(&format!("/api/submissions/{}", id)))
+}
+
+pub fn config_legacy(conf: &mut ServiceConfig) {
+    conf.service(upload_file)
+       .service(upload_all_videos_file)
+       .service(create_all_video)
+       .service(get_all_all_videos)
+       .service(get_all_video_by_id)
+       .service(update_all_video_by_id)
+       .service(delete_all_video_by_id)
+       .service(create_videomedia)
+       .service(get_all_videomedias)
+       .service(get_videomedia_by_id)
+       .service(update_videomedia_by_id)
+       .service(delete_videomedia_by_id)
+       .service(upload_chunk)
+       .service(finish_upload)
+       .service(create_playe)
+       .service(create_meusvideo)
+       .service(get_all_meusvideos)
+       .service(get_meusvideo_by_id)
+       .service(update_meusvideo_by_id)
+       .service(delete_meusvideo_by_id);
+}
@@ -0,0 +1,226 @@
+//backend/src/services/video/media.rs
+use actix_web::{
+    get, post, delete, patch,
+    web::{Data, Path, ServiceConfig},
+    HttpResponse,
+};
+use serde_json::json;
+use sqlx::PgPool;
+use uuid::Uuid;
+use crate::{
+    validation::ValidatedJson,
+    error::ApiError,
+    pagination::{Column, ListParams, ListQuery, ListSpec, Page, SqlType, fetch_page},
+    models::VideoMediaModel,
+    schema::{CreateVideoMediaSchema, CreateRenditionSchema, UpdateVideoMediaSchema},
+    AppState,
+};
+
+// Consultas das renditions (tabela `videomedias`), compartilhadas com os aliases antigos
+
+pub(super) async fn insert_media(db: &PgPool, body: &CreateVideoMediaSchema) -> Result<VideoMediaModel, sqlx::Error> {
+    let query = r#"
+        INSERT INTO videomedias (video_id, video_path, status)
+        VALUES ($1, $2, $3)
+        RETURNING id, video_id, video_path, status
+    "#;
+
+    sqlx::query_as::<_, VideoMediaModel>(query)
+        .bind(body.video_id)
+        .bind(&body.video_path)
+        .bind(&body.status)
+        .fetch_one(db)
+        .await
+}
+
+const MEDIA_LIST: ListSpec = ListSpec {
+    from: "videomedias",
+    select: "id, video_id, video_path, status",
+    id: "id",
+    sorts: &[
+        Column::new("id", "id", SqlType::Uuid),
+        Column::new("status", "status", SqlType::Text),
+    ],
+    default_sort: "id",
+    filters: &[
+        Column::new("video_id", "video_id", SqlType::Uuid),
+        Column::new("status", "status", SqlType::Text),
+    ],
+};
+
+pub(super) async fn list_media(db: &PgPool, query: &ListQuery) -> Result<Page<VideoMediaModel>, ApiError> {
+    fetch_page(db, &MEDIA_LIST, query).await
+}
+
+pub(super) async fn find_media(db: &PgPool, media_id: Uuid) -> Result<Option<VideoMediaModel>, sqlx::Error> {
+    sqlx::query_as::<_, VideoMediaModel>("SELECT * FROM videomedias WHERE id = $1")
+        .bind(media_id)
+        .fetch_optional(db)
+        .await
+}
+
+pub(super) async fn update_media(
+    db: &PgPool,
+    media_id: Uuid,
+    body: &UpdateVideoMediaSchema,
+) -> Result<Option<VideoMediaModel>, sqlx::Error> {
+    let query = r#"
+        UPDATE videomedias
+        SET video_id = COALESCE($1, video_id),
+            video_path = COALESCE($2, video_path),
+            status = COALESCE($3, status)
+        WHERE id = $4
+        RETURNING *
+    "#;
+
+    sqlx::query_as::<_, VideoMediaModel>(query)
+        .bind(body.video_id)
+        .bind(&body.video_path)
+        .bind(&body.status)
+        .bind(media_id)
+        .fetch_optional(db)
+        .await
+}
+
+/// `video_id` da rendition removida; `None` se ela não existia
+pub(super) async fn delete_media(db: &PgPool, media_id: Uuid) -> Result<Option<Uuid>, sqlx::Error> {
+    sqlx::query_scalar("DELETE FROM videomedias WHERE id = $1 RETURNING video_id")
+        .bind(media_id)
+        .fetch_optional(db)
+        .await
+}
+
+/// Lista as renditions de um vídeo
+#[utoipa::path(
+    tag = "videos",
+    params(
+        ListParams,
+        ("status" = Option<String>, Query, description = "Filtra por `status`"),
+    ),
+    responses(
+        (status = 200, description = "Página de resultados", body = MediaPage),
+        (status = 400, description = "Requisição inválida", body = ErrorBody),
+        (status = 404, description = "Registro não encontrado", body = ErrorBody),
+    )
+)]
+#[get("/videos/{id}/media")]
+async fn get_video_media(
+    path: Path<Uuid>,
+    query: ListQuery,
+    data: Data<AppState>
+) -> Result<HttpResponse, ApiError> {
+    let query = query.with_filter("video_id", path.into_inner());
+    let page = list_media(&data.db, &query).await?;
+
+    Ok(HttpResponse::Ok().json(page.to_json("media")))
+}
+
+/// Registra uma nova rendition para o vídeo
+#[utoipa::path(
+    tag = "videos",
+    request_body = CreateRenditionSchema,
+    responses(
+        (status = 201, description = "Registro criado", body = MediaResponse),
+        (status = 400, description = "Requisição inválida", body = ErrorBody),
+        (status = 404, description = "Registro não encontrado", body = ErrorBody),
+        (status = 409, description = "Conflito com um registro existente", body = ErrorBody),
+        (status = 422, description = "Referência a um registro inexistente", body = ErrorBody),
+    )
+)]
+#[post("/videos/{id}/media")]
+async fn create_video_media(
+    path: Path<Uuid>,
+    body: ValidatedJson<CreateRenditionSchema>,
+    data: Data<AppState>
+) -> Result<HttpResponse, ApiError> {
+    let body = body.into_inner();
+    let body = CreateVideoMediaSchema {
+        video_id: path.into_inner(),
+        video_path: body.video_path,
+        status: body.status,
+    };
+
+    let media = insert_media(&data.db, &body).await?;
+
+    Ok(HttpResponse::Created().json(json!({"status": "success", "media": media})))
+}
+
+/// Obtém uma rendition
+#[utoipa::path(
+    tag = "videos",
+    responses(
+        (status = 200, description = "Registro encontrado", body = MediaResponse),
+        (status = 404, description = "Registro não encontrado", body = ErrorBody),
+    )
+)]
+#[get("/videos/{id}/media/{media_id}")]
+async fn get_video_media_by_id(
+    path: Path<(Uuid, Uuid)>,
+    data: Data<AppState>
+) -> Result<HttpResponse, ApiError> {
+    let (video_id, media_id) = path.into_inner();
+
+    let media = find_media(&data.db, media_id)
+        .await?
+        .filter(|media| media.video_id == video_id)
+        .ok_or_else(|| ApiError::not_found("Video media"))?;
+
+    Ok(HttpResponse::Ok().json(json!({"status": "success", "media": media})))
+}
+
+/// Atualiza o caminho ou o status de uma rendition
+#[utoipa::path(
+    tag = "videos",
+    request_body = UpdateVideoMediaSchema,
+    responses(
+        (status = 200, description = "Registro atualizado", body = MediaResponse),
+        (status = 400, description = "Requisição inválida", body = ErrorBody),
+        (status = 404, description = "Registro não encontrado", body = ErrorBody),
+        (status = 409, description = "Conflito com um registro existente", body = ErrorBody),
+        (status = 422, description = "Referência a um registro inexistente", body = ErrorBody),
+    )
+)]
+#[patch("/videos/{id}/media/{media_id}")]
+async fn update_video_media_by_id(
+    path: Path<(Uuid, Uuid)>,
+    body: ValidatedJson<UpdateVideoMediaSchema>,
+    data: Data<AppState>
+) -> Result<HttpResponse, ApiError> {
+    let (_video_id, media_id) = path.into_inner();
+
+    let media = update_media(&data.db, media_id, &body)
+        .await?
+        .ok_or_else(|| ApiError::not_found("Video media"))?;
+
+    Ok(HttpResponse::Ok().json(json!({"status": "success", "media": media})))
+}
+
+/// Remove uma rendition
+#[utoipa::path(
+    tag = "videos",
+    responses(
+        (status = 204, description = "Registro removido"),
+        (status = 404, description = "Registro não encontrado", body = ErrorBody),
+    )
+)]
+#[delete("/videos/{id}/media/{media_id}")]
+async fn delete_video_media_by_id(
+    path: Path<(Uuid, Uuid)>,
+    data: Data<AppState>
+) -> Result<HttpResponse, ApiError> {
+    let (_video_id, media_id) = path.into_inner();
+
+    if delete_media(&data.db, media_id).await?.is_none() {
+        return Err(ApiError::not_found("Video media"));
+    }
+
+    Ok(HttpResponse::NoContent().finish())
+}
+
+pub fn config_media(conf: &mut ServiceConfig) {
+    conf.service(get_video_media)
+       .service(create_video_media)
+       .service(get_video_media_by_id)
+       .service(update_video_media_by_id)
+       .service(delete_video_media_by_id);
+}
@@ -0,0 +1,24 @@
+//backend/src/services/video/mod.rs
+//! Domínio de vídeo, com um recurso por arquivo:
+//! - `videos`: o vídeo publicado (metadados, slug, busca)
+//! - `media`: renditions geradas pelo transcoder (`videomedias`), em `/videos/{id}/media`
+//! - `submissions`: vídeos enviados por alunos (`meusvideos`), em `/submissions`
+//! - `uploads`: envio de arquivos, inteiro ou em pedaços
+//! - `legacy`: aliases depreciados das rotas antigas (`/upload`, `/all_videos`, `/videomedia(s)`,
+//!   `/upload_chunk`, `/finish_upload`, `/playes`, `/meusvideos`)
+use actix_web::web::ServiceConfig;
+
+pub mod legacy;
+pub mod media;
+pub mod submissions;
+pub mod uploads;
+pub mod videos;
+
+pub fn config_video(conf: &mut ServiceConfig) {
+    // Rotas com segmentos fixos antes de `/videos/{id}`
+    conf.configure(uploads::config_uploads)
+        .configure(videos::config_videos)
+        .configure(media::config_media)
+        .configure(submissions::config_submissions)
+        .configure(legacy::config_legacy);
+}
@@ -0,0 +1,227 @@
+//backend/src/services/video/submissions.rs
+use actix_web::{
+    get, post, delete, patch,
+    web::{Data, Path, ServiceConfig},
+    HttpResponse,
+};
+use serde_json::json;
+use sqlx::PgPool;
+use uuid::Uuid;
+use crate::{
+    validation::ValidatedJson,
+    error::ApiError,
+    pagination::{Column, ListParams, ListQuery, ListSpec, Page, SqlType, fetch_page},
+    models::MeusVideoModel,
+    schema::{CreateMeusVideoSchema, UpdateMeusVideoSchema},
+    AppState,
+};
+
+// Consultas dos vídeos enviados por alunos (tabela `meusvideos`), compartilhadas com os aliases antigos
+
+pub(super) async fn insert_submission(db: &PgPool, body: &CreateMeusVideoSchema) -> Result<MeusVideoModel, sqlx::Error> {
+    let query = r#"
+        INSERT INTO meusvideos (student_id, filename, description)
+        VALUES ($1, $2, $3)
+        RETURNING id, student_id, filename, description, created_at
+    "#;
+
+    sqlx::query_as::<_, MeusVideoModel>(query)
+        .bind(body.student_id)
+        .bind(&body.filename)
+        .bind(&body.description)
+        .fetch_one(db)
+        .await
+}
+
+const SUBMISSION_LIST: ListSpec = ListSpec {
+    from: "meusvideos",
+    select: "id, student_id, filename, description, created_at",
+    id: "id",
+    sorts: &[
+        Column::new("id", "id", SqlType::Uuid),
+        Column::new("created_at", "COALESCE(created_at, 'epoch')", SqlType::TimestampTz),
+    ],
+    default_sort: "-created_at",
+    filters: &[Column::new("student_id", "student_id", SqlType::Uuid)],
+};
+
+pub(super) async fn list_submissions(db: &PgPool, query: &ListQuery) -> Result<Page<MeusVideoModel>, ApiError> {
+    fetch_page(db, &SUBMISSION_LIST, query).await
+}
+
+pub(super) async fn find_submission(db: &PgPool, submission_id: Uuid) -> Result<Option<MeusVideoModel>, sqlx::Error> {
+    sqlx::query_as::<_, MeusVideoModel>("SELECT * FROM meusvideos WHERE id = $1")
+        .bind(submission_id)
+        .fetch_optional(db)
+        .await
+}
+
+pub(super) async fn update_submission(
+    db: &PgPool,
+    submission_id: Uuid,
+    body: &UpdateMeusVideoSchema,
+) -> Result<Option<MeusVideoModel>, sqlx::Error> {
+    let query = r#"
+        UPDATE meusvideos
+        SET student_id = COALESCE($1, student_id),
+            filename = COALESCE($2, filename),
+            description = COALESCE($3, description)
+        WHERE id = $4
+        RETURNING *
+    "#;
+
+    sqlx::query_as::<_, MeusVideoModel>(query)
+        .bind(body.student_id)
+        .bind(&body.filename)
+        .bind(&body.description)
+        .bind(submission_id)
+        .fetch_optional(db)
+        .await
+}
+
+pub(super) async fn delete_submission(db: &PgPool, submission_id: Uuid) -> Result<bool, sqlx::Error> {
+    sqlx::query("DELETE FROM meusvideos WHERE id = $1")
+        .bind(submission_id)
+        .execute(db)
+        .await
+        .map(|result| result.rows_affected() > 0)
+}
+
+/// Cria um envio de vídeo de aluno
+#[utoipa::path(
+    tag = "submissions",
+    request_body = CreateMeusVideoSchema,
+    responses(
+        (status = 201, description = "Registro criado", body = SubmissionResponse),
+        (status = 400, description = "Requisição inválida", body = ErrorBody),
+        (status = 409, description = "Conflito com um registro existente", body = ErrorBody),
+        (status = 422, description = "Referência a um registro inexistente", body = ErrorBody),
+    )
+)]
+#[post("/submissions")]
+async fn create_submission(
+    body: ValidatedJson<CreateMeusVideoSchema>,
+    data: Data<AppState>
+) -> Result<HttpResponse, ApiError> {
+    let submission = insert_submission(&data.db, &body).await?;
+
+    Ok(HttpResponse::Created().json(json!({"status": "success", "submission": submission})))
+}
+
+/// Lista os envios de todos os alunos
+#[utoipa::path(
+    tag = "submissions",
+    params(
+        ListParams,
+        ("student_id" = Option<Uuid>, Query, description = "Filtra por `student_id`"),
+    ),
+    responses(
+        (status = 200, description = "Página de resultados", body = SubmissionsPage),
+        (status = 400, description = "Requisição inválida", body = ErrorBody),
+    )
+)]
+#[get("/submissions")]
+async fn get_all_submissions(
+    query: ListQuery,
+    data: Data<AppState>
+) -> Result<HttpResponse, ApiError> {
+    let page = list_submissions(&data.db, &query).await?;
+
+    Ok(HttpResponse::Ok().json(page.to_json("submissions")))
+}
+
+/// Lista os envios de um aluno
+#[utoipa::path(
+    tag = "submissions",
+    params(ListParams),
+    responses(
+        (status = 200, description = "Página de resultados", body = SubmissionsPage),
+        (status = 400, description = "Requisição inválida", body = ErrorBody),
+        (status = 404, description = "Registro não encontrado", body = ErrorBody),
+    )
+)]
+#[get("/students/{id}/submissions")]
+async fn get_student_submissions(
+    path: Path<Uuid>,
+    query: ListQuery,
+    data: Data<AppState>
+) -> Result<HttpResponse, ApiError> {
+    let query = query.with_filter("student_id", path.into_inner());
+    let page = list_submissions(&data.db, &query).await?;
+
+    Ok(HttpResponse::Ok().json(page.to_json("submissions")))
+}
+
+/// Obtém um envio
+#[utoipa::path(
+    tag = "submissions",
+    responses(
+        (status = 200, description = "Registro encontrado", body = SubmissionResponse),
+        (status = 404, description = "Registro não encontrado", body = ErrorBody),
+    )
+)]
+#[get("/submissions/{id}")]
+async fn get_submission_by_id(
+    path: Path<Uuid>,
+    data: Data<AppState>
+) -> Result<HttpResponse, ApiError> {
+    let submission = find_submission(&data.db, path.into_inner())
+        .await?
+        .ok_or_else(|| ApiError::not_found("Submission"))?;
+
+    Ok(HttpResponse::Ok().json(json!({"status": "success", "submission": submission})))
+}
+
+/// Atualiza um envio
+#[utoipa::path(
+    tag = "submissions",
+    request_body = UpdateMeusVideoSchema,
+    responses(
+        (status = 200, description = "Registro atualizado", body = SubmissionResponse),
+        (status = 400, description = "Requisição inválida", body = ErrorBody),
+        (status = 404, description = "Registro não encontrado", body = ErrorBody),
+        (status = 409, description = "Conflito com um registro existente", body = ErrorBody),
+        (status = 422, description = "Referência a um registro inexistente", body = ErrorBody),
+    )
+)]
+#[patch("/submissions/{id}")]
+async fn update_submission_by_id(
+    path: Path<Uuid>,
+    body: ValidatedJson<UpdateMeusVideoSchema>,
+    data: Data<AppState>
+) -> Result<HttpResponse, ApiError> {
+    let submission = update_submission(&data.db, path.into_inner(), &body)
+        .await?
+        .ok_or_else(|| ApiError::not_found("Submission"))?;
+
+    Ok(HttpResponse::Ok().json(json!({"status": "success", "submission": submission})))
+}
+
+/// Remove um envio
+#[utoipa::path(
+    tag = "submissions",
+    responses(
+        (status = 204, description = "Registro removido"),
+        (status = 404, description = "Registro não encontrado", body = ErrorBody),
+    )
+)]
+#[delete("/submissions/{id}")]
+async fn delete_submission_by_id(
+    path: Path<Uuid>,
+    data: Data<AppState>
+) -> Result<HttpResponse, ApiError> {
+    if !delete_submission(&data.db, path.into_inner()).await? {
+        return Err(ApiError::not_found("Submission"));
+    }
+
+    Ok(HttpResponse::NoContent().finish())
+}
+
+pub fn config_submissions(conf: &mut ServiceConfig) {
+    conf.service(create_submission)
+       .service(get_all_submissions)
+       .service(get_student_submissions)
+       .service(get_submission_by_id)
+       .service(update_submission_by_id)
+       .service(delete_submission_by_id);
+}
@@ -0,0 +1,226 @@
+//backend/src/services/video/uploads.rs
+use actix_multipart::Multipart;
+use actix_web::{
+    post,
+    web::{Data, Path, ServiceConfig},
+    HttpResponse,
+};
+use app_config::StorageConfig;
+use futures_util::StreamExt;
+use serde::Deserialize;
+use serde_json::json;
+use tokio::fs::{self, File, OpenOptions};
+use tokio::io::AsyncWriteExt;
+use utoipa::ToSchema;
+use uuid::Uuid;
+use validator::Validate;
+use crate::{error::ApiError, outbox, repo::VideoRepo, request_id::RequestId, storage, validation::ValidatedJson, AppState};
+
+// Metadados enviados ao finalizar um upload em pedaços
+#[derive(Deserialize, ToSchema, Validate)]
+pub struct FinishUpload {
+    #[validate(range(min = 1))]
+    #[schema(minimum = 1)]
+    total_chunks: usize,
+}
+
+/// Salva o primeiro arquivo do multipart em `storage.uploads_dir` e devolve a URL pública.
+/// Compartilhado com os aliases antigos (`POST /upload`, `POST /all_videos/upload`).
+pub(super) async fn save_upload(mut payload: Multipart, storage_config: &StorageConfig) -> Result<Option<String>, ApiError> {
+    fs::create_dir_all(&storage_config.uploads_dir).await?;
+
+    let mut field = match payload.next().await {
+        Some(field) => field?,
+        None => return Ok(None),
+    };
+
+    let filename = storage::sanitize_filename(
+        field.content_disposition().get_filename().unwrap_or("default_filename"),
+    );
+    let filepath = format!("{}/{}", storage_config.uploads_dir, filename);
+    storage::save_field(&mut field, &filepath, storage_config.max_upload_bytes).await?;
+
+    Ok(Some(format!("/uploads/{}", filename)))
+}
+
+/// Grava um pedaço do arquivo de vídeo
+async fn save_chunk(
+    video_id: Uuid,
+    chunk_index: usize,
+    mut payload: Multipart,
+    storage_config: &StorageConfig,
+) -> Result<(), ApiError> {
+    fs::create_dir_all(&storage_config.chunks_dir).await?;
+
+    let file_path = format!("{}/{}_chunk_{}", storage_config.chunks_dir, video_id, chunk_index);
+    let mut f = OpenOptions::new().create(true).write(true).truncate(true).open(&file_path).await?;
+
+    let mut written = 0;
+    while let Some(field) = payload.next().await {
+        let mut field = field?;
+        if let Err(error) = storage::copy_field(&mut field, &mut f, &mut written, storage_config.max_chunk_bytes).await {
+            drop(f);
+            let _ = fs::remove_file(&file_path).await;
+            return Err(error);
+        }
+    }
+
+    Ok(())
+}
+
+fn chunk_path(storage_config: &StorageConfig, video_id: Uuid, index: usize) -> String {
+    format!("{}/{}_chunk_{}", storage_config.chunks_dir, video_id, index)
+}
+
+/// Junta os pedaços em `{video_id}.mp4`. Os pedaços ficam no disco até
+/// `remove_chunks`, para que o cliente possa repetir a finalização se ela falhar.
+async fn consolidate_chunks(video_id: Uuid, total_chunks: usize, storage_config: &StorageConfig) -> Result<(), ApiError> {
+    let final_path = format!("{}/{}.mp4", storage_config.chunks_dir, video_id);
+    let chunk_path = |i: usize| chunk_path(storage_config, video_id, i);
+
+    for i in 0..total_chunks {
+        if fs::metadata(chunk_path(i)).await.is_err() {
+            return Err(ApiError::BadRequest(format!("Chunk {} was not uploaded", i)));
+        }
+    }
+
+    let mut final_file = File::create(&final_path).await?;
+    for i in 0..total_chunks {
+        let bytes = fs::read(chunk_path(i)).await?;
+        final_file.write_all(&bytes).await?;
+    }
+    final_file.flush().await?;
+
+    Ok(())
+}
+
+// Apaga os pedaços já consolidados; uma falha aqui só deixa lixo no disco
+async fn remove_chunks(video_id: Uuid, total_chunks: usize, storage_config: &StorageConfig) {
+    for i in 0..total_chunks {
+        if let Err(error) = fs::remove_file(chunk_path(storage_config, video_id, i)).await {
+            tracing::warn!(%video_id, chunk = i, error = %error, "failed to remove uploaded chunk");
+        }
+    }
+}
+
+// Pedaços só são aceitos para vídeos já cadastrados
+async fn ensure_video(data: &AppState, video_id: Uuid) -> Result<(), ApiError> {
+    if VideoRepo::exists(&data.db, video_id).await? {
+        Ok(())
+    } else {
+        Err(ApiError::not_found("Video"))
+    }
+}
+
+/// Recebe um pedaço do vídeo. Compartilhado com o alias `POST /upload_chunk/{id}/{chunk_index}`.
+pub(super) async fn receive_chunk(
+    data: &AppState,
+    video_id: Uuid,
+    chunk_index: usize,
+    payload: Multipart,
+) -> Result<(), ApiError> {
+    ensure_video(data, video_id).await?;
+    save_chunk(video_id, chunk_index, payload, &data.config.storage).await
+}
+
+/// Grava o job de transcodificação na outbox e consolida os pedaços do vídeo
+/// antes do commit; o relay o publica depois, com o arquivo já no disco (o
+/// worker em processo e o transcoder leem `{chunks_dir}/{video_id}.mp4`). Os
+/// pedaços só são apagados depois do commit, então uma finalização que falhe
+/// pode ser repetida. Compartilhado com o alias `POST /finish_upload/{id}`.
+pub(super) async fn finish_upload(
+    data: &AppState,
+    video_id: Uuid,
+    body: &FinishUpload,
+    request_id: &RequestId,
+) -> Result<(), ApiError> {
+    ensure_video(data, video_id).await?;
+
+    let job = json!({
+        "video_id": video_id,
+        "action": "transcode"
+    });
+    let mut tx = data.db.begin().await?;
+    outbox::enqueue(&mut tx, &data.config.amqp.routing_key, &job, Some(request_id.as_str())).await?;
+    consolidate_chunks(video_id, body.total_chunks, &data.config.storage).await?;
+    tx.commit().await?;
+    data.outbox.notify();
+
+    remove_chunks(video_id, body.total_chunks, &data.config.storage).await;
+
+    Ok(())
+}
+
+/// Envia um arquivo de vídeo inteiro
+#[utoipa::path(
+    tag = "videos",
+    request_body(content = FileUpload, content_type = "multipart/form-data"),
+    responses(
+        (status = 200, description = "Arquivo salvo", body = UploadResponse),
+        (status = 400, description = "Requisição inválida", body = ErrorBody),
+        (status = 413, description = "Arquivo maior que o limite configurado", body = ErrorBody),
+    )
+)]
+#[post("/videos/uploads")]
+async fn upload_video_file(payload: Multipart, data: Data<AppState>) -> Result<HttpResponse, ApiError> {
+    let file_url = save_upload(payload, &data.config.storage)
+        .await?
+        .ok_or_else(|| ApiError::BadRequest("No file in request".to_string()))?;
+
+    Ok(HttpResponse::Ok().json(json!({
+        "status": "success",
+        "message": "File uploaded successfully.",
+        "file_url": file_url
+    })))
+}
+
+/// Envia um pedaço do arquivo de um vídeo
+#[utoipa::path(
+    tag = "videos",
+    request_body(content = FileUpload, content_type = "multipart/form-data"),
+    responses(
+        (status = 200, description = "Pedaço salvo", body = ChunkResponse),
+        (status = 400, description = "Requisição inválida", body = ErrorBody),
+        (status = 404, description = "Registro não encontrado", body = ErrorBody),
+        (status = 413, description = "Pedaço maior que o limite configurado", body = ErrorBody),
+    )
+)]
+#[post("/videos/{id}/upload/chunks/{chunk_index}")]
+async fn upload_video_chunk(
+    path: Path<(Uuid, usize)>,
+    payload: Multipart,
+    data: Data<AppState>,
+) -> Result<HttpResponse, ApiError> {
+    let (video_id, chunk_index) = path.into_inner();
+    receive_chunk(&data, video_id, chunk_index, payload).await?;
+
+    Ok(HttpResponse::Ok().json(json!({ "status": "success", "chunk_index": chunk_index })))
+}
+
+/// Finaliza o upload em pedaços de um vídeo
+#[utoipa::path(
+    tag = "videos",
+    request_body = FinishUpload,
+    responses(
+        (status = 200, description = "Upload consolidado", body = StatusMessage),
+        (status = 400, description = "Requisição inválida", body = ErrorBody),
+        (status = 404, description = "Registro não encontrado", body = ErrorBody),
+    )
+)]
+#[post("/videos/{id}/upload/complete")]
+async fn complete_video_upload(
+    path: Path<Uuid>,
+    body: ValidatedJson<FinishUpload>,
+    data: Data<AppState>,
+    request_id: RequestId,
+) -> Result<HttpResponse, ApiError> {
+    finish_upload(&data, path.into_inner(), &body, &request_id).await?;
+
+    Ok(HttpResponse::Ok().json(json!({ "status": "success", "message": "Upload finalizado com sucesso" })))
+}
+
+pub fn config_uploads(conf: &mut ServiceConfig) {
+    conf.service(upload_video_file)
+       .service(upload_video_chunk)
+       .service(complete_video_upload);
+}
@@ -0,0 +1,270 @@
+//backend/src/services/video/videos.rs
+use actix_web::{
+    get, post, delete, patch,
+    web::{Data, Query, Path, ServiceConfig},
+    HttpResponse,
+};
+use crate::{
+    validation::ValidatedJson,
+    error::ApiError,
+    pagination::{ListParams, ListQuery},
+    models::VideoModel,
+    schema::{CreateVideoSchema, UpdateVideoSchema, VideoSearchOptions},
+    repo::VideoRepo,
+    slug::{is_slug_conflict, unique_slug, SLUG_ATTEMPTS},
+    AppState,
+};
+use serde_json::json;
+use uuid::Uuid;
+
+/// Cria o vídeo com um slug único. O job de transcodificação só é gravado quando
+/// o upload termina (`uploads::finish_upload`), já com o arquivo no disco.
+pub(super) async fn insert_video(body: &CreateVideoSchema, data: &AppState) -> Result<VideoModel, ApiError> {
+    // O slug é gerado a partir do título (ou do slug informado) e sempre normalizado
+    let slug_source = body.slug.as_deref().unwrap_or(&body.title);
+
+    let mut attempt = 1;
+    loop {
+        let mut tx = data.db.begin().await?;
+        let slug = unique_slug(&mut tx, slug_source, None).await?;
+        let video = match VideoRepo::create(&mut tx, body, &slug).await {
+            Ok(video) => video,
+            // Um vídeo simultâneo levou o slug; a nova tentativa escolhe o próximo sufixo
+            Err(error) if is_slug_conflict(&error) && attempt < SLUG_ATTEMPTS => {
+                attempt += 1;
+                continue;
+            }
+            Err(error) => return Err(error.into()),
+        };
+
+        tx.commit().await?;
+        return Ok(video);
+    }
+}
+
+#[utoipa::path(
+    tag = "videos",
+    request_body = CreateVideoSchema,
+    responses(
+        (status = 201, description = "Registro criado", body = VideoResponse),
+        (status = 400, description = "Requisição inválida", body = ErrorBody),
+        (status = 409, description = "Conflito com um registro existente", body = ErrorBody),
+        (status = 422, description = "Referência a um registro inexistente", body = ErrorBody),
+    )
+)]
+#[post("/videos")]
+async fn create_video(
+    body: ValidatedJson<CreateVideoSchema>,
+    data: Data<AppState>,
+) -> Result<HttpResponse, ApiError> {
+    let video = insert_video(&body, &data).await?;
+
+    Ok(HttpResponse::Created().json(json!({"status": "success", "video": video})))
+}
+
+// Handler para obter todos os vídeos
+#[utoipa::path(
+    tag = "videos",
+    params(
+        ListParams,
+        ("author_id" = Option<Uuid>, Query, description = "Filtra por `author_id`"),
+        ("is_published" = Option<bool>, Query, description = "Filtra por `is_published`"),
+    ),
+    responses(
+        (status = 200, description = "Página de resultados", body = VideosPage),
+        (status = 400, description = "Requisição inválida", body = ErrorBody),
+    )
+)]
+#[get("/videos")]
+async fn get_all_videos(
+    query: ListQuery,
+    data: Data<AppState>
+) -> Result<HttpResponse, ApiError> {
+    let page = VideoRepo::list(&data.db, &query).await?;
+
+    Ok(HttpResponse::Ok().json(page.to_json("videos")))
+}
+
+// Handler de busca textual (título, descrição e nomes das tags), ordenada por relevância
+#[utoipa::path(
+    tag = "videos",
+    params(VideoSearchOptions),
+    responses(
+        (status = 200, description = "Vídeos encontrados, por relevância", body = VideoSearchResponse),
+        (status = 400, description = "Requisição inválida", body = ErrorBody),
+    )
+)]
+#[get("/videos/search")]
+async fn search_videos(
+    opts: Query<VideoSearchOptions>,
+    data: Data<AppState>
+) -> Result<HttpResponse, ApiError> {
+    let q = opts.q.trim();
+    if q.is_empty() {
+        return Err(ApiError::BadRequest("Query parameter 'q' must not be empty".to_string()));
+    }
+
+    let limit = opts.limit.unwrap_or(10).min(100);
+    let offset = (opts.page.unwrap_or(1).max(1) - 1)
+        .checked_mul(limit)
+        .and_then(|offset| i64::try_from(offset).ok())
+        .ok_or_else(|| ApiError::BadRequest("Query parameter 'page' is too large".to_string()))?;
+
+    let results = VideoRepo::search(&data.db, q, &opts, limit as i64, offset).await?;
+
+    Ok(HttpResponse::Ok().json(json!({
+        "status": "success",
+        "results": results.len(),
+        "videos": results
+    })))
+}
+
+// Handler para obter um vídeo por ID
+#[utoipa::path(
+    tag = "videos",
+    responses(
+        (status = 200, description = "Registro encontrado", body = VideoResponse),
+        (status = 404, description = "Registro não encontrado", body = ErrorBody),
+    )
+)]
+#[get("/videos/{id}")]
+async fn get_video_by_id(
+    path: Path<Uuid>,
+    data: Data<AppState>
+) -> Result<HttpResponse, ApiError> {
+    let video_id = path.into_inner();
+
+    let video = VideoRepo::find(&data.db, video_id)
+        .await?
+        .ok_or_else(|| ApiError::not_found("Video"))?;
+
+    Ok(HttpResponse::Ok().json(json!({"status": "success", "video": video})))
+}
+
+// Handler para obter um vídeo pelo slug (slugs antigos redirecionam para o atual)
+#[utoipa::path(
+    tag = "videos",
+    responses(
+        (status = 200, description = "Registro encontrado", body = VideoResponse),
+        (status = 301, description = "Slug antigo; `Location` aponta para o atual", body = SlugRedirect),
+        (status = 404, description = "Registro não encontrado", body = ErrorBody),
+    )
+)]
+#[get("/videos/by-slug/{slug}")]
+async fn get_video_by_slug(
+    path: Path<String>,
+    data: Data<AppState>
+) -> Result<HttpResponse, ApiError> {
+    let slug = path.into_inner();
+
+    if let Some(video) = VideoRepo::find_by_slug(&data.db, &slug).await? {
+        return Ok(HttpResponse::Ok().json(json!({"status": "success", "video": video})));
+    }
+
+    let current_slug = VideoRepo::current_slug(&data.db, &slug)
+        .await?
+        .ok_or_else(|| ApiError::not_found("Video"))?;
+
+    Ok(HttpResponse::MovedPermanently()
+        .insert_header(("Location", format!("/api/videos/by-slug/{}", current_slug)))
+        .json(json!({"status": "success", "slug": current_slug})))
+}
+
+// Handler para atualizar um vídeo por ID
+#[utoipa::path(
+    tag = "videos",
+    request_body = UpdateVideoSchema,
+    responses(
+        (status = 200, description = "Registro atualizado", body = VideoResponse),
+        (status = 400, description = "Requisição inválida", body = ErrorBody),
+        (status = 404, description = "Registro não encontrado", body = ErrorBody),
+        (status = 409, description = "Conflito com um registro existente", body = ErrorBody),
+        (status = 422, description = "Referência a um registro inexistente", body = ErrorBody),
+    )
+)]
+#[patch("/videos/{id}")]
+async fn update_video_by_id(
+    path: Path<Uuid>,
+    body: ValidatedJson<UpdateVideoSchema>,
+    data: Data<AppState>
+) -> Result<HttpResponse, ApiError> {
+    let video_id = path.into_inner();
+
+    let current = VideoRepo::find(&data.db, video_id)
+        .await?
+        .ok_or_else(|| ApiError::not_found("Video"))?;
+
+    // Slug informado explicitamente tem prioridade; senão, um novo título gera um novo slug
+    let title_changed = body.title.as_deref().is_some_and(|t| t != current.title);
+    let slug_source = match (&body.slug, &body.title) {
+        (Some(slug), _) => Some(slug.as_str()),
+        (None, Some(title)) if title_changed => Some(title.as_str()),
+        _ => None,
+    };
+
+    let mut attempt = 1;
+    let updated_video = loop {
+        let mut tx = data.db.begin().await?;
+        let new_slug = match slug_source {
+            Some(source) => Some(unique_slug(&mut tx, source, Some(video_id)).await?),
+            None => None,
+        };
+
+        // O slug antigo continua válido como redirecionamento para o vídeo
+        if let (Some(old_slug), Some(new_slug)) = (&current.slug, &new_slug) {
+            if old_slug != new_slug {
+                VideoRepo::redirect_slug(&mut tx, old_slug, video_id).await?;
+            }
+        }
+
+        // Um slug reaproveitado deixa de ser redirecionamento
+        if let Some(new_slug) = &new_slug {
+            VideoRepo::release_slug(&mut tx, new_slug).await?;
+        }
+
+        match VideoRepo::update(&mut tx, video_id, &body, new_slug.as_deref()).await {
+            Ok(updated) => {
+                let updated = updated.ok_or_else(|| ApiError::not_found("Video"))?;
+                tx.commit().await?;
+                break updated;
+            }
+            Err(error) if is_slug_conflict(&error) && attempt < SLUG_ATTEMPTS => attempt += 1,
+            Err(error) => return Err(error.into()),
+        }
+    };
+
+    Ok(HttpResponse::Ok().json(json!({"status": "success", "video": updated_video})))
+}
+
+// Handler para deletar um vídeo por ID
+#[utoipa::path(
+    tag = "videos",
+    responses(
+        (status = 204, description = "Registro removido"),
+        (status = 404, description = "Registro não encontrado", body = ErrorBody),
+    )
+)]
+#[delete("/videos/{id}")]
+async fn delete_video_by_id(
+    path: Path<Uuid>,
+    data: Data<AppState>
+) -> Result<HttpResponse, ApiError> {
+    let video_id = path.into_inner();
+
+    if !VideoRepo::delete(&data.db, video_id).await? {
+        return Err(ApiError::not_found("Video"));
+    }
+
+    Ok(HttpResponse::NoContent().finish())
+}
+
+// Configuração dos serviços de vídeo
+pub fn config_videos(conf: &mut ServiceConfig) {
+    conf.service(create_video)
+       .service(get_all_videos)
+       .service(search_videos)
+       .service(get_video_by_slug)
+       .service(get_video_by_id)
+       .service(update_video_by_id)
+       .service(delete_video_by_id);
+}
@@ -1,99 +1,114 @@
 use actix_web::{
-    get, post, delete, patch, web::{Data, Json, Path, ServiceConfig}, HttpResponse, Responder
+    get, post, put, delete, patch, web::{Data, Json, Path, ServiceConfig}, HttpResponse
 };
 use serde_json::json;
 use uuid::Uuid;
 use crate::{
-    models::VideoTagModel,
-    schema::{CreateVideoTagSchema, UpdateVideoTagSchema, FilterOptions},
+    validation::ValidatedJson,
+    error::ApiError,
+    models::{TagModel, VideoTagModel},
+    schema::{CreateVideoTagSchema, UpdateVideoTagSchema, SetVideoTagsSchema},
+    repo::VideoRepo,
     AppState
 };
 
 /// Função para criar um nova tag
+#[utoipa::path(
+    tag = "tags",
+    request_body = CreateVideoTagSchema,
+    responses(
+        (status = 200, description = "Registro criado", body = VideotagResponse),
+        (status = 400, description = "Requisição inválida", body = ErrorBody),
+        (status = 409, description = "Conflito com um registro existente", body = ErrorBody),
+        (status = 422, description = "Referência a um registro inexistente", body = ErrorBody),
+    )
+)]
 #[post("/videotags")]
 pub async fn create_videotag(
     body: Json<CreateVideoTagSchema>,
     data: Data<AppState>,
-) -> impl Responder {
+) -> Result<HttpResponse, ApiError> {
     let query = r#"
         INSERT INTO video_tags (video_id, tag_id)
         VALUES ($1, $2)
         RETURNING video_id, tag_id
     "#;
 
-    match sqlx::query_as::<_, VideoTagModel>(query)
-        .bind(&body.video_id)
-        .bind(&body.tag_id)
+    let videotag = sqlx::query_as::<_, VideoTagModel>(query)
+        .bind(body.video_id)
+        .bind(body.tag_id)
         .fetch_one(&data.db)
-        .await
-    {
-        Ok(videotag) => {
-            let response = json!({
-                "status": "sucesso",
-                "videotag": {
-                    "video_id": videotag.video_id,
-                    "tag_id": videotag.tag_id,
-                }
-            });
-            HttpResponse::Ok().json(response)
-        }
-        Err(error) => {
-            let response = json!({
-                "status": "erro",
-                "mensagem": format!("Falha ao criar videotag: {:?}", error),
-            });
-            HttpResponse::InternalServerError().json(response)
-        }
-    }
+        .await?;
+
+    Ok(HttpResponse::Ok().json(json!({
+        "status": "success",
+        "videotag": videotag
+    })))
 }
 
 /// Rota para obter todas as tags
+#[utoipa::path(
+    tag = "tags",
+    responses(
+        (status = 200, description = "Associações vídeo-tag", body = VideotagList),
+    )
+)]
 #[get("/videotags")]
-async fn get_all_videotags(data: Data<AppState>) -> impl Responder {
+async fn get_all_videotags(data: Data<AppState>) -> Result<HttpResponse, ApiError> {
     let query = "SELECT * FROM video_tags ORDER BY video_id";
 
-    match sqlx::query_as::<_, VideoTagModel>(query).fetch_all(&data.db).await {
-        Ok(videotags) => HttpResponse::Ok().json(json!({
-            "status": "success",
-            "videotags": videotags
-        })),
-        Err(error) => HttpResponse::InternalServerError().json(json!({
-            "status": "error",
-            "message": format!("Failed to get videotags: {:?}", error)
-        })),
-    }
+    let videotags = sqlx::query_as::<_, VideoTagModel>(query).fetch_all(&data.db).await?;
+
+    Ok(HttpResponse::Ok().json(json!({
+        "status": "success",
+        "videotags": videotags
+    })))
 }
 
 /// Rota para obter uma videotag por video_id
+#[utoipa::path(
+    tag = "tags",
+    responses(
+        (status = 200, description = "Registro encontrado", body = VideotagResponse),
+        (status = 404, description = "Registro não encontrado", body = ErrorBody),
+    )
+)]
 #[get("/videotags/{id}")]
 async fn get_videotag_by_id(
     path: Path<Uuid>,
     data: Data<AppState>
-) -> impl Responder {
+) -> Result<HttpResponse, ApiError> {
     let videotag_id = path.into_inner();
 
-    match sqlx::query_as!(VideoTagModel, "SELECT * FROM video_tags WHERE video_id = $1", videotag_id)
-        .fetch_one(&data.db)
-        .await
-    {
-        Ok(videotag) => HttpResponse::Ok().json(json!({
-            "status": "success",
-            "videotag": videotag
-        })),
-        Err(error) => HttpResponse::InternalServerError().json(json!({
-            "status": "error",
-            "message": format!("Failed to get videotag: {:?}", error)
-        })),
-    }
+    let videotag = sqlx::query_as!(VideoTagModel, "SELECT * FROM video_tags WHERE video_id = $1", videotag_id)
+        .fetch_optional(&data.db)
+        .await?
+        .ok_or_else(|| ApiError::not_found("Videotag"))?;
+
+    Ok(HttpResponse::Ok().json(json!({
+        "status": "success",
+        "videotag": videotag
+    })))
 }
 
 /// Rota para atualizar uma tag por ID
+#[utoipa::path(
+    tag = "tags",
+    request_body = UpdateVideoTagSchema,
+    responses(
+        (status = 200, description = "Registro atualizado", body = VideotagResponse),
+        (status = 400, description = "Requisição inválida", body = ErrorBody),
+        (status = 404, description = "Registro não encontrado", body = ErrorBody),
+        (status = 409, description = "Conflito com um registro existente", body = ErrorBody),
+        (status = 422, description = "Referência a um registro inexistente", body = ErrorBody),
+    )
+)]
 #[patch("/videotags/{id}")]
 async fn update_videotag_by_id(
     path: Path<Uuid>,
     body: Json<UpdateVideoTagSchema>,
     data: Data<AppState>
-) -> impl Responder {
+) -> Result<HttpResponse, ApiError> {
     let videotag_id = path.into_inner();
 
     let query = r#"
@@ -101,41 +116,161 @@ async fn update_videotag_by_id(
         WHERE video_id = $2 RETURNING *
     "#;
 
-    match sqlx::query_as::<_, VideoTagModel>(query)
-        .bind(&body.tag_id)
+    let updated_videotag = sqlx::query_as::<_, VideoTagModel>(query)
+        .bind(body.tag_id)
         .bind(videotag_id)
-        .fetch_one(&data.db)
-        .await
-    {
-        Ok(updated_videotag) => HttpResponse::Ok().json(json!({
-            "status": "success",
-            "videotag": updated_videotag
-        })),
-        Err(error) => HttpResponse::InternalServerError().json(json!({
-            "status": "error",
-            "message": format!("Failed to update videotag: {:?}", error)
-        })),
-    }
+        .fetch_optional(&data.db)
+        .await?
+        .ok_or_else(|| ApiError::not_found("Videotag"))?;
+
+    Ok(HttpResponse::Ok().json(json!({
+        "status": "success",
+        "videotag": updated_videotag
+    })))
 }
 
-/// Rota para deletar uma videotag por video_id
-#[delete("/tags/{id}")]
+/// Rota para remover uma tag de um vídeo
+#[utoipa::path(
+    tag = "tags",
+    responses(
+        (status = 204, description = "Registro removido"),
+        (status = 404, description = "Registro não encontrado", body = ErrorBody),
+    )
+)]
+#[delete("/videotags/{video_id}/{tag_id}")]
 async fn delete_videotag_by_id(
-    path: Path<Uuid>,
+    path: Path<(Uuid, Uuid)>,
     data: Data<AppState>
-) -> impl Responder {
-    let videotag_id = path.into_inner();
+) -> Result<HttpResponse, ApiError> {
+    let (video_id, tag_id) = path.into_inner();
 
-    match sqlx::query!("DELETE FROM video_tags WHERE video_id = $1", videotag_id)
+    let result = sqlx::query("DELETE FROM video_tags WHERE video_id = $1 AND tag_id = $2")
+        .bind(video_id)
+        .bind(tag_id)
         .execute(&data.db)
-        .await
-    {
-        Ok(_) => HttpResponse::NoContent().finish(),
-        Err(error) => HttpResponse::InternalServerError().json(json!({
-            "status": "error",
-            "message": format!("Failed to delete videotag: {:?}", error)
-        })),
+        .await?;
+
+    if result.rows_affected() == 0 {
+        return Err(ApiError::not_found("Videotag"));
+    }
+
+    Ok(HttpResponse::NoContent().finish())
+}
+
+/// Rota para listar as tags de um vídeo
+#[utoipa::path(
+    tag = "tags",
+    responses(
+        (status = 200, description = "Tags do vídeo", body = TagList),
+        (status = 404, description = "Registro não encontrado", body = ErrorBody),
+    )
+)]
+#[get("/videos/{id}/tags")]
+async fn get_video_tags(
+    path: Path<Uuid>,
+    data: Data<AppState>
+) -> Result<HttpResponse, ApiError> {
+    let video_id = path.into_inner();
+    if !VideoRepo::exists(&data.db, video_id).await? {
+        return Err(ApiError::not_found("Video"));
+    }
+
+    let query = r#"
+        SELECT t.id, t.name
+        FROM tags t
+        JOIN video_tags vt ON vt.tag_id = t.id
+        WHERE vt.video_id = $1
+        ORDER BY t.name
+    "#;
+
+    let tags = sqlx::query_as::<_, TagModel>(query)
+        .bind(video_id)
+        .fetch_all(&data.db)
+        .await?;
+
+    Ok(HttpResponse::Ok().json(json!({
+        "status": "success",
+        "tags": tags
+    })))
+}
+
+/// Rota para definir o conjunto completo de tags de um vídeo, criando as que não existem
+#[utoipa::path(
+    tag = "tags",
+    request_body = SetVideoTagsSchema,
+    responses(
+        (status = 200, description = "Tags do vídeo após a troca", body = TagList),
+        (status = 400, description = "Requisição inválida", body = ErrorBody),
+        (status = 404, description = "Registro não encontrado", body = ErrorBody),
+        (status = 409, description = "Conflito com um registro existente", body = ErrorBody),
+        (status = 422, description = "Referência a um registro inexistente", body = ErrorBody),
+    )
+)]
+#[put("/videos/{id}/tags")]
+async fn set_video_tags(
+    path: Path<Uuid>,
+    body: ValidatedJson<SetVideoTagsSchema>,
+    data: Data<AppState>
+) -> Result<HttpResponse, ApiError> {
+    let video_id = path.into_inner();
+
+    // Normaliza os nomes: sem espaços nas pontas, sem vazios e sem duplicatas (ignorando maiúsculas)
+    let mut names: Vec<String> = Vec::new();
+    for name in body.tags.iter().map(|n| n.trim()).filter(|n| !n.is_empty()) {
+        if !names.iter().any(|n| n.to_lowercase() == name.to_lowercase()) {
+            names.push(name.to_string());
+        }
+    }
+    let lowered: Vec<String> = names.iter().map(|n| n.to_lowercase()).collect();
+
+    let mut tx = data.db.begin().await?;
+
+    if !VideoRepo::exists(&mut tx, video_id).await? {
+        return Err(ApiError::not_found("Video"));
     }
+
+    let create_query = r#"
+        INSERT INTO tags (name)
+        SELECT n FROM unnest($1::text[]) AS n
+        WHERE NOT EXISTS (SELECT 1 FROM tags WHERE lower(tags.name) = lower(n))
+        ON CONFLICT (name) DO NOTHING
+    "#;
+
+    sqlx::query(create_query)
+        .bind(&names)
+        .execute(&mut tx)
+        .await?;
+
+    let tags = sqlx::query_as::<_, TagModel>("SELECT id, name FROM tags WHERE lower(name) = ANY($1) ORDER BY name")
+        .bind(&lowered)
+        .fetch_all(&mut tx)
+        .await?;
+    let tag_ids: Vec<Uuid> = tags.iter().map(|t| t.id).collect();
+
+    sqlx::query("DELETE FROM video_tags WHERE video_id = $1 AND NOT (tag_id = ANY($2))")
+        .bind(video_id)
+        .bind(&tag_ids)
+        .execute(&mut tx)
+        .await?;
+
+    let link_query = r#"
+        INSERT INTO video_tags (video_id, tag_id)
+        SELECT $1, unnest($2::uuid[])
+        ON CONFLICT DO NOTHING
+    "#;
+
+    sqlx::query(link_query)
+        .bind(video_id)
+        .bind(&tag_ids)
+        .execute(&mut tx)
+        .await?;
+
+    tx.commit().await?;
+
+    Ok(HttpResponse::Ok().json(json!({
+        "status": "success",
+        "tags": tags
+    })))
 }
 
 /// Configuração das rotas de videotag
@@ -144,6 +279,7 @@ pub fn config_videotags(conf: &mut ServiceConfig) {
        .service(get_all_videotags)
        .service(get_videotag_by_id)
        .service(update_videotag_by_id)
-       .service(delete_videotag_by_id);
+       .service(delete_videotag_by_id)
+       .service(get_video_tags)
+       .service(set_video_tags);
 }
-
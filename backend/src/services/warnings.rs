@@ -1,114 +1,149 @@
 //backend/src/services/warnings.rs
 use actix_web::{
     get, post, delete, patch,
-    web::{Data, Json, Path, Query, ServiceConfig},
-    HttpResponse, Responder
+    web::{Data, Path, ServiceConfig},
+    HttpResponse,
 };
 use serde_json::json;
 use uuid::Uuid;
 use crate::{
+    audit::{self, Actor, Entity},
+    validation::ValidatedJson,
     AppState,
+    error::ApiError,
+    pagination::{Column, ListParams, ListQuery, ListSpec, SqlType, fetch_page},
     models::WarningModel,
-    schema::{CreateWarningSchema, UpdateWarningSchema, FilterOptions},
+    schema::{CreateWarningSchema, UpdateWarningSchema},
 };
 
 /// Criar advertência
+#[utoipa::path(
+    tag = "warnings",
+    request_body = CreateWarningSchema,
+    responses(
+        (status = 201, description = "Registro criado", body = WarningResponse),
+        (status = 400, description = "Requisição inválida", body = ErrorBody),
+        (status = 409, description = "Conflito com um registro existente", body = ErrorBody),
+        (status = 422, description = "Referência a um registro inexistente", body = ErrorBody),
+    )
+)]
 #[post("/warnings")]
 async fn create_warning(
     data: Data<AppState>,
-    body: Json<CreateWarningSchema>
-) -> impl Responder {
+    actor: Actor,
+    body: ValidatedJson<CreateWarningSchema>
+) -> Result<HttpResponse, ApiError> {
     let query = r#"
         INSERT INTO warnings (student_id, reason)
         VALUES ($1, $2)
         RETURNING id, student_id, reason, warning_date
     "#;
 
-    match sqlx::query_as::<_, WarningModel>(query)
+    let mut tx = data.db.begin().await?;
+    let warning = sqlx::query_as::<_, WarningModel>(query)
         .bind(body.student_id)
         .bind(&body.reason)
-        .fetch_one(&data.db)
-        .await
-    {
-        Ok(warning) => HttpResponse::Created().json(json!({
-            "status": "success",
-            "warning": warning
-        })),
-        Err(e) => HttpResponse::InternalServerError().json(json!({
-            "status": "error",
-            "message": format!("Failed to create warning: {:?}", e)
-        })),
-    }
+        .fetch_one(&mut tx)
+        .await?;
+
+    audit::created(&mut tx, &actor, Entity::Warning, warning.id).await?;
+    tx.commit().await?;
+
+    Ok(HttpResponse::Created().json(json!({
+        "status": "success",
+        "warning": warning
+    })))
 }
 
+const WARNING_LIST: ListSpec = ListSpec {
+    from: "warnings",
+    select: "id, student_id, reason, warning_date",
+    id: "id",
+    sorts: &[
+        Column::new("id", "id", SqlType::Uuid),
+        Column::new("warning_date", "COALESCE(warning_date, 'epoch')", SqlType::TimestampTz),
+    ],
+    default_sort: "-warning_date",
+    filters: &[Column::new("student_id", "student_id", SqlType::Uuid)],
+};
+
 /// Listar advertências
+#[utoipa::path(
+    tag = "warnings",
+    params(
+        ListParams,
+        ("student_id" = Option<Uuid>, Query, description = "Filtra por `student_id`"),
+    ),
+    responses(
+        (status = 200, description = "Página de resultados", body = WarningsPage),
+        (status = 400, description = "Requisição inválida", body = ErrorBody),
+    )
+)]
 #[get("/warnings")]
 async fn get_all_warnings(
-    data: Data<AppState>,
-    opts: Query<FilterOptions>
-) -> impl Responder {
-    let limit = opts.limit.unwrap_or(10);
-    let offset = (opts.page.unwrap_or(1) - 1) * limit;
-
-    let query = r#"
-        SELECT id, student_id, reason, warning_date
-        FROM warnings
-        ORDER BY warning_date DESC
-        LIMIT $1 OFFSET $2
-    "#;
+    query: ListQuery,
+    data: Data<AppState>
+) -> Result<HttpResponse, ApiError> {
+    let page = fetch_page::<WarningModel>(&data.db, &WARNING_LIST, &query).await?;
 
-    match sqlx::query_as::<_, WarningModel>(query)
-        .bind(limit as i64)
-        .bind(offset as i64)
-        .fetch_all(&data.db)
-        .await
-    {
-        Ok(warnings) => HttpResponse::Ok().json(json!({
-            "status": "success",
-            "warnings": warnings
-        })),
-        Err(e) => HttpResponse::InternalServerError().json(json!({
-            "status": "error",
-            "message": format!("Failed to get warnings: {:?}", e)
-        })),
-    }
+    Ok(HttpResponse::Ok().json(page.to_json("warnings")))
 }
 
 /// Buscar advertência por ID
+#[utoipa::path(
+    tag = "warnings",
+    responses(
+        (status = 200, description = "Registro encontrado", body = WarningResponse),
+        (status = 404, description = "Registro não encontrado", body = ErrorBody),
+    )
+)]
 #[get("/warnings/{id}")]
 async fn get_warning_by_id(
     data: Data<AppState>,
     path: Path<Uuid>
-) -> impl Responder {
+) -> Result<HttpResponse, ApiError> {
     let id = path.into_inner();
 
     let query = "SELECT id, student_id, reason, warning_date FROM warnings WHERE id = $1";
 
-    match sqlx::query_as::<_, WarningModel>(query)
+    let warning = sqlx::query_as::<_, WarningModel>(query)
         .bind(id)
-        .fetch_one(&data.db)
-        .await
-    {
-        Ok(warning) => HttpResponse::Ok().json(json!({
-            "status": "success",
-            "warning": warning
-        })),
-        Err(e) => HttpResponse::NotFound().json(json!({
-            "status": "error",
-            "message": format!("Warning not found: {:?}", e)
-        })),
-    }
+        .fetch_optional(&data.db)
+        .await?
+        .ok_or_else(|| ApiError::not_found("Warning"))?;
+
+    Ok(HttpResponse::Ok().json(json!({
+        "status": "success",
+        "warning": warning
+    })))
 }
 
 /// Atualizar advertência
+#[utoipa::path(
+    tag = "warnings",
+    request_body = UpdateWarningSchema,
+    responses(
+        (status = 200, description = "Registro atualizado", body = WarningResponse),
+        (status = 400, description = "Requisição inválida", body = ErrorBody),
+        (status = 404, description = "Registro não encontrado", body = ErrorBody),
+        (status = 409, description = "Conflito com um registro existente", body = ErrorBody),
+        (status = 422, description = "Referência a um registro inexistente", body = ErrorBody),
+    )
+)]
 #[patch("/warnings/{id}")]
 async fn update_warning_by_id(
     data: Data<AppState>,
+    actor: Actor,
     path: Path<Uuid>,
-    body: Json<UpdateWarningSchema>
-) -> impl Responder {
+    body: ValidatedJson<UpdateWarningSchema>
+) -> Result<HttpResponse, ApiError> {
     let id = path.into_inner();
 
+    let mut tx = data.db.begin().await?;
+    let before = audit::snapshot(&mut tx, Entity::Warning, id)
+        .await?
+        .ok_or_else(|| ApiError::not_found("Warning"))?;
+
     let query = r#"
         UPDATE warnings
         SET student_id = COALESCE($1, student_id),
@@ -117,45 +152,55 @@ async fn update_warning_by_id(
         RETURNING id, student_id, reason, warning_date
     "#;
 
-    match sqlx::query_as::<_, WarningModel>(query)
+    let updated = sqlx::query_as::<_, WarningModel>(query)
         .bind(body.student_id)
         .bind(&body.reason)
         .bind(id)
-        .fetch_one(&data.db)
-        .await
-    {
-        Ok(updated) => HttpResponse::Ok().json(json!({
-            "status": "success",
-            "warning": updated
-        })),
-        Err(e) => HttpResponse::InternalServerError().json(json!({
-            "status": "error",
-            "message": format!("Failed to update warning: {:?}", e)
-        })),
-    }
+        .fetch_optional(&mut tx)
+        .await?
+        .ok_or_else(|| ApiError::not_found("Warning"))?;
+
+    audit::updated(&mut tx, &actor, Entity::Warning, id, before).await?;
+    tx.commit().await?;
+
+    Ok(HttpResponse::Ok().json(json!({
+        "status": "success",
+        "warning": updated
+    })))
 }
 
 /// Excluir advertência
+#[utoipa::path(
+    tag = "warnings",
+    responses(
+        (status = 204, description = "Registro removido"),
+        (status = 404, description = "Registro não encontrado", body = ErrorBody),
+    )
+)]
 #[delete("/warnings/{id}")]
 async fn delete_warning_by_id(
     data: Data<AppState>,
+    actor: Actor,
     path: Path<Uuid>
-) -> impl Responder {
+) -> Result<HttpResponse, ApiError> {
     let id = path.into_inner();
 
+    let mut tx = data.db.begin().await?;
+    let before = audit::snapshot(&mut tx, Entity::Warning, id)
+        .await?
+        .ok_or_else(|| ApiError::not_found("Warning"))?;
+
     let query = "DELETE FROM warnings WHERE id = $1";
 
-    match sqlx::query(query)
+    sqlx::query(query)
         .bind(id)
-        .execute(&data.db)
-        .await
-    {
-        Ok(_) => HttpResponse::NoContent().finish(),
-        Err(e) => HttpResponse::InternalServerError().json(json!({
-            "status": "error",
-            "message": format!("Failed to delete warning: {:?}", e)
-        })),
-    }
+        .execute(&mut tx)
+        .await?;
+
+    audit::deleted(&mut tx, &actor, Entity::Warning, id, before).await?;
+    tx.commit().await?;
+
+    Ok(HttpResponse::NoContent().finish())
 }
 
 /// Configuração de rotas
@@ -0,0 +1,146 @@
+// backend/src/slug.rs
+use sqlx::PgConnection;
+use uuid::Uuid;
+
+/// Tamanho máximo da coluna `videos.slug`
+pub const MAX_SLUG_LEN: usize = 100;
+/// Tentativas de gravar o vídeo quando outro pega o mesmo slug no meio do caminho
+pub const SLUG_ATTEMPTS: u32 = 3;
+
+/// Translitera acentos do português para ASCII (á -> a, ç -> c, ...)
+fn transliterate(c: char) -> Option<&'static str> {
+    let s = match c {
+        'á' | 'à' | 'â' | 'ã' | 'ä' | 'Á' | 'À' | 'Â' | 'Ã' | 'Ä' => "a",
+        'é' | 'è' | 'ê' | 'ë' | 'É' | 'È' | 'Ê' | 'Ë' => "e",
+        'í' | 'ì' | 'î' | 'ï' | 'Í' | 'Ì' | 'Î' | 'Ï' => "i",
+        'ó' | 'ò' | 'ô' | 'õ' | 'ö' | 'Ó' | 'Ò' | 'Ô' | 'Õ' | 'Ö' => "o",
+        'ú' | 'ù' | 'û' | 'ü' | 'Ú' | 'Ù' | 'Û' | 'Ü' => "u",
+        'ç' | 'Ç' => "c",
+        'ñ' | 'Ñ' => "n",
+        _ => return None,
+    };
+    Some(s)
+}
+
+/// Gera um slug a partir de um texto livre: "Treino de Goleiros!" -> "treino-de-goleiros"
+pub fn slugify(input: &str) -> String {
+    let mut slug = String::with_capacity(input.len());
+    let mut pending_dash = false;
+
+    for c in input.chars() {
+        let piece = if let Some(t) = transliterate(c) {
+            t.to_string()
+        } else if c.is_ascii_alphanumeric() {
+            c.to_ascii_lowercase().to_string()
+        } else {
+            pending_dash = true;
+            continue;
+        };
+
+        if pending_dash && !slug.is_empty() {
+            slug.push('-');
+        }
+        pending_dash = false;
+        slug.push_str(&piece);
+    }
+
+    // Respeita o limite da coluna sem terminar com hífen
+    slug.truncate(MAX_SLUG_LEN);
+    let slug = slug.trim_end_matches('-').to_string();
+
+    if slug.is_empty() {
+        "video".to_string()
+    } else {
+        slug
+    }
+}
+
+/// Verifica se o slug já está em uso por outro vídeo (atual ou redirecionamento)
+async fn slug_taken(conn: &mut PgConnection, slug: &str, video_id: Option<Uuid>) -> Result<bool, sqlx::Error> {
+    let query = r#"
+        SELECT EXISTS (
+            SELECT 1 FROM videos WHERE slug = $1 AND ($2::uuid IS NULL OR id <> $2)
+            UNION ALL
+            SELECT 1 FROM video_slug_redirects WHERE old_slug = $1 AND ($2::uuid IS NULL OR video_id <> $2)
+        )
+    "#;
+
+    sqlx::query_scalar::<_, bool>(query)
+        .bind(slug)
+        .bind(video_id)
+        .fetch_one(conn)
+        .await
+}
+
+/// Gera um slug único a partir de `source`, adicionando sufixos (-2, -3, ...) em caso de colisão.
+/// `video_id` exclui o próprio vídeo da verificação ao atualizar.
+///
+/// Rode na mesma transação do insert ou update: uma gravação simultânea com o
+/// mesmo slug ainda pode vencer a corrida, e aí o insert falha com
+/// `is_slug_conflict` e a transação inteira deve ser repetida.
+pub async fn unique_slug(
+    conn: &mut PgConnection,
+    source: &str,
+    video_id: Option<Uuid>,
+) -> Result<String, sqlx::Error> {
+    let base = slugify(source);
+
+    if !slug_taken(&mut *conn, &base, video_id).await? {
+        return Ok(base);
+    }
+
+    let mut n: u32 = 2;
+    loop {
+        let suffix = format!("-{}", n);
+        let mut candidate = base.clone();
+        candidate.truncate(MAX_SLUG_LEN - suffix.len());
+        let candidate = format!("{}{}", candidate.trim_end_matches('-'), suffix);
+
+        if !slug_taken(&mut *conn, &candidate, video_id).await? {
+            return Ok(candidate);
+        }
+        n += 1;
+    }
+}
+
+/// Se o erro é a violação de `videos_slug_key`: outro vídeo gravou o mesmo slug
+/// entre a verificação de `unique_slug` e o insert
+pub fn is_slug_conflict(error: &sqlx::Error) -> bool {
+    match error {
+        sqlx::Error::Database(db_error) => {
+            db_error.code().as_deref() == Some("23505") && db_error.constraint() == Some("videos_slug_key")
+        }
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn slugify_transliterates_accents() {
+        assert_eq!(slugify("Treino de Goleiros"), "treino-de-goleiros");
+        assert_eq!(slugify("Ação Ofensiva: Pênalti"), "acao-ofensiva-penalti");
+        assert_eq!(slugify("ÑANDÚ ÇÃO"), "nandu-cao");
+    }
+
+    #[test]
+    fn slugify_collapses_punctuation() {
+        assert_eq!(slugify("  Treino!!! -- de   passe?? "), "treino-de-passe");
+        assert_eq!(slugify("Sub-9 / 2025"), "sub-9-2025");
+    }
+
+    #[test]
+    fn slugify_falls_back_on_empty_input() {
+        assert_eq!(slugify(""), "video");
+        assert_eq!(slugify("!?#"), "video");
+    }
+
+    #[test]
+    fn slugify_respects_column_length() {
+        let slug = slugify(&"a b ".repeat(100));
+        assert!(slug.len() <= MAX_SLUG_LEN);
+        assert!(!slug.ends_with('-'));
+    }
+}
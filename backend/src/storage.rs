@@ -0,0 +1,60 @@
+// backend/src/storage.rs
+//! Gravação dos arquivos enviados por multipart, respeitando os limites de
+//! tamanho de `[storage]` na configuração.
+use actix_multipart::Field;
+use futures_util::StreamExt;
+use std::path::Path;
+use tokio::fs::{self, File};
+use tokio::io::{AsyncWrite, AsyncWriteExt};
+
+use crate::{error::ApiError, metrics::metrics};
+
+/// Mantém só o nome do arquivo, descartando qualquer diretório enviado pelo
+/// cliente (caminhos absolutos e `..` sairiam do diretório de destino)
+pub fn sanitize_filename(filename: &str) -> String {
+    let name = filename.rsplit(['/', '\\']).next().unwrap_or_default().trim();
+    if name.is_empty() || name == "." || name == ".." {
+        "default_filename".to_string()
+    } else {
+        name.to_string()
+    }
+}
+
+/// Copia o conteúdo de um campo para `out`, somando em `written` os bytes gravados.
+/// Falha com `PayloadTooLarge` assim que o total passa de `max_bytes`.
+pub async fn copy_field<W: AsyncWrite + Unpin>(
+    field: &mut Field,
+    out: &mut W,
+    written: &mut u64,
+    max_bytes: u64,
+) -> Result<(), ApiError> {
+    while let Some(chunk) = field.next().await {
+        let chunk = chunk?;
+        *written += chunk.len() as u64;
+        if *written > max_bytes {
+            return Err(ApiError::PayloadTooLarge(format!("File exceeds the limit of {} bytes", max_bytes)));
+        }
+        out.write_all(&chunk).await?;
+        metrics().upload_bytes.inc_by(chunk.len() as u64);
+    }
+    Ok(())
+}
+
+/// Grava um campo em `path`; o arquivo parcial é apagado se o envio falhar
+pub async fn save_field(field: &mut Field, path: impl AsRef<Path>, max_bytes: u64) -> Result<u64, ApiError> {
+    let path = path.as_ref();
+    let mut file = File::create(path).await?;
+    let mut written = 0;
+
+    let result = match copy_field(field, &mut file, &mut written, max_bytes).await {
+        Ok(()) => file.flush().await.map_err(ApiError::from),
+        Err(error) => Err(error),
+    };
+    if let Err(error) = result {
+        drop(file);
+        let _ = fs::remove_file(path).await;
+        return Err(error);
+    }
+
+    Ok(written)
+}
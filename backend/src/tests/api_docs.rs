@@ -0,0 +1,79 @@
+// backend/src/tests/api_docs.rs
+//! Documento OpenAPI e Swagger UI
+use actix_web::http::StatusCode;
+use serde_json::json;
+use uuid::Uuid;
+
+use super::test_app;
+
+#[actix_web::test]
+#[ignore = "needs TEST_DATABASE_URL"]
+async fn openapi_document_is_served() {
+    let app = test_app!();
+
+    let response = app.get("/api/openapi.json").await;
+    assert_eq!(response.status, StatusCode::OK, "{}", response.text);
+    let doc = &response.body;
+    assert!(doc["openapi"].as_str().is_some_and(|version| version.starts_with("3.")), "{}", doc["openapi"]);
+    assert_eq!(doc["servers"][0]["url"], "/api");
+
+    // Rotas dos handlers, com corpo de requisição e respostas de erro
+    let create_student = &doc["paths"]["/students"]["post"];
+    assert_eq!(
+        create_student["requestBody"]["content"]["application/json"]["schema"]["$ref"],
+        "#/components/schemas/CreateStudentSchema"
+    );
+    assert_eq!(
+        create_student["responses"]["400"]["content"]["application/json"]["schema"]["$ref"],
+        "#/components/schemas/ErrorBody"
+    );
+    assert!(doc["paths"]["/students/{id}"]["get"]["responses"]["404"].is_object());
+
+    let schemas = &doc["components"]["schemas"];
+    let required = &schemas["ErrorBody"]["required"];
+    for field in ["status", "code", "message"] {
+        assert!(required.as_array().unwrap().contains(&json!(field)), "{}", schemas["ErrorBody"]);
+    }
+    assert!(schemas["StudentModel"]["properties"]["name"].is_object());
+
+    let ui = app.get("/api/docs/").await;
+    assert_eq!(ui.status, StatusCode::OK);
+    assert!(ui.text.contains("swagger"), "{}", ui.text);
+}
+
+#[actix_web::test]
+#[ignore = "needs TEST_DATABASE_URL"]
+async fn error_responses_match_the_documented_envelope() {
+    let app = test_app!();
+    let doc = app.get("/api/openapi.json").await.body;
+    let documented: Vec<&str> = doc["components"]["schemas"]["ErrorBody"]["properties"]
+        .as_object()
+        .unwrap()
+        .keys()
+        .map(String::as_str)
+        .collect();
+
+    let invalid = app
+        .post("/api/students", &json!({"user_id": Uuid::new_v4(), "name": "", "email": "não é email", "age": 0}))
+        .await;
+    assert_eq!(invalid.status, StatusCode::BAD_REQUEST, "{}", invalid.body);
+    assert_eq!(invalid.body["status"], "error");
+    assert_eq!(invalid.body["code"], "validation_failed");
+    for key in invalid.body.as_object().unwrap().keys() {
+        assert!(documented.contains(&key.as_str()), "undocumented key {} in {}", key, invalid.body);
+    }
+    let codes: Vec<(&str, &str)> = invalid.body["errors"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|error| (error["field"].as_str().unwrap(), error["code"].as_str().unwrap()))
+        .collect();
+    assert!(codes.contains(&("email", "email")), "{:?}", codes);
+    assert!(codes.contains(&("age", "range")), "{:?}", codes);
+
+    let malformed = app.post("/api/students", &json!({"name": 1})).await;
+    assert_eq!(malformed.status, StatusCode::BAD_REQUEST, "{}", malformed.body);
+    assert_eq!(malformed.body["status"], "error");
+    assert!(malformed.body["code"].is_string());
+    assert!(malformed.body["message"].is_string());
+}
@@ -0,0 +1,159 @@
+// backend/src/tests/calendar_feeds.rs
+//! Feeds .ics das turmas e das famílias
+use actix_web::http::StatusCode;
+use chrono::{Datelike, Duration, Local, NaiveDate};
+use serde_json::json;
+use uuid::Uuid;
+
+use super::{create_user, test_app, TestApp};
+
+fn ics_time(date: NaiveDate, time: &str) -> String {
+    format!("{}T{}", date.format("%Y%m%d"), time)
+}
+
+// VEVENTs do calendário, com as linhas dobradas já desdobradas
+fn events(ics: &str) -> Vec<String> {
+    ics.replace("\r\n ", "")
+        .split("BEGIN:VEVENT\r\n")
+        .skip(1)
+        .map(str::to_string)
+        .collect()
+}
+
+// SEQUENCE do evento com o UID
+fn sequence(ics: &str, uid: &str) -> i64 {
+    let event = events(ics).into_iter().find(|event| event.contains(uid)).expect("event");
+    let line = event.lines().find_map(|line| line.strip_prefix("SEQUENCE:")).expect("SEQUENCE");
+    line.parse().unwrap()
+}
+
+// Turma com treino toda semana no dia da semana de hoje, às 18h
+async fn group_training_today(app: &TestApp, name: &str) -> (Uuid, Uuid) {
+    let today = Local::now().date_naive();
+    let group_id = app.create("/api/groups", "group", &json!({"name": name})).await;
+    let schedule_id = app
+        .create(&format!("/api/groups/{}/schedules", group_id), "schedule", &json!({
+            "weekday": today.weekday().number_from_monday(),
+            "start_time": "18:00:00",
+            "end_time": "19:30:00",
+            "location": "Campo 1; coberto",
+            "valid_from": today - Duration::days(14),
+        }))
+        .await;
+    (group_id, schedule_id)
+}
+
+#[actix_web::test]
+#[ignore = "needs TEST_DATABASE_URL"]
+async fn group_feed_keeps_uids_across_changes() {
+    let app = test_app!();
+    let today = Local::now().date_naive();
+    let next_week = today + Duration::weeks(1);
+    let (group_id, schedule_id) = group_training_today(&app, "Sub-9").await;
+
+    let created = app.post(&format!("/api/groups/{}/calendar_feed", group_id), &json!({})).await;
+    assert_eq!(created.status, StatusCode::CREATED, "{}", created.body);
+    let path = created.body["path"].as_str().unwrap().to_string();
+    let token = path.strip_prefix("/api/calendar/").and_then(|rest| rest.strip_suffix(".ics")).unwrap();
+    assert_eq!(token.len(), 64, "{}", path);
+    assert!(token.bytes().all(|b| b.is_ascii_hexdigit()), "{}", path);
+
+    let before = app.get(&path).await;
+    assert_eq!(before.status, StatusCode::OK);
+    assert_eq!(before.content_type.as_deref(), Some("text/calendar; charset=utf-8"));
+    assert!(before.text.starts_with("BEGIN:VCALENDAR\r\nVERSION:2.0\r\n"), "{}", before.text);
+    assert!(before.text.contains("X-WR-CALNAME:Treinos Sub-9\r\n"));
+    let next_week_uid = format!("UID:{}-{}@", schedule_id, ics_time(next_week, "180000"));
+    assert!(events(&before.text).iter().any(|event| event.contains(&next_week_uid)), "{}", before.text);
+    assert!(before.text.contains("\r\nLAST-MODIFIED:"), "{}", before.text);
+    let first_sequence = sequence(&before.text, &next_week_uid);
+
+    // Cancela a de hoje e remarca a da semana que vem
+    app.create("/api/schedule_changes", "schedule_change", &json!({
+        "schedule_id": schedule_id,
+        "old_date": format!("{}T18:00:00", today),
+        "reason": "Chuva, sem campo",
+    }))
+    .await;
+    let change_id = app.create("/api/schedule_changes", "schedule_change", &json!({
+        "schedule_id": schedule_id,
+        "old_date": format!("{}T18:00:00", next_week),
+        "new_date": format!("{}T10:00:00", next_week + Duration::days(1)),
+    }))
+    .await;
+
+    let after = app.get(&path).await;
+    let after_events = events(&after.text);
+    assert_eq!(after_events.len(), events(&before.text).len());
+
+    let today_uid = format!("UID:{}-{}@", schedule_id, ics_time(today, "180000"));
+    let cancelled = after_events.iter().find(|event| event.contains(&today_uid)).expect("today's session");
+    assert!(cancelled.contains("STATUS:CANCELLED\r\n"), "{}", cancelled);
+    assert!(cancelled.contains("DESCRIPTION:Chuva\\, sem campo\r\n"), "{}", cancelled);
+    assert!(cancelled.contains("LOCATION:Campo 1\\; coberto\r\n"), "{}", cancelled);
+
+    let moved = after_events.iter().find(|event| event.contains(&next_week_uid)).expect("next week's session");
+    let new_start = ics_time(next_week + Duration::days(1), "100000");
+    assert!(moved.contains(&format!("DTSTART:{}\r\n", new_start)), "{}", moved);
+    assert!(moved.contains("STATUS:CONFIRMED\r\n"), "{}", moved);
+
+    // Cada edição é uma versão nova do evento, inclusive desfazer a mudança
+    let moved_sequence = sequence(&after.text, &next_week_uid);
+    assert!(moved_sequence > first_sequence, "{} <= {}", moved_sequence, first_sequence);
+    let edited = app.patch(&format!("/api/schedule_changes/{}", change_id), &json!({"reason": "Jogo no sábado"})).await;
+    assert_eq!(edited.status, StatusCode::OK, "{}", edited.body);
+    let edited_sequence = sequence(&app.get(&path).await.text, &next_week_uid);
+    assert!(edited_sequence > moved_sequence, "{} <= {}", edited_sequence, moved_sequence);
+    let undone = app.delete(&format!("/api/schedule_changes/{}", change_id)).await;
+    assert_eq!(undone.status, StatusCode::NO_CONTENT);
+    let restored = app.get(&path).await.text;
+    assert!(sequence(&restored, &next_week_uid) > edited_sequence, "{}", restored);
+
+    // Gerar de novo troca a URL
+    let rotated = app.post(&format!("/api/groups/{}/calendar_feed", group_id), &json!({})).await;
+    assert_eq!(rotated.status, StatusCode::OK);
+    assert_ne!(rotated.body["path"], json!(path));
+    assert_eq!(app.get(&path).await.status, StatusCode::NOT_FOUND);
+
+    let uri = format!("/api/groups/{}/calendar_feed", group_id);
+    assert_eq!(app.delete(&uri).await.status, StatusCode::NO_CONTENT);
+    assert_eq!(app.delete(&uri).await.status, StatusCode::NOT_FOUND);
+    let revoked = rotated.body["path"].as_str().unwrap().to_string();
+    assert_eq!(app.get(&revoked).await.status, StatusCode::NOT_FOUND);
+}
+
+#[actix_web::test]
+#[ignore = "needs TEST_DATABASE_URL"]
+async fn household_feed_merges_the_childrens_groups() {
+    let app = test_app!();
+    let (sub9, _) = group_training_today(&app, "Sub-9").await;
+    let (sub11, _) = group_training_today(&app, "Sub-11").await;
+    group_training_today(&app, "Sub-13").await;
+
+    let household_id = app.create("/api/households", "household", &json!({"name": "Família Lima"})).await;
+    for (name, group_id) in [("Pedro Lima", sub9), ("Ana Lima", sub11)] {
+        let user_id = create_user(&app).await;
+        app.create("/api/students", "student", &json!({
+            "user_id": user_id,
+            "name": name,
+            "email": format!("{}@example.com", Uuid::new_v4().simple()),
+            "age": 9,
+            "group_id": group_id,
+            "household_id": household_id,
+        }))
+        .await;
+    }
+
+    let feed = app.post(&format!("/api/households/{}/calendar_feed", household_id), &json!({})).await;
+    assert_eq!(feed.status, StatusCode::CREATED, "{}", feed.body);
+    let ics = app.get(feed.body["path"].as_str().unwrap()).await;
+    assert_eq!(ics.status, StatusCode::OK);
+    assert!(ics.text.contains("X-WR-CALNAME:Treinos Família Lima\r\n"));
+    assert!(ics.text.contains("SUMMARY:Treino Sub-9\r\n"));
+    assert!(ics.text.contains("SUMMARY:Treino Sub-11\r\n"));
+    assert!(!ics.text.contains("SUMMARY:Treino Sub-13\r\n"));
+
+    let missing = app.post(&format!("/api/households/{}/calendar_feed", Uuid::new_v4()), &json!({})).await;
+    assert_eq!(missing.status, StatusCode::NOT_FOUND);
+    assert_eq!(app.get("/api/calendar/nao-existe.ics").await.status, StatusCode::NOT_FOUND);
+}
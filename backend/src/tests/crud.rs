@@ -0,0 +1,430 @@
+// backend/src/tests/crud.rs
+//! Criação, leitura, alteração, listagem e remoção de cada recurso
+use actix_web::{http::StatusCode, test::TestRequest};
+use serde_json::{json, Value};
+use uuid::Uuid;
+
+use super::{create_student, create_user, test_app, TestApp};
+
+/// Um recurso com as rotas `POST {path}`, `GET/PATCH/DELETE {path}/{id}` e `GET {path}`
+struct Resource<'a> {
+    path: &'a str,
+    /// Chave do registro nas respostas (`{"student": {...}}`)
+    key: &'a str,
+    /// Chave da lista em `GET {path}`
+    list_key: &'a str,
+    create: Value,
+    /// Os campos enviados precisam voltar iguais na resposta do PATCH
+    update: Value,
+}
+
+async fn assert_crud(app: &TestApp, resource: Resource<'_>) {
+    let id = app.create(resource.path, resource.key, &resource.create).await;
+    let item_uri = format!("{}/{}", resource.path, id);
+
+    let found = app.get(&item_uri).await;
+    assert_eq!(found.status, StatusCode::OK, "GET {}: {}", item_uri, found.body);
+    assert_eq!(found.body[resource.key]["id"], json!(id));
+
+    let updated = app.patch(&item_uri, &resource.update).await;
+    assert_eq!(updated.status, StatusCode::OK, "PATCH {}: {}", item_uri, updated.body);
+    for (field, value) in resource.update.as_object().expect("update is an object") {
+        assert_eq!(&updated.body[resource.key][field], value, "PATCH {} did not change {}", item_uri, field);
+    }
+
+    let list = app.get(resource.path).await;
+    assert_eq!(list.status, StatusCode::OK, "GET {}: {}", resource.path, list.body);
+    let listed = list.body[resource.list_key].as_array().expect("list is an array");
+    assert!(listed.iter().any(|item| item["id"] == json!(id)), "{} missing from GET {}", id, resource.path);
+
+    let deleted = app.delete(&item_uri).await;
+    assert_eq!(deleted.status, StatusCode::NO_CONTENT, "DELETE {}: {}", item_uri, deleted.body);
+    let gone = app.get(&item_uri).await;
+    assert_eq!(gone.status, StatusCode::NOT_FOUND, "GET {} after delete: {}", item_uri, gone.body);
+}
+
+#[actix_web::test]
+#[ignore = "needs TEST_DATABASE_URL"]
+async fn users() {
+    let app = test_app!();
+    assert_crud(&app, Resource {
+        path: "/api/users",
+        key: "user",
+        list_key: "users",
+        create: json!({"username": "maria", "password_hash": "hash", "role": "parent"}),
+        update: json!({"username": "maria.silva", "role": "admin"}),
+    })
+    .await;
+}
+
+#[actix_web::test]
+#[ignore = "needs TEST_DATABASE_URL"]
+async fn parents() {
+    let app = test_app!();
+    assert_crud(&app, Resource {
+        path: "/api/parents",
+        key: "parent",
+        list_key: "parents",
+        create: json!({"name": "Maria Silva", "email": "maria@example.com"}),
+        update: json!({"name": "Maria S. Silva"}),
+    })
+    .await;
+}
+
+#[actix_web::test]
+#[ignore = "needs TEST_DATABASE_URL"]
+async fn students() {
+    let app = test_app!();
+    let user_id = create_user(&app).await;
+    assert_crud(&app, Resource {
+        path: "/api/students",
+        key: "student",
+        list_key: "students",
+        create: json!({"user_id": user_id, "name": "Pedro Lima", "email": "pedro@example.com", "age": 9}),
+        update: json!({"name": "Pedro A. Lima", "shirt_size": "M"}),
+    })
+    .await;
+}
+
+#[actix_web::test]
+#[ignore = "needs TEST_DATABASE_URL"]
+async fn guardians() {
+    let app = test_app!();
+    assert_crud(&app, Resource {
+        path: "/api/guardians",
+        key: "guardian",
+        list_key: "guardians",
+        create: json!({"name": "José Lima", "relationship": "avô"}),
+        update: json!({"relationship": "tio"}),
+    })
+    .await;
+}
+
+#[actix_web::test]
+#[ignore = "needs TEST_DATABASE_URL"]
+async fn households() {
+    let app = test_app!();
+    assert_crud(&app, Resource {
+        path: "/api/households",
+        key: "household",
+        list_key: "households",
+        create: json!({"name": "Família Lima"}),
+        update: json!({"name": "Família Lima Souza"}),
+    })
+    .await;
+}
+
+#[actix_web::test]
+#[ignore = "needs TEST_DATABASE_URL"]
+async fn addresses() {
+    let app = test_app!();
+    let student_id = create_student(&app).await;
+    assert_crud(&app, Resource {
+        path: "/api/addresses",
+        key: "address",
+        list_key: "addresses",
+        create: json!({
+            "student_id": student_id,
+            "street": "Rua das Flores, 10",
+            "city": "Recife",
+            "state": "PE",
+            "zip_code": "50000-000",
+        }),
+        update: json!({"city": "Olinda"}),
+    })
+    .await;
+}
+
+#[actix_web::test]
+#[ignore = "needs TEST_DATABASE_URL"]
+async fn phones() {
+    let app = test_app!();
+    let student_id = create_student(&app).await;
+    assert_crud(&app, Resource {
+        path: "/api/phones",
+        key: "phone",
+        list_key: "phones",
+        create: json!({"student_id": student_id, "number": "81999990000", "phone_type": "mobile"}),
+        update: json!({"number": "8133330000", "phone_type": "home"}),
+    })
+    .await;
+}
+
+#[actix_web::test]
+#[ignore = "needs TEST_DATABASE_URL"]
+async fn attendances() {
+    let app = test_app!();
+    let student_id = create_student(&app).await;
+    assert_crud(&app, Resource {
+        path: "/api/attendances",
+        key: "attendance",
+        list_key: "attendances",
+        create: json!({"student_id": student_id, "date": "2025-03-10", "status": "presente"}),
+        update: json!({"status": "falta", "notes": "atestado"}),
+    })
+    .await;
+}
+
+#[actix_web::test]
+#[ignore = "needs TEST_DATABASE_URL"]
+async fn warnings() {
+    let app = test_app!();
+    let student_id = create_student(&app).await;
+    assert_crud(&app, Resource {
+        path: "/api/warnings",
+        key: "warning",
+        list_key: "warnings",
+        create: json!({"student_id": student_id, "reason": "Atraso"}),
+        update: json!({"reason": "Atraso recorrente"}),
+    })
+    .await;
+}
+
+#[actix_web::test]
+#[ignore = "needs TEST_DATABASE_URL"]
+async fn photos() {
+    let app = test_app!();
+    let student_id = create_student(&app).await;
+    assert_crud(&app, Resource {
+        path: "/api/photos",
+        key: "photo",
+        list_key: "photos",
+        create: json!({"student_id": student_id, "filename": "treino.jpg"}),
+        update: json!({"description": "Treino de sábado"}),
+    })
+    .await;
+}
+
+#[actix_web::test]
+#[ignore = "needs TEST_DATABASE_URL"]
+async fn file_metadatas() {
+    let app = test_app!();
+    assert_crud(&app, Resource {
+        path: "/api/file_metadatas",
+        key: "file_metadata",
+        list_key: "file_metadatas",
+        create: json!({"file_type": "photo", "filename": "equipe.jpg"}),
+        update: json!({"file_type": "video", "filename": "equipe.mp4"}),
+    })
+    .await;
+}
+
+#[actix_web::test]
+#[ignore = "needs TEST_DATABASE_URL"]
+async fn tasks() {
+    let app = test_app!();
+    assert_crud(&app, Resource {
+        path: "/api/tasks",
+        key: "task",
+        list_key: "tasks",
+        create: json!({"title": "Comprar bolas", "content": "Duas bolas tamanho 4"}),
+        update: json!({"title": "Comprar bolas e coletes"}),
+    })
+    .await;
+}
+
+#[actix_web::test]
+#[ignore = "needs TEST_DATABASE_URL"]
+async fn tags() {
+    let app = test_app!();
+    assert_crud(&app, Resource {
+        path: "/api/tags",
+        key: "tag",
+        list_key: "tags",
+        create: json!({"name": "drible"}),
+        update: json!({"name": "finalização"}),
+    })
+    .await;
+}
+
+#[actix_web::test]
+#[ignore = "needs TEST_DATABASE_URL"]
+async fn groups() {
+    let app = test_app!();
+    assert_crud(&app, Resource {
+        path: "/api/groups",
+        key: "group",
+        list_key: "groups",
+        create: json!({"name": "pequenos"}),
+        update: json!({"description": "Sub-9"}),
+    })
+    .await;
+}
+
+#[actix_web::test]
+#[ignore = "needs TEST_DATABASE_URL"]
+async fn chat_rooms_and_messages() {
+    let app = test_app!();
+    assert_crud(&app, Resource {
+        path: "/api/chat_rooms",
+        key: "chat_room",
+        list_key: "chat_rooms",
+        create: json!({"name": "Pais sub-9", "is_group": true}),
+        update: json!({"name": "Pais sub-10"}),
+    })
+    .await;
+    // As mensagens precisam de uma sala que ainda exista
+    let room_id = app.create("/api/chat_rooms", "chat_room", &json!({"name": "Técnicos", "is_group": true})).await;
+    let sender_id = create_user(&app).await;
+    assert_crud(&app, Resource {
+        path: "/api/messages",
+        key: "message",
+        list_key: "messages",
+        create: json!({"room_id": room_id, "sender_id": sender_id, "content": "Treino cancelado"}),
+        update: json!({"content": "Treino remarcado"}),
+    })
+    .await;
+}
+
+#[actix_web::test]
+#[ignore = "needs TEST_DATABASE_URL"]
+async fn submissions() {
+    let app = test_app!();
+    let student_id = create_student(&app).await;
+    assert_crud(&app, Resource {
+        path: "/api/submissions",
+        key: "submission",
+        list_key: "submissions",
+        create: json!({"student_id": student_id, "filename": "gol.mp4"}),
+        update: json!({"description": "Gol de falta"}),
+    })
+    .await;
+}
+
+#[actix_web::test]
+#[ignore = "needs TEST_DATABASE_URL"]
+async fn documents() {
+    let app = test_app!();
+    let student_id = create_student(&app).await;
+
+    let created = app
+        .multipart("/api/documents", &[
+            ("student_id", None, student_id.to_string().as_bytes()),
+            ("doc_type", None, b"rg"),
+            ("file", Some("rg.pdf"), b"%PDF-1.4"),
+        ])
+        .await;
+    assert_eq!(created.status, StatusCode::OK, "{}", created.body);
+    let id = created.body["document"]["id"].as_str().expect("document id").to_string();
+    assert!(app.storage_dir.join("uploads").join("rg.pdf").exists());
+
+    let uri = format!("/api/documents/{}", id);
+    let updated = app.patch(&uri, &json!({"doc_type": "cpf"})).await;
+    assert_eq!(updated.status, StatusCode::OK, "{}", updated.body);
+    assert_eq!(updated.body["document"]["doc_type"], "cpf");
+
+    assert_eq!(app.delete(&uri).await.status, StatusCode::NO_CONTENT);
+    assert_eq!(app.get(&uri).await.status, StatusCode::NOT_FOUND);
+}
+
+#[actix_web::test]
+#[ignore = "needs TEST_DATABASE_URL"]
+async fn schedule_changes() {
+    let app = test_app!();
+    let group_id = app.create("/api/groups", "group", &json!({"name": "medios"})).await;
+
+    let id = app
+        .create("/api/schedule_changes", "schedule_change", &json!({
+            "group_id": group_id,
+            "old_date": "2025-03-10T18:00:00",
+            "new_date": "2025-03-12T18:00:00",
+            "reason": "Chuva",
+        }))
+        .await;
+    let uri = format!("/api/schedule_changes/{}", id);
+
+    let updated = app.patch(&uri, &json!({"reason": "Feriado"})).await;
+    assert_eq!(updated.status, StatusCode::OK, "{}", updated.body);
+    assert_eq!(updated.body["schedule_change"]["reason"], "Feriado");
+
+    let list = app.get("/api/schedule_changes").await;
+    assert_eq!(list.body["schedule_changes"][0]["id"], json!(id));
+
+    assert_eq!(app.delete(&uri).await.status, StatusCode::NO_CONTENT);
+    assert_eq!(app.delete(&uri).await.status, StatusCode::NOT_FOUND);
+}
+
+#[actix_web::test]
+#[ignore = "needs TEST_DATABASE_URL"]
+async fn audit_log_records_changes() {
+    let app = test_app!();
+    let user_id = create_user(&app).await;
+
+    let logs = app.get(&format!("/api/logs?entity_type=user&entity_id={}", user_id)).await;
+    assert_eq!(logs.status, StatusCode::OK, "{}", logs.body);
+    assert_eq!(logs.body["logs"][0]["action"], "create");
+    assert_eq!(logs.body["logs"][0]["after"]["password_hash"], "[redacted]");
+}
+
+#[actix_web::test]
+#[ignore = "needs TEST_DATABASE_URL"]
+async fn manual_logs_are_attributed_to_the_caller() {
+    let app = test_app!();
+    let caller = create_user(&app).await;
+    let someone_else = create_user(&app).await;
+
+    let request = TestRequest::post()
+        .uri("/api/logs")
+        .insert_header(("x-user-id", caller.to_string()))
+        .set_json(json!({"user_id": someone_else, "action": "export", "description": "Exportou a chamada"}));
+    let response = app.send(request).await;
+    assert_eq!(response.status, StatusCode::OK, "{}", response.body);
+    assert_eq!(response.body["log"]["user_id"], json!(caller));
+
+    let anonymous = app
+        .post("/api/logs", &json!({"user_id": someone_else, "action": "export", "description": "Sem autor"}))
+        .await;
+    assert_eq!(anonymous.status, StatusCode::OK, "{}", anonymous.body);
+    assert_eq!(anonymous.body["log"]["user_id"], Value::Null);
+}
+
+#[actix_web::test]
+#[ignore = "needs TEST_DATABASE_URL"]
+async fn audit_ip_trusts_forwarded_headers_only_from_proxies() {
+    let app = test_app!(|config| config.server.trusted_proxies = vec!["10.0.0.1".to_string()]);
+    let log_ip = |peer: &str, forwarded: &str| {
+        TestRequest::post()
+            .uri("/api/logs")
+            .peer_addr(peer.parse().unwrap())
+            .insert_header(("x-forwarded-for", forwarded.to_string()))
+            .set_json(json!({"action": "export", "description": "Exportou a chamada"}))
+    };
+
+    // Direto do cliente: o cabeçalho é ignorado
+    let direct = app.send(log_ip("203.0.113.9:5000", "1.2.3.4")).await;
+    assert_eq!(direct.status, StatusCode::OK, "{}", direct.body);
+    assert_eq!(direct.body["log"]["ip_address"], "203.0.113.9");
+
+    // Pelo proxy: vale o endereço que ele acrescentou, não o enviado pelo cliente
+    let proxied = app.send(log_ip("10.0.0.1:5000", "1.2.3.4, 198.51.100.7")).await;
+    assert_eq!(proxied.body["log"]["ip_address"], "198.51.100.7", "{}", proxied.body);
+}
+
+#[actix_web::test]
+#[ignore = "needs TEST_DATABASE_URL"]
+async fn validation_and_missing_records() {
+    let app = test_app!();
+
+    let invalid = app.post("/api/users", &json!({"username": "", "password_hash": "x", "role": "visitor"})).await;
+    assert_eq!(invalid.status, StatusCode::BAD_REQUEST);
+    assert_eq!(invalid.body["code"], "validation_failed");
+
+    let missing = app.get(&format!("/api/students/{}", Uuid::new_v4())).await;
+    assert_eq!(missing.status, StatusCode::NOT_FOUND);
+    assert_eq!(missing.body["code"], "not_found");
+
+    let dangling = app
+        .post("/api/warnings", &json!({"student_id": Uuid::new_v4(), "reason": "Atraso"}))
+        .await;
+    assert_eq!(dangling.status, StatusCode::UNPROCESSABLE_ENTITY);
+}
+
+#[actix_web::test]
+#[ignore = "needs TEST_DATABASE_URL"]
+async fn page_offset_overflow_is_rejected() {
+    let app = test_app!();
+
+    let response = app.get(&format!("/api/users?limit=100&page={}", i64::MAX)).await;
+    assert_eq!(response.status, StatusCode::BAD_REQUEST, "{}", response.body);
+    assert_eq!(response.body["message"], "'page' is too large");
+}
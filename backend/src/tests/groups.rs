@@ -0,0 +1,200 @@
+// backend/src/tests/groups.rs
+//! Turmas configuráveis e a distribuição dos alunos por idade na temporada
+use actix_web::http::StatusCode;
+use chrono::{Local, NaiveDate};
+use serde_json::json;
+use uuid::Uuid;
+
+use super::{create_user, test_app, TestApp};
+use crate::validation::age_on;
+
+async fn create_coach(app: &TestApp) -> Uuid {
+    let username = format!("coach-{}", Uuid::new_v4().simple());
+    app.create("/api/users", "user", &json!({"username": username, "password_hash": "hash", "role": "coach"})).await
+}
+
+async fn create_born(app: &TestApp, name: &str, birth_date: Option<NaiveDate>) -> Uuid {
+    let user_id = create_user(app).await;
+    let age = birth_date.map_or(10, |birth_date| age_on(birth_date, Local::now().date_naive()));
+    app.create("/api/students", "student", &json!({
+        "user_id": user_id,
+        "name": name,
+        "email": format!("{}@example.com", Uuid::new_v4().simple()),
+        "age": age,
+        "birth_date": birth_date,
+    }))
+    .await
+}
+
+fn date(year: i32, month: u32, day: u32) -> Option<NaiveDate> {
+    NaiveDate::from_ymd_opt(year, month, day)
+}
+
+#[actix_web::test]
+#[ignore = "needs TEST_DATABASE_URL"]
+async fn group_settings_are_validated() {
+    let app = test_app!();
+    let coach_id = create_coach(&app).await;
+
+    let created = app
+        .post("/api/groups", &json!({
+            "name": "Sub-9 Manhã",
+            "season": 2025,
+            "min_age": 8,
+            "max_age": 9,
+            "coach_id": coach_id,
+            "capacity": 20,
+        }))
+        .await;
+    assert_eq!(created.status, StatusCode::CREATED, "{}", created.body);
+    assert_eq!(created.body["group"]["coach_id"], json!(coach_id));
+    assert_eq!(created.body["group"]["capacity"], 20);
+
+    let by_season = app.get("/api/groups?season=2025").await;
+    assert_eq!(by_season.body["groups"].as_array().map(Vec::len), Some(1), "{}", by_season.body);
+
+    let not_a_coach = create_user(&app).await;
+    let rejected = app.post("/api/groups", &json!({"name": "Sub-11", "coach_id": not_a_coach})).await;
+    assert_eq!(rejected.status, StatusCode::BAD_REQUEST, "{}", rejected.body);
+
+    let inverted = app.post("/api/groups", &json!({"name": "Sub-11", "min_age": 11, "max_age": 10})).await;
+    assert_eq!(inverted.status, StatusCode::BAD_REQUEST, "{}", inverted.body);
+
+    let missing_coach = app.post("/api/groups", &json!({"name": "Sub-11", "coach_id": Uuid::new_v4()})).await;
+    assert_eq!(missing_coach.status, StatusCode::UNPROCESSABLE_ENTITY, "{}", missing_coach.body);
+}
+
+#[actix_web::test]
+#[ignore = "needs TEST_DATABASE_URL"]
+async fn group_update_distinguishes_missing_from_null() {
+    let app = test_app!();
+    let coach_id = create_coach(&app).await;
+    let group_id = app
+        .create("/api/groups", "group", &json!({
+            "name": "Sub-13 Tarde",
+            "description": "Terças e quintas",
+            "season": 2025,
+            "min_age": 12,
+            "max_age": 13,
+            "coach_id": coach_id,
+            "capacity": 18,
+        }))
+        .await;
+    let uri = format!("/api/groups/{}", group_id);
+
+    // Campos ausentes ficam como estão
+    let renamed = app.patch(&uri, &json!({"name": "Sub-13"})).await;
+    assert_eq!(renamed.status, StatusCode::OK, "{}", renamed.body);
+    let group = &renamed.body["group"];
+    assert_eq!(group["name"], "Sub-13");
+    assert_eq!(group["coach_id"], json!(coach_id));
+    assert_eq!(group["capacity"], 18);
+
+    let invalid = app.patch(&uri, &json!({"capacity": 0, "min_age": 14})).await;
+    assert_eq!(invalid.status, StatusCode::BAD_REQUEST, "{}", invalid.body);
+
+    // `null` limpa o valor
+    let cleared = app
+        .patch(&uri, &json!({
+            "description": null,
+            "season": null,
+            "min_age": null,
+            "max_age": null,
+            "coach_id": null,
+            "capacity": null,
+        }))
+        .await;
+    assert_eq!(cleared.status, StatusCode::OK, "{}", cleared.body);
+    let group = &cleared.body["group"];
+    assert_eq!(group["name"], "Sub-13");
+    for field in ["description", "season", "min_age", "max_age", "coach_id", "capacity"] {
+        assert!(group[field].is_null(), "{} not cleared: {}", field, group);
+    }
+
+    let by_coach = app.get(&format!("/api/groups?coach_id={}", coach_id)).await;
+    assert_eq!(by_coach.body["groups"], json!([]), "{}", by_coach.body);
+}
+
+#[actix_web::test]
+#[ignore = "needs TEST_DATABASE_URL"]
+async fn students_are_assigned_by_season_age() {
+    let app = test_app!();
+    let sub9 = app
+        .create("/api/groups", "group", &json!({"name": "Sub-9", "season": 2025, "min_age": 8, "max_age": 9, "capacity": 1}))
+        .await;
+    let sub11 = app
+        .create("/api/groups", "group", &json!({"name": "Sub-11", "season": 2025, "min_age": 10, "max_age": 11}))
+        .await;
+    // Turma de outra temporada não conta
+    app.create("/api/groups", "group", &json!({"name": "Sub-9", "season": 2024, "min_age": 8, "max_age": 9})).await;
+
+    let caio = create_born(&app, "Caio", date(2014, 11, 20)).await; // 11 em 2025
+    let bia = create_born(&app, "Bia", date(2016, 12, 31)).await; // 9 em 2025
+    let davi = create_born(&app, "Davi", date(2017, 1, 1)).await; // 8 em 2025, Sub-9 já cheia
+    let eva = create_born(&app, "Eva", date(2019, 6, 1)).await; // 6 em 2025
+    let sem_data = create_born(&app, "Fábio", None).await;
+
+    let preview = app.get("/api/groups/assignments?season=2025").await;
+    assert_eq!(preview.status, StatusCode::OK, "{}", preview.body);
+    let by_student = |body: &serde_json::Value, id: Uuid| {
+        body["assignments"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|assignment| assignment["student_id"] == json!(id))
+            .cloned()
+            .unwrap_or_else(|| panic!("{} missing from {}", id, body))
+    };
+    assert_eq!(by_student(&preview.body, caio)["group_id"], json!(sub11));
+    assert_eq!(by_student(&preview.body, bia)["group_id"], json!(sub9));
+    assert_eq!(by_student(&preview.body, bia)["season_age"], 9);
+    assert_eq!(by_student(&preview.body, davi)["reason"], "groups_full");
+    assert_eq!(by_student(&preview.body, eva)["reason"], "no_matching_group");
+    assert_eq!(by_student(&preview.body, sem_data)["reason"], "no_birth_date");
+
+    // A sugestão não altera nada
+    let bia_before = app.get(&format!("/api/students/{}", bia)).await;
+    assert_eq!(bia_before.body["student"]["group_id"], json!(null));
+
+    let applied = app.post("/api/groups/assignments", &json!({"season": 2025})).await;
+    assert_eq!(applied.status, StatusCode::OK, "{}", applied.body);
+    assert_eq!(applied.body["assigned"], 2);
+    let bia_after = app.get(&format!("/api/students/{}", bia)).await;
+    assert_eq!(bia_after.body["student"]["group_id"], json!(sub9));
+
+    // Quem já tem turma na temporada sai da próxima sugestão
+    let again = app.get("/api/groups/assignments?season=2025").await;
+    assert_eq!(again.body["assignments"].as_array().map(Vec::len), Some(3), "{}", again.body);
+}
+
+#[actix_web::test]
+#[ignore = "needs TEST_DATABASE_URL"]
+async fn only_students_from_past_seasons_are_reassigned() {
+    let app = test_app!();
+    let sub9 = app
+        .create("/api/groups", "group", &json!({"name": "Sub-9", "season": 2025, "min_age": 8, "max_age": 9}))
+        .await;
+    let past = app.create("/api/groups", "group", &json!({"name": "Sub-8", "season": 2024})).await;
+    let legacy = app.create("/api/groups", "group", &json!({"name": "Turma antiga"})).await;
+    let next = app.create("/api/groups", "group", &json!({"name": "Sub-10", "season": 2026})).await;
+
+    let mut students = Vec::new();
+    for (name, group_id) in [("Ana", past), ("Bruno", legacy), ("Carla", next)] {
+        let student_id = create_born(&app, name, date(2016, 3, 1)).await; // 9 em 2025
+        let moved = app.patch(&format!("/api/students/{}", student_id), &json!({"group_id": group_id})).await;
+        assert_eq!(moved.status, StatusCode::OK, "{}", moved.body);
+        students.push(student_id);
+    }
+
+    let preview = app.get("/api/groups/assignments?season=2025").await;
+    assert_eq!(preview.status, StatusCode::OK, "{}", preview.body);
+    let assignments = preview.body["assignments"].as_array().unwrap();
+    assert_eq!(assignments.len(), 1, "{}", preview.body);
+    assert_eq!(assignments[0]["student_id"], json!(students[0]));
+    assert_eq!(assignments[0]["group_id"], json!(sub9));
+
+    let applied = app.post("/api/groups/assignments", &json!({"season": 2025})).await;
+    assert_eq!(applied.body["assigned"], 1, "{}", applied.body);
+    let untouched = app.get(&format!("/api/students/{}", students[1])).await;
+    assert_eq!(untouched.body["student"]["group_id"], json!(legacy));
+}
@@ -0,0 +1,99 @@
+// backend/src/tests/guardians.rs
+//! Vínculos entre alunos e responsáveis (`student_guardians`)
+use actix_web::http::StatusCode;
+use serde_json::json;
+use uuid::Uuid;
+
+use super::{create_student, test_app, TestApp};
+
+async fn create_guardian(app: &TestApp, name: &str) -> Uuid {
+    app.create("/api/guardians", "guardian", &json!({"name": name, "relationship": "responsável"})).await
+}
+
+#[actix_web::test]
+#[ignore = "needs TEST_DATABASE_URL"]
+async fn link_and_query_guardians() {
+    let app = test_app!();
+    let student_id = create_student(&app).await;
+    let avo = create_guardian(&app, "José Lima").await;
+    let tia = create_guardian(&app, "Carla Lima").await;
+    let vizinha = create_guardian(&app, "Beatriz Ramos").await;
+
+    let uri = |guardian_id: Uuid| format!("/api/students/{}/guardians/{}", student_id, guardian_id);
+    let created = app
+        .put(&uri(avo), &json!({"relationship": "avô", "can_pick_up": true, "emergency_priority": 2}))
+        .await;
+    assert_eq!(created.status, StatusCode::CREATED, "{}", created.body);
+    assert_eq!(created.body["student_guardian"]["relationship"], "avô");
+    app.put(&uri(tia), &json!({"relationship": "tia", "emergency_priority": 1})).await;
+    app.put(&uri(vizinha), &json!({"relationship": "vizinha", "can_pick_up": true})).await;
+
+    // Contatos de emergência primeiro, pela prioridade; depois os demais
+    let all = app.get(&format!("/api/students/{}/guardians", student_id)).await;
+    assert_eq!(all.status, StatusCode::OK, "{}", all.body);
+    let names: Vec<_> = all.body["guardians"].as_array().unwrap().iter().map(|g| g["name"].clone()).collect();
+    assert_eq!(names, [json!("Carla Lima"), json!("José Lima"), json!("Beatriz Ramos")]);
+
+    let pickup = app.get(&format!("/api/students/{}/guardians?can_pick_up=true", student_id)).await;
+    let ids: Vec<_> = pickup.body["guardians"].as_array().unwrap().iter().map(|g| g["guardian_id"].clone()).collect();
+    assert_eq!(ids, [json!(avo), json!(vizinha)]);
+
+    let emergency = app.get(&format!("/api/students/{}/guardians?emergency=false", student_id)).await;
+    assert_eq!(emergency.body["guardians"][0]["guardian_id"], json!(vizinha));
+
+    let students = app.get(&format!("/api/guardians/{}/students", avo)).await;
+    assert_eq!(students.status, StatusCode::OK, "{}", students.body);
+    assert_eq!(students.body["students"][0]["student_id"], json!(student_id));
+    assert_eq!(students.body["students"][0]["relationship"], "avô");
+
+    let profile = app.get(&format!("/api/students/{}/profile?include=guardians", student_id)).await;
+    assert_eq!(profile.body["profile"]["guardians"].as_array().map(Vec::len), Some(3));
+}
+
+#[actix_web::test]
+#[ignore = "needs TEST_DATABASE_URL"]
+async fn relink_updates_and_unlink_removes() {
+    let app = test_app!();
+    let student_id = create_student(&app).await;
+    let guardian_id = create_guardian(&app, "José Lima").await;
+    let uri = format!("/api/students/{}/guardians/{}", student_id, guardian_id);
+
+    app.put(&uri, &json!({"relationship": "avô"})).await;
+    let updated = app.put(&uri, &json!({"relationship": "avô", "can_pick_up": true})).await;
+    assert_eq!(updated.status, StatusCode::OK, "{}", updated.body);
+    assert_eq!(updated.body["student_guardian"]["can_pick_up"], true);
+    let link_id = updated.body["student_guardian"]["id"].as_str().expect("link id").to_string();
+
+    let logs = app.get(&format!("/api/logs?entity_type=student_guardian&entity_id={}", link_id)).await;
+    let actions: Vec<_> = logs.body["logs"].as_array().unwrap().iter().map(|log| log["action"].clone()).collect();
+    assert!(actions.contains(&json!("create")) && actions.contains(&json!("update")), "{}", logs.body);
+
+    assert_eq!(app.delete(&uri).await.status, StatusCode::NO_CONTENT);
+    assert_eq!(app.delete(&uri).await.status, StatusCode::NOT_FOUND);
+    // O responsável continua cadastrado
+    assert_eq!(app.get(&format!("/api/guardians/{}", guardian_id)).await.status, StatusCode::OK);
+}
+
+#[actix_web::test]
+#[ignore = "needs TEST_DATABASE_URL"]
+async fn invalid_links() {
+    let app = test_app!();
+    let student_id = create_student(&app).await;
+
+    let missing = app
+        .put(&format!("/api/students/{}/guardians/{}", student_id, Uuid::new_v4()), &json!({"relationship": "tio"}))
+        .await;
+    assert_eq!(missing.status, StatusCode::UNPROCESSABLE_ENTITY, "{}", missing.body);
+
+    let guardian_id = create_guardian(&app, "José Lima").await;
+    let invalid = app
+        .put(
+            &format!("/api/students/{}/guardians/{}", student_id, guardian_id),
+            &json!({"relationship": "avô", "emergency_priority": 0}),
+        )
+        .await;
+    assert_eq!(invalid.status, StatusCode::BAD_REQUEST, "{}", invalid.body);
+
+    let unknown = app.get(&format!("/api/guardians/{}/students", Uuid::new_v4())).await;
+    assert_eq!(unknown.status, StatusCode::NOT_FOUND);
+}
@@ -0,0 +1,82 @@
+// backend/src/tests/health.rs
+//! Verificações de saúde: liveness e readiness
+use actix_web::http::StatusCode;
+use serde_json::Value;
+
+use super::test_app;
+
+fn component<'a>(body: &'a Value, name: &str) -> &'a Value {
+    body["components"]
+        .as_array()
+        .and_then(|components| components.iter().find(|c| c["name"] == name))
+        .unwrap_or_else(|| panic!("no {} component in {}", name, body))
+}
+
+#[actix_web::test]
+#[ignore = "needs TEST_DATABASE_URL"]
+async fn disk_check_works_before_the_uploads_dir_exists() {
+    let app = test_app!();
+    assert!(!app.storage_dir.join("uploads").exists());
+
+    let response = app.get("/api/health/ready").await;
+    assert_eq!(response.status, StatusCode::OK, "{}", response.body);
+    let disk = component(&response.body, "disk");
+    assert_eq!(disk["status"], "up", "{}", disk);
+    assert_eq!(component(&response.body, "postgres")["status"], "up");
+}
+
+#[actix_web::test]
+#[ignore = "needs TEST_DATABASE_URL"]
+async fn failures_do_not_leak_paths_or_errors() {
+    let app = test_app!(|config| {
+        config.health.min_free_disk_bytes = u64::MAX;
+        config.health.require_ffmpeg = true;
+        config.transcoder.ffmpeg_path = "/nonexistent/bin/ffmpeg".to_string();
+    });
+
+    let response = app.get("/api/health/ready").await;
+    assert_eq!(response.status, StatusCode::SERVICE_UNAVAILABLE, "{}", response.body);
+    assert_eq!(response.body["status"], "unavailable");
+
+    let disk = component(&response.body, "disk");
+    assert_eq!(disk["status"], "down");
+    assert_eq!(disk["detail"], "free space below the configured minimum");
+
+    let ffmpeg = component(&response.body, "ffmpeg");
+    assert_eq!(ffmpeg["status"], "down");
+    assert_eq!(ffmpeg["detail"], "cannot run ffmpeg");
+
+    let storage_dir = app.storage_dir.to_string_lossy().into_owned();
+    assert!(!response.text.contains(&storage_dir), "{}", response.text);
+    assert!(!response.text.contains("/nonexistent/bin"), "{}", response.text);
+}
+
+#[actix_web::test]
+#[ignore = "needs TEST_DATABASE_URL"]
+async fn liveness_does_not_touch_dependencies() {
+    let app = test_app!();
+
+    let response = app.get("/api/health/live").await;
+    assert_eq!(response.status, StatusCode::OK);
+    assert_eq!(response.body["message"], "alive");
+}
+
+#[actix_web::test]
+#[ignore = "needs TEST_DATABASE_URL"]
+async fn readiness_reports_a_disconnected_publisher() {
+    let app = test_app!();
+    app.publisher.set_connected(false);
+
+    let response = app.get("/api/health/ready").await;
+    assert_eq!(response.status, StatusCode::SERVICE_UNAVAILABLE, "{}", response.body);
+    let publisher = component(&response.body, app.state.publisher.name());
+    assert_eq!(publisher["status"], "down", "{}", publisher);
+    assert_eq!(publisher["detail"], "not connected; reconnecting");
+
+    // Liveness não depende do broker
+    assert_eq!(app.get("/api/health/live").await.status, StatusCode::OK);
+
+    app.publisher.set_connected(true);
+    let response = app.get("/api/health/ready").await;
+    assert_eq!(response.status, StatusCode::OK, "{}", response.body);
+}
@@ -0,0 +1,92 @@
+// backend/src/tests/households.rs
+//! Famílias: integrantes e contatos compartilhados
+use actix_web::{http::StatusCode, test::TestRequest};
+use serde_json::json;
+use uuid::Uuid;
+
+use super::{create_user, test_app, TestApp};
+
+async fn create_child(app: &TestApp, name: &str, household_id: Uuid) -> Uuid {
+    let user_id = create_user(app).await;
+    app.create("/api/students", "student", &json!({
+        "user_id": user_id,
+        "name": name,
+        "email": format!("{}@example.com", Uuid::new_v4().simple()),
+        "age": 9,
+        "household_id": household_id,
+    }))
+    .await
+}
+
+#[actix_web::test]
+#[ignore = "needs TEST_DATABASE_URL"]
+async fn household_groups_siblings_and_shared_contacts() {
+    let app = test_app!();
+    let household_id = app.create("/api/households", "household", &json!({"name": "Família Lima"})).await;
+    let parent_user = create_user(&app).await;
+    let parent_id = app
+        .create("/api/parents", "parent", &json!({
+            "user_id": parent_user,
+            "name": "Maria Lima",
+            "email": "maria@example.com",
+            "household_id": household_id,
+        }))
+        .await;
+    let pedro = create_child(&app, "Pedro Lima", household_id).await;
+    let ana = create_child(&app, "Ana Lima", household_id).await;
+    app.create("/api/addresses", "address", &json!({
+        "household_id": household_id,
+        "street": "Rua das Flores, 10",
+        "city": "Recife",
+        "state": "PE",
+        "zip_code": "50000-000",
+    }))
+    .await;
+    app.create("/api/phones", "phone", &json!({"household_id": household_id, "number": "8133330000"})).await;
+
+    let members = app.get(&format!("/api/households/{}/members", household_id)).await;
+    assert_eq!(members.status, StatusCode::OK, "{}", members.body);
+    let members = &members.body["members"];
+    assert_eq!(members["parents"][0]["id"], json!(parent_id));
+    assert_eq!(members["students"].as_array().map(Vec::len), Some(2));
+    assert_eq!(members["students"][0]["id"], json!(ana));
+    assert_eq!(members["students"][1]["id"], json!(pedro));
+    assert_eq!(members["addresses"].as_array().map(Vec::len), Some(1));
+
+    // O endereço e o telefone da família aparecem no perfil de cada irmão
+    for student_id in [pedro, ana] {
+        let profile = app.get(&format!("/api/students/{}/profile?include=contacts", student_id)).await;
+        let contacts = &profile.body["profile"]["contacts"];
+        assert_eq!(contacts["addresses"][0]["city"], "Recife", "{}", profile.body);
+        assert_eq!(contacts["phones"][0]["number"], "8133330000");
+    }
+
+    // Sem a família, os irmãos continuam cadastrados e os contatos dela somem
+    assert_eq!(app.delete(&format!("/api/households/{}", household_id)).await.status, StatusCode::NO_CONTENT);
+    let student = app.get(&format!("/api/students/{}", pedro)).await;
+    assert_eq!(student.body["student"]["household_id"], json!(null));
+    let addresses = app.get(&format!("/api/addresses?household_id={}", household_id)).await;
+    assert_eq!(addresses.body["addresses"], json!([]));
+}
+
+#[actix_web::test]
+#[ignore = "needs TEST_DATABASE_URL"]
+async fn children_are_not_listed_by_claimed_user() {
+    let app = test_app!();
+    let parent_user = create_user(&app).await;
+    let household_id = app.create("/api/households", "household", &json!({"name": "Família Souza"})).await;
+    app.create("/api/parents", "parent", &json!({
+        "user_id": parent_user,
+        "name": "Rita Souza",
+        "email": "rita@example.com",
+        "household_id": household_id,
+    }))
+    .await;
+    create_child(&app, "Caio Souza", household_id).await;
+
+    // `X-User-Id` não é autenticação: sem ela não há rota de "meus filhos"
+    let request = TestRequest::get().uri("/api/me/children").insert_header(("x-user-id", parent_user.to_string()));
+    let children = app.send(request).await;
+    assert_eq!(children.status, StatusCode::NOT_FOUND, "{}", children.body);
+    assert!(children.body["students"].is_null());
+}
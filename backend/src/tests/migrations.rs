@@ -0,0 +1,77 @@
+// backend/src/tests/migrations.rs
+//! Migrations embutidas: aplicadas em ordem, uma vez só, com o schema usado
+//! pelos handlers
+use super::{migrate, test_app};
+
+#[actix_web::test]
+#[ignore = "needs TEST_DATABASE_URL"]
+async fn embedded_migrations_are_applied_once() {
+    let app = test_app!();
+
+    let applied: Vec<(i64, bool)> =
+        sqlx::query_as("SELECT version, success FROM _sqlx_migrations ORDER BY version")
+            .fetch_all(app.db())
+            .await
+            .unwrap();
+    let embedded = std::fs::read_dir(concat!(env!("CARGO_MANIFEST_DIR"), "/migrations"))
+        .unwrap()
+        .filter(|entry| {
+            entry
+                .as_ref()
+                .unwrap()
+                .file_name()
+                .to_string_lossy()
+                .ends_with(".up.sql")
+        })
+        .count();
+    assert_eq!(applied.len(), embedded);
+    assert!(applied.iter().all(|(_, success)| *success));
+    let (latest, _) = migrate::latest().expect("embedded migrations");
+    assert_eq!(applied.last().map(|(version, _)| *version), Some(latest));
+
+    // Rodar de novo (ex.: `backend migrate` com o servidor já migrado) não muda nada
+    migrate::run(app.db()).await.expect("second run");
+    let count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM _sqlx_migrations")
+        .fetch_one(app.db())
+        .await
+        .unwrap();
+    assert_eq!(count as usize, embedded);
+}
+
+#[actix_web::test]
+#[ignore = "needs TEST_DATABASE_URL"]
+async fn schema_has_the_tables_the_handlers_use() {
+    let app = test_app!();
+
+    let columns: Vec<(String, String)> = sqlx::query_as(
+        r#"
+        SELECT table_name::text, column_name::text
+        FROM information_schema.columns
+        WHERE table_schema = 'public' AND table_name IN ('students', 'groups', 'attendances')
+        "#,
+    )
+    .fetch_all(app.db())
+    .await
+    .unwrap();
+    let has = |table: &str, column: &str| columns.iter().any(|(t, c)| t == table && c == column);
+
+    // `students.group_id` aponta para `groups`; o antigo `group_type` não existe mais
+    assert!(has("students", "group_id"));
+    assert!(!has("students", "group_type"));
+    assert!(has("groups", "id"));
+    assert!(has("attendances", "status"));
+
+    let references: Option<String> = sqlx::query_scalar(
+        r#"
+        SELECT ccu.table_name::text
+        FROM information_schema.table_constraints tc
+        JOIN information_schema.key_column_usage kcu ON kcu.constraint_name = tc.constraint_name
+        JOIN information_schema.constraint_column_usage ccu ON ccu.constraint_name = tc.constraint_name
+        WHERE tc.constraint_type = 'FOREIGN KEY' AND tc.table_name = 'students' AND kcu.column_name = 'group_id'
+        "#,
+    )
+    .fetch_optional(app.db())
+    .await
+    .unwrap();
+    assert_eq!(references.as_deref(), Some("groups"));
+}
@@ -0,0 +1,65 @@
+// backend/src/tests/monitoring.rs
+//! `GET /metrics`. O registro é global ao processo e compartilhado pelos
+//! testes, então só se verifica a presença das séries, nunca valores exatos.
+use actix_web::http::StatusCode;
+
+use super::{create_student, test_app, TestApp};
+
+async fn scrape(app: &TestApp) -> String {
+    let response = app.get("/metrics").await;
+    assert_eq!(response.status, StatusCode::OK, "{}", response.text);
+    assert!(response.content_type.as_deref().unwrap_or_default().starts_with("text/plain"));
+    response.text
+}
+
+/// Linhas de uma métrica que contêm todos os rótulos pedidos
+fn series<'a>(text: &'a str, name: &str, labels: &[&str]) -> Vec<&'a str> {
+    text.lines()
+        .filter(|line| line.starts_with(&format!("{}{{", name)))
+        .filter(|line| labels.iter().all(|label| line.contains(label)))
+        .collect()
+}
+
+#[actix_web::test]
+#[ignore = "needs TEST_DATABASE_URL"]
+async fn requests_are_counted_by_route_pattern() {
+    let app = test_app!();
+    let student_id = create_student(&app).await;
+
+    let response = app.get(&format!("/api/students/{}", student_id)).await;
+    assert_eq!(response.status, StatusCode::OK, "{}", response.body);
+    app.get("/api/nao-existe").await;
+
+    let text = scrape(&app).await;
+    // O rótulo é o padrão da rota, não a URL com o id
+    let route = r#"route="/api/students/{id}""#;
+    assert!(!series(&text, "http_requests_total", &[route, r#"method="GET""#, r#"status="200""#]).is_empty(), "{}", text);
+    assert!(!series(&text, "http_request_duration_seconds_bucket", &[route]).is_empty(), "{}", text);
+    assert!(!text.contains(&student_id.to_string()));
+    assert!(!series(&text, "http_requests_total", &[r#"route="unmatched""#, r#"status="404""#]).is_empty());
+}
+
+#[actix_web::test]
+#[ignore = "needs TEST_DATABASE_URL"]
+async fn pool_and_upload_metrics_are_exported() {
+    let app = test_app!();
+
+    let body = vec![b'x'; 4096];
+    let response = app.upload("/api/videos/uploads", "metricas.mp4", &body).await;
+    assert_eq!(response.status, StatusCode::OK, "{}", response.body);
+
+    let text = scrape(&app).await;
+    let uploaded: u64 = text
+        .lines()
+        .find_map(|line| line.strip_prefix("upload_bytes_total "))
+        .and_then(|value| value.parse().ok())
+        .expect("upload_bytes_total");
+    assert!(uploaded >= body.len() as u64);
+
+    for state in ["idle", "in_use", "max"] {
+        let label = format!(r#"state="{}""#, state);
+        assert!(!series(&text, "db_pool_connections", &[&label]).is_empty(), "{}", text);
+    }
+    let max = series(&text, "db_pool_connections", &[r#"state="max""#]);
+    assert!(max[0].ends_with(&format!(" {}", app.state.config.database.max_connections)));
+}
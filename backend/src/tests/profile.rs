@@ -0,0 +1,89 @@
+// backend/src/tests/profile.rs
+//! Perfil agregado do aluno (`GET /students/{id}/profile`)
+use actix_web::http::StatusCode;
+use chrono::{Duration, Utc};
+use serde_json::json;
+use uuid::Uuid;
+
+use super::{create_user, test_app};
+
+#[actix_web::test]
+#[ignore = "needs TEST_DATABASE_URL"]
+async fn profile_aggregates_related_records() {
+    let app = test_app!();
+    let parent_id = app.create("/api/parents", "parent", &json!({"name": "Maria Lima", "email": "maria@example.com"})).await;
+    let group_id = app.create("/api/groups", "group", &json!({"name": "pequenos"})).await;
+    let user_id = create_user(&app).await;
+    let student_id = app
+        .create("/api/students", "student", &json!({
+            "user_id": user_id,
+            "name": "Pedro Lima",
+            "email": "pedro@example.com",
+            "age": 9,
+            "parent_id": parent_id,
+            "group_id": group_id,
+        }))
+        .await;
+
+    app.create("/api/phones", "phone", &json!({"parent_id": parent_id, "number": "81999990000"})).await;
+    app.create("/api/addresses", "address", &json!({
+        "student_id": student_id,
+        "street": "Rua das Flores, 10",
+        "city": "Recife",
+        "state": "PE",
+        "zip_code": "50000-000",
+    }))
+    .await;
+    let today = Utc::now().date_naive();
+    for (days_ago, status) in [(0, "presente"), (1, "falta"), (2, "presente"), (60, "presente")] {
+        let date = today - Duration::days(days_ago);
+        app.create("/api/attendances", "attendance", &json!({"student_id": student_id, "date": date, "status": status}))
+            .await;
+    }
+    app.create("/api/warnings", "warning", &json!({"student_id": student_id, "reason": "Atraso"})).await;
+    app.create("/api/photos", "photo", &json!({"student_id": student_id, "filename": "treino.jpg"})).await;
+
+    let response = app.get(&format!("/api/students/{}/profile", student_id)).await;
+    assert_eq!(response.status, StatusCode::OK, "{}", response.body);
+    let profile = &response.body["profile"];
+    assert_eq!(profile["student"]["id"], json!(student_id));
+    assert_eq!(profile["parent"]["id"], json!(parent_id));
+    assert_eq!(profile["group"]["name"], "pequenos");
+    assert_eq!(profile["contacts"]["addresses"].as_array().map(Vec::len), Some(1));
+    assert_eq!(profile["contacts"]["phones"][0]["number"], "81999990000");
+    // O registro de 60 dias atrás fica fora da janela padrão de 30 dias
+    assert_eq!(profile["attendance"]["total"], 3);
+    assert_eq!(profile["attendance"]["present"], 2);
+    assert_eq!(profile["attendance"]["absent"], 1);
+    assert_eq!(profile["attendance"]["last_date"], json!(today));
+    assert_eq!(profile["attendance"]["recent"].as_array().map(Vec::len), Some(4));
+    assert_eq!(profile["warnings"]["count"], 1);
+    assert_eq!(profile["media"], json!({"photos": 1, "documents": 0, "submissions": 0}));
+
+    let wider = app.get(&format!("/api/students/{}/profile?include=attendance&attendance_days=90", student_id)).await;
+    assert_eq!(wider.body["profile"]["attendance"]["total"], 4);
+}
+
+#[actix_web::test]
+#[ignore = "needs TEST_DATABASE_URL"]
+async fn profile_include_selects_sections() {
+    let app = test_app!();
+    let student_id = super::create_student(&app).await;
+
+    let response = app.get(&format!("/api/students/{}/profile?include=media,parent", student_id)).await;
+    assert_eq!(response.status, StatusCode::OK, "{}", response.body);
+    let profile = response.body["profile"].as_object().expect("profile object");
+    let mut keys: Vec<&str> = profile.keys().map(String::as_str).collect();
+    keys.sort_unstable();
+    assert_eq!(keys, ["media", "parent", "student"]);
+    assert!(profile["parent"].is_null());
+
+    let only_student = app.get(&format!("/api/students/{}/profile?include=", student_id)).await;
+    assert_eq!(only_student.body["profile"].as_object().map(|p| p.len()), Some(1));
+
+    let unknown = app.get(&format!("/api/students/{}/profile?include=grades", student_id)).await;
+    assert_eq!(unknown.status, StatusCode::BAD_REQUEST);
+
+    let missing = app.get(&format!("/api/students/{}/profile", Uuid::new_v4())).await;
+    assert_eq!(missing.status, StatusCode::NOT_FOUND);
+}
@@ -0,0 +1,116 @@
+// backend/src/tests/relay.rs
+//! Relay da outbox: reserva, publicação em ordem e estacionamento após falhas
+use serde_json::{json, Value};
+use std::time::Duration;
+
+use super::{test_app, TestApp};
+use crate::outbox;
+
+async fn enqueue(app: &TestApp, payload: Value) {
+    let mut tx = app.db().begin().await.expect("begin");
+    outbox::enqueue(&mut tx, "video.created", &payload, None).await.expect("enqueue");
+    tx.commit().await.expect("commit");
+    app.state.outbox.notify();
+}
+
+#[actix_web::test]
+#[ignore = "needs TEST_DATABASE_URL"]
+async fn failing_message_is_parked_after_max_attempts() {
+    let app = test_app!(|config| config.outbox.max_attempts = 2);
+    app.publisher.reject(json!({"n": 1}));
+
+    enqueue(&app, json!({"n": 1})).await;
+    enqueue(&app, json!({"n": 2})).await;
+
+    // A segunda só sai depois que a primeira é estacionada
+    let jobs = app.wait_for_jobs(1).await;
+    assert_eq!(jobs.len(), 1);
+    assert_eq!(jobs[0].payload, json!({"n": 2}));
+
+    let (attempts, last_error, parked, claimed): (i32, Option<String>, bool, bool) = sqlx::query_as(
+        r#"
+        SELECT attempts, last_error, parked_at IS NOT NULL, claimed_until IS NOT NULL
+        FROM outbox
+        WHERE payload = '{"n": 1}'
+        "#,
+    )
+    .fetch_one(app.db())
+    .await
+    .expect("parked message");
+    assert_eq!(attempts, 2);
+    assert!(last_error.is_some_and(|error| error.contains("rejected")));
+    assert!(parked);
+    assert!(!claimed);
+
+    let (pending,): (i64,) = sqlx::query_as("SELECT COUNT(*) FROM outbox WHERE sent_at IS NULL AND parked_at IS NULL")
+        .fetch_one(app.db())
+        .await
+        .expect("count pending");
+    assert_eq!(pending, 0);
+}
+
+#[actix_web::test]
+#[ignore = "needs TEST_DATABASE_URL"]
+async fn claimed_messages_wait_for_the_lease_to_expire() {
+    let app = test_app!();
+
+    // Reservada por outro relay, que ainda está publicando
+    sqlx::query(
+        r#"
+        INSERT INTO outbox (routing_key, payload, claimed_until)
+        VALUES ('video.created', '{"n": 1}', NOW() + INTERVAL '1 hour')
+        "#,
+    )
+    .execute(app.db())
+    .await
+    .expect("insert claimed message");
+    app.state.outbox.notify();
+
+    tokio::time::sleep(Duration::from_millis(300)).await;
+    assert!(app.publisher.jobs().is_empty());
+
+    // O relay que a reservou caiu: a reserva expira e a mensagem sai
+    sqlx::query("UPDATE outbox SET claimed_until = NOW() - INTERVAL '1 second'")
+        .execute(app.db())
+        .await
+        .expect("expire claim");
+    app.state.outbox.notify();
+
+    let jobs = app.wait_for_jobs(1).await;
+    assert_eq!(jobs[0].payload, json!({"n": 1}));
+}
+
+#[actix_web::test]
+#[ignore = "needs TEST_DATABASE_URL"]
+async fn publishing_does_not_hold_row_locks() {
+    let app = test_app!();
+    let pause = app.publisher.pause().await;
+    enqueue(&app, json!({"n": 1})).await;
+
+    // Espera o relay reservar a mensagem e ficar preso na publicação
+    let mut claimed = false;
+    for _ in 0..100 {
+        let (count,): (i64,) = sqlx::query_as("SELECT COUNT(*) FROM outbox WHERE claimed_until IS NOT NULL")
+            .fetch_one(app.db())
+            .await
+            .expect("count claimed");
+        if count == 1 {
+            claimed = true;
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(20)).await;
+    }
+    assert!(claimed, "relay never claimed the message");
+
+    // A reserva já foi gravada: a linha não fica travada durante a publicação
+    let mut tx = app.db().begin().await.expect("begin");
+    let locked: Option<(i64,)> = sqlx::query_as("SELECT id FROM outbox FOR UPDATE NOWAIT")
+        .fetch_optional(&mut tx)
+        .await
+        .expect("row is not locked");
+    assert!(locked.is_some());
+    tx.rollback().await.expect("rollback");
+
+    drop(pause);
+    assert_eq!(app.wait_for_jobs(1).await[0].payload, json!({"n": 1}));
+}
@@ -0,0 +1,199 @@
+// backend/src/tests/schedules.rs
+//! Horários fixos das turmas e o calendário com as mudanças de treino aplicadas
+use actix_web::http::StatusCode;
+use serde_json::{json, Value};
+use uuid::Uuid;
+
+use super::{test_app, TestApp};
+
+async fn create_schedule(app: &TestApp, group_id: Uuid, weekday: i32) -> Uuid {
+    app.create(&format!("/api/groups/{}/schedules", group_id), "schedule", &json!({
+        "weekday": weekday,
+        "start_time": "18:00:00",
+        "end_time": "19:30:00",
+        "location": "Campo 2",
+        "valid_from": "2025-03-03",
+        "valid_until": "2025-03-31",
+    }))
+    .await
+}
+
+fn session<'a>(sessions: &'a Value, occurrence: &str) -> &'a Value {
+    sessions
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|session| session["occurrence"] == occurrence)
+        .unwrap_or_else(|| panic!("{} missing from {}", occurrence, sessions))
+}
+
+#[actix_web::test]
+#[ignore = "needs TEST_DATABASE_URL"]
+async fn schedules_are_validated() {
+    let app = test_app!();
+    let group_id = app.create("/api/groups", "group", &json!({"name": "Sub-11"})).await;
+    let uri = format!("/api/groups/{}/schedules", group_id);
+
+    let inverted = app
+        .post(&uri, &json!({"weekday": 2, "start_time": "19:00:00", "end_time": "18:00:00", "valid_from": "2025-03-03"}))
+        .await;
+    assert_eq!(inverted.status, StatusCode::BAD_REQUEST, "{}", inverted.body);
+
+    let bad_weekday = app
+        .post(&uri, &json!({"weekday": 8, "start_time": "18:00:00", "end_time": "19:00:00", "valid_from": "2025-03-03"}))
+        .await;
+    assert_eq!(bad_weekday.status, StatusCode::BAD_REQUEST, "{}", bad_weekday.body);
+
+    let missing_group = app
+        .post(
+            &format!("/api/groups/{}/schedules", Uuid::new_v4()),
+            &json!({"weekday": 2, "start_time": "18:00:00", "end_time": "19:00:00", "valid_from": "2025-03-03"}),
+        )
+        .await;
+    assert_eq!(missing_group.status, StatusCode::NOT_FOUND);
+
+    let schedule_id = create_schedule(&app, group_id, 2).await;
+    let moved = app.patch(&format!("{}/{}", uri, schedule_id), &json!({"weekday": 4, "location": "Quadra"})).await;
+    assert_eq!(moved.status, StatusCode::OK, "{}", moved.body);
+    assert_eq!(moved.body["schedule"]["weekday"], 4);
+    assert_eq!(moved.body["schedule"]["start_time"], "18:00:00");
+
+    let listed = app.get(&uri).await;
+    assert_eq!(listed.body["schedules"].as_array().map(Vec::len), Some(1), "{}", listed.body);
+
+    // O horário é da turma do caminho
+    let other_group = app.create("/api/groups", "group", &json!({"name": "Sub-13"})).await;
+    let wrong = app.delete(&format!("/api/groups/{}/schedules/{}", other_group, schedule_id)).await;
+    assert_eq!(wrong.status, StatusCode::NOT_FOUND);
+    assert_eq!(app.delete(&format!("{}/{}", uri, schedule_id)).await.status, StatusCode::NO_CONTENT);
+}
+
+#[actix_web::test]
+#[ignore = "needs TEST_DATABASE_URL"]
+async fn calendar_applies_cancellations_and_reschedules() {
+    let app = test_app!();
+    let group_id = app.create("/api/groups", "group", &json!({"name": "Sub-9"})).await;
+    let monday = create_schedule(&app, group_id, 1).await;
+    let wednesday = create_schedule(&app, group_id, 3).await;
+
+    let cancel = json!({"schedule_id": monday, "old_date": "2025-03-10T18:00:00", "reason": "Chuva"});
+    app.create("/api/schedule_changes", "schedule_change", &cancel).await;
+    let duplicate = app.post("/api/schedule_changes", &cancel).await;
+    assert_eq!(duplicate.status, StatusCode::CONFLICT, "{}", duplicate.body);
+
+    let moved = app
+        .create("/api/schedule_changes", "schedule_change", &json!({
+            "schedule_id": wednesday,
+            "old_date": "2025-03-12T18:00:00",
+            "new_date": "2025-03-13T19:00:00",
+            "reason": "Feriado",
+        }))
+        .await;
+    // Sessão prevista fora do período, remarcada para dentro dele
+    app.create("/api/schedule_changes", "schedule_change", &json!({
+        "schedule_id": monday,
+        "old_date": "2025-03-17T18:00:00",
+        "new_date": "2025-03-15T10:00:00",
+    }))
+    .await;
+    // Mudança antiga, só com a turma
+    app.create("/api/schedule_changes", "schedule_change", &json!({
+        "group_id": group_id,
+        "old_date": "2025-03-05T18:00:00",
+        "new_date": "2025-03-05T20:00:00",
+    }))
+    .await;
+
+    let not_a_session = app
+        .post("/api/schedule_changes", &json!({"schedule_id": monday, "old_date": "2025-03-11T18:00:00"}))
+        .await;
+    assert_eq!(not_a_session.status, StatusCode::BAD_REQUEST, "{}", not_a_session.body);
+
+    let calendar = app.get(&format!("/api/groups/{}/calendar?from=2025-03-03&to=2025-03-16", group_id)).await;
+    assert_eq!(calendar.status, StatusCode::OK, "{}", calendar.body);
+    let sessions = &calendar.body["sessions"];
+    let starts: Vec<&str> = sessions.as_array().unwrap().iter().filter_map(|s| s["starts_at"].as_str()).collect();
+    assert_eq!(
+        starts,
+        [
+            "2025-03-03T18:00:00",
+            "2025-03-05T20:00:00",
+            "2025-03-10T18:00:00",
+            "2025-03-13T19:00:00",
+            "2025-03-15T10:00:00",
+        ]
+    );
+
+    assert_eq!(session(sessions, "2025-03-03T18:00:00")["status"], "scheduled");
+    assert_eq!(session(sessions, "2025-03-03T18:00:00")["location"], "Campo 2");
+    assert_eq!(session(sessions, "2025-03-05T18:00:00")["status"], "rescheduled");
+    assert_eq!(session(sessions, "2025-03-10T18:00:00")["status"], "cancelled");
+    assert_eq!(session(sessions, "2025-03-10T18:00:00")["reason"], "Chuva");
+    let thursday = session(sessions, "2025-03-12T18:00:00");
+    assert_eq!(thursday["change_id"], json!(moved));
+    assert_eq!(thursday["ends_at"], "2025-03-13T20:30:00");
+    assert_eq!(session(sessions, "2025-03-17T18:00:00")["schedule_id"], json!(monday));
+
+    let inverted = app.get(&format!("/api/groups/{}/calendar?from=2025-03-16&to=2025-03-03", group_id)).await;
+    assert_eq!(inverted.status, StatusCode::BAD_REQUEST);
+    let too_long = app.get(&format!("/api/groups/{}/calendar?from=2025-01-01&to=2026-06-01", group_id)).await;
+    assert_eq!(too_long.status, StatusCode::BAD_REQUEST);
+}
+
+#[actix_web::test]
+#[ignore = "needs TEST_DATABASE_URL"]
+async fn schedule_fields_can_be_cleared() {
+    let app = test_app!();
+    let group_id = app.create("/api/groups", "group", &json!({"name": "Sub-11"})).await;
+    let schedule_id = create_schedule(&app, group_id, 2).await;
+    let uri = format!("/api/groups/{}/schedules/{}", group_id, schedule_id);
+
+    // Campo ausente fica como está
+    let untouched = app.patch(&uri, &json!({"end_time": "20:00:00"})).await;
+    assert_eq!(untouched.status, StatusCode::OK, "{}", untouched.body);
+    assert_eq!(untouched.body["schedule"]["location"], "Campo 2");
+    assert_eq!(untouched.body["schedule"]["valid_until"], "2025-03-31");
+
+    let cleared = app.patch(&uri, &json!({"location": null, "valid_until": null})).await;
+    assert_eq!(cleared.status, StatusCode::OK, "{}", cleared.body);
+    assert_eq!(cleared.body["schedule"]["location"], Value::Null);
+    assert_eq!(cleared.body["schedule"]["valid_until"], Value::Null);
+    assert_eq!(cleared.body["schedule"]["end_time"], "20:00:00");
+
+    let blank = app.patch(&uri, &json!({"location": "  "})).await;
+    assert_eq!(blank.status, StatusCode::BAD_REQUEST, "{}", blank.body);
+}
+
+#[actix_web::test]
+#[ignore = "needs TEST_DATABASE_URL"]
+async fn moving_a_schedule_cannot_orphan_its_changes() {
+    let app = test_app!();
+    let group_id = app.create("/api/groups", "group", &json!({"name": "Sub-9"})).await;
+    let schedule_id = create_schedule(&app, group_id, 1).await;
+    let uri = format!("/api/groups/{}/schedules/{}", group_id, schedule_id);
+
+    let cancel = json!({"schedule_id": schedule_id, "old_date": "2025-03-17T18:00:00", "reason": "Feriado"});
+    let change_id = app.create("/api/schedule_changes", "schedule_change", &cancel).await;
+
+    for orphaning in [
+        json!({"weekday": 2}),
+        json!({"start_time": "17:00:00"}),
+        json!({"valid_until": "2025-03-16"}),
+        json!({"valid_from": "2025-03-18"}),
+    ] {
+        let response = app.patch(&uri, &orphaning).await;
+        assert_eq!(response.status, StatusCode::CONFLICT, "{}: {}", orphaning, response.body);
+    }
+    let unchanged = app.get(&format!("/api/groups/{}/schedules", group_id)).await;
+    assert_eq!(unchanged.body["schedules"][0]["weekday"], 1, "{}", unchanged.body);
+    assert_eq!(unchanged.body["schedules"][0]["start_time"], "18:00:00");
+
+    // A sessão continua no horário: a alteração passa
+    let extended = app.patch(&uri, &json!({"valid_until": null, "end_time": "19:00:00"})).await;
+    assert_eq!(extended.status, StatusCode::OK, "{}", extended.body);
+
+    // Sem a mudança de treino o horário pode mudar de dia
+    assert_eq!(app.delete(&format!("/api/schedule_changes/{}", change_id)).await.status, StatusCode::NO_CONTENT);
+    let moved = app.patch(&uri, &json!({"weekday": 2})).await;
+    assert_eq!(moved.status, StatusCode::OK, "{}", moved.body);
+}
@@ -0,0 +1,114 @@
+// backend/src/tests/tags.rs
+//! Tags dos vídeos: troca do conjunto, fusão e contagem de uso
+use actix_web::http::StatusCode;
+use serde_json::{json, Value};
+use uuid::Uuid;
+
+use super::{test_app, TestApp};
+
+async fn create_video(app: &TestApp, title: &str) -> Uuid {
+    app.create("/api/videos", "video", &json!({"title": title})).await
+}
+
+// Nomes das tags do vídeo, em ordem alfabética sem diferenciar maiúsculas
+async fn tag_names(app: &TestApp, video_id: Uuid) -> Vec<String> {
+    let response = app.get(&format!("/api/videos/{}/tags", video_id)).await;
+    assert_eq!(response.status, StatusCode::OK, "{}", response.body);
+    let mut names: Vec<String> = response.body["tags"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|tag| tag["name"].as_str().unwrap().to_string())
+        .collect();
+    names.sort_by_key(|name| name.to_lowercase());
+    names
+}
+
+#[actix_web::test]
+#[ignore = "needs TEST_DATABASE_URL"]
+async fn video_tags_are_replaced_as_a_set() {
+    let app = test_app!();
+    let video_id = create_video(&app, "Treino de passe").await;
+    let uri = format!("/api/videos/{}/tags", video_id);
+
+    let set = app.put(&uri, &json!({"tags": ["passe", " Drible ", "PASSE"]})).await;
+    assert_eq!(set.status, StatusCode::OK, "{}", set.body);
+    assert_eq!(tag_names(&app, video_id).await, ["Drible", "passe"]);
+
+    // A troca remove o que não veio e reaproveita as tags existentes, sem
+    // diferenciar maiúsculas
+    let replaced = app.put(&uri, &json!({"tags": ["drible", "cabeceio"]})).await;
+    assert_eq!(replaced.status, StatusCode::OK, "{}", replaced.body);
+    assert_eq!(tag_names(&app, video_id).await, ["cabeceio", "Drible"]);
+    assert_eq!(app.get("/api/tags").await.body["results"], 3);
+
+    for invalid in [json!([" "]), json!(["x".repeat(51)])] {
+        let response = app.put(&uri, &json!({"tags": invalid})).await;
+        assert_eq!(response.status, StatusCode::BAD_REQUEST, "{}", response.body);
+    }
+    assert_eq!(tag_names(&app, video_id).await, ["cabeceio", "Drible"]);
+
+    let missing = format!("/api/videos/{}/tags", Uuid::new_v4());
+    assert_eq!(app.put(&missing, &json!({"tags": ["passe"]})).await.status, StatusCode::NOT_FOUND);
+    assert_eq!(app.get(&missing).await.status, StatusCode::NOT_FOUND);
+}
+
+#[actix_web::test]
+#[ignore = "needs TEST_DATABASE_URL"]
+async fn merging_tags_collapses_duplicate_links() {
+    let app = test_app!();
+    let both = create_video(&app, "Chute e finalização").await;
+    let only_source = create_video(&app, "Só chute").await;
+    app.put(&format!("/api/videos/{}/tags", both), &json!({"tags": ["chute", "finalização"]})).await;
+    app.put(&format!("/api/videos/{}/tags", only_source), &json!({"tags": ["chute"]})).await;
+
+    let tags = app.get("/api/tags?sort=name").await.body["tags"].clone();
+    let source = tags[0]["id"].as_str().unwrap().to_string();
+    let target = tags[1]["id"].as_str().unwrap().to_string();
+
+    let itself = app.post(&format!("/api/tags/{}/merge", source), &json!({"target_id": source})).await;
+    assert_eq!(itself.status, StatusCode::BAD_REQUEST, "{}", itself.body);
+    assert_eq!(itself.body["code"], "validation_failed");
+    let malformed = app.post(&format!("/api/tags/{}/merge", source), &json!({"target_id": "chute"})).await;
+    assert_eq!(malformed.status, StatusCode::BAD_REQUEST, "{}", malformed.body);
+
+    let merged = app.post(&format!("/api/tags/{}/merge", source), &json!({"target_id": target})).await;
+    assert_eq!(merged.status, StatusCode::OK, "{}", merged.body);
+    assert_eq!(merged.body["tag"]["name"], "finalização");
+
+    // O vídeo que tinha as duas fica com uma associação só
+    assert_eq!(tag_names(&app, both).await, ["finalização"]);
+    assert_eq!(tag_names(&app, only_source).await, ["finalização"]);
+    let videos = app.get(&format!("/api/tags/{}/videos", target)).await;
+    assert_eq!(videos.body["videos"].as_array().map(Vec::len), Some(2), "{}", videos.body);
+
+    assert_eq!(app.get(&format!("/api/tags/{}", source)).await.status, StatusCode::NOT_FOUND);
+    assert_eq!(app.get(&format!("/api/tags/{}/videos", source)).await.status, StatusCode::NOT_FOUND);
+    let again = app.post(&format!("/api/tags/{}/merge", source), &json!({"target_id": target})).await;
+    assert_eq!(again.status, StatusCode::NOT_FOUND, "{}", again.body);
+}
+
+#[actix_web::test]
+#[ignore = "needs TEST_DATABASE_URL"]
+async fn usage_counts_videos_per_tag() {
+    let app = test_app!();
+    for (title, tags) in [("Jogo 1", json!(["passe", "drible"])), ("Jogo 2", json!(["passe"]))] {
+        let video_id = create_video(&app, title).await;
+        app.put(&format!("/api/videos/{}/tags", video_id), &json!({"tags": tags})).await;
+    }
+    app.create("/api/tags", "tag", &json!({"name": "goleiro"})).await;
+
+    let usage = app.get("/api/tags/usage").await;
+    assert_eq!(usage.status, StatusCode::OK, "{}", usage.body);
+    let counts: Vec<(Value, Value)> = usage.body["tags"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|tag| (tag["name"].clone(), tag["video_count"].clone()))
+        .collect();
+    assert_eq!(counts, [
+        (json!("passe"), json!(2)),
+        (json!("drible"), json!(1)),
+        (json!("goleiro"), json!(0)),
+    ]);
+}
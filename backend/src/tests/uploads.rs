@@ -0,0 +1,155 @@
+// backend/src/tests/uploads.rs
+//! Envio de arquivos, inteiro ou em pedaços
+use actix_web::http::StatusCode;
+use serde_json::json;
+use uuid::Uuid;
+
+use super::test_app;
+
+#[actix_web::test]
+#[ignore = "needs TEST_DATABASE_URL"]
+async fn upload_whole_file() {
+    let app = test_app!();
+
+    let response = app.upload("/api/videos/uploads", "../../jogo.mp4", b"video bytes").await;
+    assert_eq!(response.status, StatusCode::OK, "{}", response.body);
+    // O diretório enviado pelo cliente é descartado
+    assert_eq!(response.body["file_url"], "/uploads/jogo.mp4");
+
+    let saved = std::fs::read(app.storage_dir.join("uploads").join("jogo.mp4")).expect("uploaded file");
+    assert_eq!(saved, b"video bytes");
+}
+
+#[actix_web::test]
+#[ignore = "needs TEST_DATABASE_URL"]
+async fn upload_without_file_is_rejected() {
+    let app = test_app!();
+
+    let response = app.multipart("/api/videos/uploads", &[]).await;
+    assert_eq!(response.status, StatusCode::BAD_REQUEST, "{}", response.body);
+}
+
+#[actix_web::test]
+#[ignore = "needs TEST_DATABASE_URL"]
+async fn chunked_upload_is_consolidated() {
+    let app = test_app!();
+    let video_id = app.create("/api/videos", "video", &json!({"title": "Treino em pedaços"})).await;
+
+    for (index, chunk) in [&b"primeiro-"[..], b"segundo-", b"terceiro"].iter().enumerate() {
+        let uri = format!("/api/videos/{}/upload/chunks/{}", video_id, index);
+        let response = app.upload(&uri, "blob", chunk).await;
+        assert_eq!(response.status, StatusCode::OK, "{}", response.body);
+        assert_eq!(response.body["chunk_index"], index);
+    }
+
+    let uri = format!("/api/videos/{}/upload/complete", video_id);
+    let response = app.post(&uri, &json!({"total_chunks": 3})).await;
+    assert_eq!(response.status, StatusCode::OK, "{}", response.body);
+
+    let chunks_dir = app.storage_dir.join("chunks");
+    let video = std::fs::read(chunks_dir.join(format!("{}.mp4", video_id))).expect("consolidated file");
+    assert_eq!(video, b"primeiro-segundo-terceiro");
+    assert!(!chunks_dir.join(format!("{}_chunk_0", video_id)).exists());
+}
+
+#[actix_web::test]
+#[ignore = "needs TEST_DATABASE_URL"]
+async fn chunked_upload_with_missing_chunk_is_rejected() {
+    let app = test_app!();
+    let video_id = app.create("/api/videos", "video", &json!({"title": "Pedaço faltando"})).await;
+
+    let uri = format!("/api/videos/{}/upload/chunks/0", video_id);
+    assert_eq!(app.upload(&uri, "blob", b"only one").await.status, StatusCode::OK);
+
+    let uri = format!("/api/videos/{}/upload/complete", video_id);
+    let response = app.post(&uri, &json!({"total_chunks": 2})).await;
+    assert_eq!(response.status, StatusCode::BAD_REQUEST, "{}", response.body);
+    assert_eq!(response.body["message"], "Chunk 1 was not uploaded");
+    assert!(app.publisher.jobs().is_empty());
+
+    // A finalização que falhou não apaga nada: basta mandar o que falta e repetir
+    let chunk = format!("/api/videos/{}/upload/chunks/1", video_id);
+    assert_eq!(app.upload(&chunk, "blob", b" and two").await.status, StatusCode::OK);
+    let response = app.post(&uri, &json!({"total_chunks": 2})).await;
+    assert_eq!(response.status, StatusCode::OK, "{}", response.body);
+    assert_eq!(app.wait_for_jobs(1).await[0].payload["video_id"], json!(video_id));
+
+    let chunks_dir = app.storage_dir.join("chunks");
+    let video = std::fs::read(chunks_dir.join(format!("{}.mp4", video_id))).expect("consolidated file");
+    assert_eq!(video, b"only one and two");
+}
+
+#[actix_web::test]
+#[ignore = "needs TEST_DATABASE_URL"]
+async fn empty_chunked_upload_is_rejected() {
+    let app = test_app!();
+    let video_id = app.create("/api/videos", "video", &json!({"title": "Sem pedaços"})).await;
+
+    let uri = format!("/api/videos/{}/upload/complete", video_id);
+    let response = app.post(&uri, &json!({"total_chunks": 0})).await;
+    assert_eq!(response.status, StatusCode::BAD_REQUEST, "{}", response.body);
+    assert_eq!(response.body["errors"][0]["field"], "total_chunks");
+    assert!(!app.storage_dir.join("chunks").join(format!("{}.mp4", video_id)).exists());
+    assert!(app.publisher.jobs().is_empty());
+}
+
+#[actix_web::test]
+#[ignore = "needs TEST_DATABASE_URL"]
+async fn chunked_upload_of_unknown_video_is_not_found() {
+    let app = test_app!();
+    let video_id = Uuid::new_v4();
+
+    let uri = format!("/api/videos/{}/upload/chunks/0", video_id);
+    assert_eq!(app.upload(&uri, "blob", b"orphan").await.status, StatusCode::NOT_FOUND);
+    assert!(!app.storage_dir.join("chunks").join(format!("{}_chunk_0", video_id)).exists());
+
+    let uri = format!("/api/videos/{}/upload/complete", video_id);
+    let response = app.post(&uri, &json!({"total_chunks": 1})).await;
+    assert_eq!(response.status, StatusCode::NOT_FOUND, "{}", response.body);
+}
+
+#[actix_web::test]
+#[ignore = "needs TEST_DATABASE_URL"]
+async fn legacy_chunk_routes_are_aliased() {
+    let app = test_app!();
+    let video_id = app.create("/api/playes", "playe", &json!({"title": "Rota antiga"})).await;
+
+    let uri = format!("/api/upload_chunk/{}/0", video_id);
+    let response = app.upload(&uri, "blob", b"antigo").await;
+    assert_eq!(response.status, StatusCode::OK, "{}", response.body);
+
+    let uri = format!("/api/finish_upload/{}", video_id);
+    let response = app.post(&uri, &json!({"file_name": "antigo.mp4", "total_chunks": 1})).await;
+    assert_eq!(response.status, StatusCode::OK, "{}", response.body);
+
+    let video = std::fs::read(app.storage_dir.join("chunks").join(format!("{}.mp4", video_id))).expect("consolidated file");
+    assert_eq!(video, b"antigo");
+
+    let media_id = app.create("/api/videomedia", "videomedia", &json!({
+        "video_id": video_id, "video_path": "/media/antigo.m3u8", "status": "ready"
+    })).await;
+    let response = app.get(&format!("/api/videosmedias/{}", media_id)).await;
+    assert_eq!(response.status, StatusCode::OK, "{}", response.body);
+    assert_eq!(response.body["videomedia"]["video_path"], "/media/antigo.m3u8");
+    assert_eq!(app.get("/api/videomedias").await.body["results"], 1);
+}
+
+#[actix_web::test]
+#[ignore = "needs TEST_DATABASE_URL"]
+async fn uploaded_names_cannot_leave_the_upload_dir() {
+    let app = test_app!();
+    let outside = app.storage_dir.join("fora.pdf");
+    let absolute = outside.to_string_lossy().into_owned();
+
+    for (uri, dir) in [("/api/documents/upload", "uploads"), ("/api/photos/upload", "static")] {
+        for name in [absolute.as_str(), "../fora.pdf"] {
+            let response = app.upload(uri, name, b"conteudo").await;
+            assert_eq!(response.status, StatusCode::OK, "{} {}: {}", uri, name, response.body);
+            assert!(!outside.exists(), "{} {} wrote outside {}", uri, name, dir);
+
+            let saved = app.storage_dir.join(dir).join("fora.pdf");
+            assert_eq!(std::fs::read(&saved).expect("sanitized file"), b"conteudo");
+            std::fs::remove_file(saved).unwrap();
+        }
+    }
+}
@@ -0,0 +1,194 @@
+// backend/src/tests/videos.rs
+//! Vídeos e a publicação do job de transcodificação pela outbox
+use actix_web::{
+    http::{header, StatusCode},
+    test::TestRequest,
+};
+use serde_json::json;
+
+use super::{test_app, TestApp};
+
+// Envia o arquivo do vídeo em um pedaço e finaliza o upload
+async fn upload_video(app: &TestApp, video_id: &str, request_id: &str) {
+    let uri = format!("/api/videos/{}/upload/chunks/0", video_id);
+    assert_eq!(app.upload(&uri, "blob", b"video bytes").await.status, StatusCode::OK);
+
+    let request = TestRequest::post()
+        .uri(&format!("/api/videos/{}/upload/complete", video_id))
+        .insert_header(("x-request-id", request_id))
+        .set_json(json!({"total_chunks": 1}));
+    let response = app.send(request).await;
+    assert_eq!(response.status, StatusCode::OK, "{}", response.body);
+}
+
+#[actix_web::test]
+#[ignore = "needs TEST_DATABASE_URL"]
+async fn video_crud_and_slug() {
+    let app = test_app!();
+
+    let created = app.post("/api/videos", &json!({"title": "Treino de Passe"})).await;
+    assert_eq!(created.status, StatusCode::CREATED, "{}", created.body);
+    assert_eq!(created.body["video"]["slug"], "treino-de-passe");
+    let id = created.body["video"]["id"].as_str().expect("video id").to_string();
+    let uri = format!("/api/videos/{}", id);
+
+    let updated = app.patch(&uri, &json!({"description": "Passes longos em dupla"})).await;
+    assert_eq!(updated.status, StatusCode::OK, "{}", updated.body);
+    assert_eq!(updated.body["video"]["description"], "Passes longos em dupla");
+
+    let by_slug = app.get("/api/videos/by-slug/treino-de-passe").await;
+    assert_eq!(by_slug.status, StatusCode::OK, "{}", by_slug.body);
+    assert_eq!(by_slug.body["video"]["id"], json!(id));
+
+    let list = app.get("/api/videos").await;
+    assert_eq!(list.body["videos"][0]["id"], json!(id));
+
+    assert_eq!(app.delete(&uri).await.status, StatusCode::NO_CONTENT);
+    assert_eq!(app.get(&uri).await.status, StatusCode::NOT_FOUND);
+}
+
+#[actix_web::test]
+#[ignore = "needs TEST_DATABASE_URL"]
+async fn slug_taken_mid_insert_is_retried() {
+    let app = test_app!();
+
+    // Outro vídeo grava o mesmo slug numa transação ainda aberta: o handler não
+    // o enxerga, tenta o mesmo slug e espera o commit para falhar e repetir
+    let mut other = app.db().begin().await.unwrap();
+    sqlx::query("INSERT INTO videos (title, slug) VALUES ('Treino de Chute', 'treino-de-chute')")
+        .execute(&mut other)
+        .await
+        .unwrap();
+
+    let body = json!({"title": "Treino de Chute"});
+    let (created, _) = tokio::join!(app.post("/api/videos", &body), async {
+        tokio::time::sleep(std::time::Duration::from_millis(300)).await;
+        other.commit().await.unwrap();
+    });
+    assert_eq!(created.status, StatusCode::CREATED, "{}", created.body);
+    assert_eq!(created.body["video"]["slug"], "treino-de-chute-2");
+}
+
+#[actix_web::test]
+#[ignore = "needs TEST_DATABASE_URL"]
+async fn completed_upload_publishes_transcode_job() {
+    let app = test_app!();
+
+    let created = app.post("/api/videos", &json!({"title": "Cobrança de falta"})).await;
+    assert_eq!(created.status, StatusCode::CREATED, "{}", created.body);
+    let video_id = created.body["video"]["id"].clone();
+
+    // Sem arquivo ainda não há o que transcodificar
+    let (queued,): (i64,) = sqlx::query_as("SELECT COUNT(*) FROM outbox")
+        .fetch_one(app.db())
+        .await
+        .expect("count outbox");
+    assert_eq!(queued, 0);
+
+    upload_video(&app, video_id.as_str().unwrap(), "test-request-1").await;
+    let jobs = app.wait_for_jobs(1).await;
+    assert_eq!(jobs.len(), 1);
+    assert_eq!(jobs[0].routing_key, app.state.config.amqp.routing_key);
+    assert_eq!(jobs[0].payload, json!({"video_id": video_id, "action": "transcode"}));
+    assert_eq!(jobs[0].request_id.as_deref(), Some("test-request-1"));
+}
+
+#[actix_web::test]
+#[ignore = "needs TEST_DATABASE_URL"]
+async fn transcode_job_waits_in_outbox_while_broker_is_down() {
+    let app = test_app!();
+    app.publisher.set_connected(false);
+
+    let created = app.post("/api/videos", &json!({"title": "Goleiro"})).await;
+    assert_eq!(created.status, StatusCode::CREATED, "{}", created.body);
+    upload_video(&app, created.body["video"]["id"].as_str().unwrap(), "test-request-2").await;
+
+    let (pending,): (i64,) = sqlx::query_as("SELECT COUNT(*) FROM outbox WHERE sent_at IS NULL")
+        .fetch_one(app.db())
+        .await
+        .expect("count pending");
+    assert_eq!(pending, 1);
+    assert!(app.publisher.jobs().is_empty());
+
+    app.publisher.set_connected(true);
+    let jobs = app.wait_for_jobs(1).await;
+    assert_eq!(jobs[0].payload["video_id"], created.body["video"]["id"]);
+}
+
+#[actix_web::test]
+#[ignore = "needs TEST_DATABASE_URL"]
+async fn failed_upload_does_not_publish() {
+    let app = test_app!();
+    let video_id = app.create("/api/videos", "video", &json!({"title": "Pedaço perdido"})).await;
+
+    let uri = format!("/api/videos/{}/upload/complete", video_id);
+    let incomplete = app.post(&uri, &json!({"total_chunks": 1})).await;
+    assert_eq!(incomplete.status, StatusCode::BAD_REQUEST, "{}", incomplete.body);
+
+    let (total,): (i64,) = sqlx::query_as("SELECT COUNT(*) FROM outbox")
+        .fetch_one(app.db())
+        .await
+        .expect("count outbox");
+    assert_eq!(total, 0);
+}
+
+#[actix_web::test]
+#[ignore = "needs TEST_DATABASE_URL"]
+async fn search_escapes_snippets_and_matches_tags() {
+    let app = test_app!();
+
+    app.post("/api/videos", &json!({"title": "Chute < passe & cabeceio"})).await;
+    let tagged = app.post("/api/videos", &json!({"title": "Aquecimento"})).await;
+    let tagged_id = tagged.body["video"]["id"].as_str().unwrap().to_string();
+    let tags = app.put(&format!("/api/videos/{}/tags", tagged_id), &json!({"tags": ["Goleiros"]})).await;
+    assert_eq!(tags.status, StatusCode::OK, "{}", tags.body);
+
+    let found = app.get("/api/videos/search?q=passe").await;
+    assert_eq!(found.status, StatusCode::OK, "{}", found.body);
+    assert_eq!(found.body["results"], 1);
+    let snippet = found.body["videos"][0]["snippet"].as_str().unwrap();
+    assert!(snippet.contains("Chute &lt; <mark>passe</mark> &amp;"), "{}", snippet);
+
+    let by_tag = app.get("/api/videos/search?q=goleiros").await;
+    assert_eq!(by_tag.body["videos"][0]["id"], json!(tagged_id), "{}", by_tag.body);
+
+    let huge_page = app.get(&format!("/api/videos/search?q=passe&page={}", usize::MAX)).await;
+    assert_eq!(huge_page.status, StatusCode::BAD_REQUEST);
+}
+
+#[actix_web::test]
+#[ignore = "needs TEST_DATABASE_URL"]
+async fn legacy_routes_link_to_the_concrete_successor() {
+    let app = test_app!();
+    let video_id = app.create("/api/videos", "video", &json!({"title": "Rota antiga"})).await;
+    let link = |video_id, media_id| format!("</api/videos/{}/media/{}>; rel=\"successor-version\"", video_id, media_id);
+
+    let created = app.post("/api/videomedia", &json!({
+        "video_id": video_id, "video_path": "/media/antigo.m3u8", "status": "ready"
+    })).await;
+    assert_eq!(created.status, StatusCode::OK, "{}", created.body);
+    let expected = format!("</api/videos/{}/media>; rel=\"successor-version\"", video_id);
+    assert_eq!(created.header(header::LINK), Some(expected.as_str()));
+    let media_id = created.body["videomedia"]["id"].as_str().unwrap().to_string();
+
+    for uri in [format!("/api/videosmedias/{}", media_id), format!("/api/all_videos/{}", media_id)] {
+        let response = app.get(&uri).await;
+        assert_eq!(response.status, StatusCode::OK, "{}", response.body);
+        assert_eq!(response.header(header::LINK), Some(link(video_id, &media_id).as_str()), "{}", uri);
+        assert_eq!(response.header(header::HeaderName::from_static("deprecation")), Some("true"));
+    }
+
+    // Só a listagem filtrada por vídeo tem uma rota nova equivalente
+    let filtered = app.get(&format!("/api/videomedias?video_id={}", video_id)).await;
+    assert_eq!(filtered.header(header::LINK), Some(expected.as_str()));
+    assert_eq!(app.get("/api/all_videos").await.header(header::LINK), None);
+
+    let deleted = app.delete(&format!("/api/videosmedias/{}", media_id)).await;
+    assert_eq!(deleted.status, StatusCode::NO_CONTENT);
+    assert_eq!(deleted.header(header::LINK), Some(link(video_id, &media_id).as_str()));
+
+    let missing = app.get(&format!("/api/all_videos/{}", media_id)).await;
+    assert_eq!(missing.status, StatusCode::NOT_FOUND);
+    assert_eq!(missing.header(header::LINK), None);
+    assert_eq!(missing.header(header::HeaderName::from_static("deprecation")), Some("true"));
+}
@@ -0,0 +1,176 @@
+// backend/src/validation.rs
+//! Validação declarativa dos corpos de requisição. Os schemas derivam
+//! `validator::Validate` e os handlers recebem `ValidatedJson<T>` no lugar de
+//! `Json<T>`: o corpo é validado antes de chegar ao banco e os erros voltam
+//! campo a campo (`ApiError::Fields`).
+use std::borrow::Cow;
+use std::ops::Deref;
+
+use actix_web::{dev::Payload, web::Json, FromRequest, HttpRequest};
+use chrono::{Datelike, Local, NaiveDate, NaiveTime};
+use futures_util::future::LocalBoxFuture;
+use serde::de::DeserializeOwned;
+use validator::{Validate, ValidationError};
+
+use crate::error::ApiError;
+
+/// `Json<T>` validado com `T::validate()`
+pub struct ValidatedJson<T>(pub T);
+
+impl<T> ValidatedJson<T> {
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T> Deref for ValidatedJson<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T> FromRequest for ValidatedJson<T>
+where
+    T: DeserializeOwned + Validate + 'static,
+{
+    type Error = actix_web::Error;
+    type Future = LocalBoxFuture<'static, Result<Self, Self::Error>>;
+
+    fn from_request(req: &HttpRequest, payload: &mut Payload) -> Self::Future {
+        // Erros de desserialização continuam passando pelo `JsonConfig` do main.rs
+        let json = Json::<T>::from_request(req, payload);
+        Box::pin(async move {
+            let value = json.await?.into_inner();
+            value.validate().map_err(ApiError::from)?;
+            Ok(ValidatedJson(value))
+        })
+    }
+}
+
+fn invalid(code: &'static str, message: String) -> ValidationError {
+    let mut error = ValidationError::new(code);
+    error.message = Some(Cow::from(message));
+    error
+}
+
+fn one_of(value: &str, allowed: &[&str], code: &'static str) -> Result<(), ValidationError> {
+    if allowed.contains(&value) {
+        Ok(())
+    } else {
+        Err(invalid(code, format!("must be one of: {}", allowed.join(", "))))
+    }
+}
+
+// Os valores abaixo espelham os CHECKs das migrations
+
+pub const PHONE_TYPES: &[&str] = &["home", "work", "mobile"];
+pub const ATTENDANCE_STATUSES: &[&str] = &["presente", "falta"];
+pub const USER_ROLES: &[&str] = &["admin", "parent", "student", "guardian", "coach"];
+pub const FILE_TYPES: &[&str] = &["video", "photo"];
+
+pub fn phone_type(value: &str) -> Result<(), ValidationError> {
+    one_of(value, PHONE_TYPES, "phone_type")
+}
+
+pub fn attendance_status(value: &str) -> Result<(), ValidationError> {
+    one_of(value, ATTENDANCE_STATUSES, "attendance_status")
+}
+
+pub fn user_role(value: &str) -> Result<(), ValidationError> {
+    one_of(value, USER_ROLES, "user_role")
+}
+
+pub fn file_type(value: &str) -> Result<(), ValidationError> {
+    one_of(value, FILE_TYPES, "file_type")
+}
+
+/// Texto obrigatório: espaços em branco não contam como preenchido
+pub fn not_blank(value: &str) -> Result<(), ValidationError> {
+    if value.trim().is_empty() {
+        Err(invalid("blank", "must not be blank".to_string()))
+    } else {
+        Ok(())
+    }
+}
+
+/// Cada nome da lista segue as regras de `tags.name` (não vazio, até 50 caracteres)
+pub fn tag_names(values: &[String]) -> Result<(), ValidationError> {
+    for value in values {
+        let len = value.trim().chars().count();
+        if len == 0 || len > 50 {
+            return Err(invalid("tag_name", format!("invalid tag name '{}': must have 1 to 50 characters", value)));
+        }
+    }
+    Ok(())
+}
+
+pub fn not_in_future(value: &NaiveDate) -> Result<(), ValidationError> {
+    if *value > Local::now().date_naive() {
+        Err(invalid("future_date", "must not be in the future".to_string()))
+    } else {
+        Ok(())
+    }
+}
+
+/// Idade completa em `today` de quem nasceu em `birth_date`
+pub fn age_on(birth_date: NaiveDate, today: NaiveDate) -> i32 {
+    let mut age = today.year() - birth_date.year();
+    if (today.month(), today.day()) < (birth_date.month(), birth_date.day()) {
+        age -= 1;
+    }
+    age
+}
+
+/// Quando `age` e `birth_date` vêm juntos, a idade tem que bater com a data de nascimento
+pub fn age_matches_birth_date(age: Option<i32>, birth_date: Option<NaiveDate>) -> Result<(), ValidationError> {
+    if let (Some(age), Some(birth_date)) = (age, birth_date) {
+        let expected = age_on(birth_date, Local::now().date_naive());
+        if age != expected {
+            return Err(invalid(
+                "age_mismatch",
+                format!("age {} does not match birth_date {} (expected {})", age, birth_date, expected),
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// `min_age` não pode passar de `max_age` quando os dois vêm juntos
+pub fn age_range(min_age: Option<i32>, max_age: Option<i32>) -> Result<(), ValidationError> {
+    if let (Some(min_age), Some(max_age)) = (min_age, max_age) {
+        if min_age > max_age {
+            return Err(invalid(
+                "age_range",
+                format!("min_age {} is greater than max_age {}", min_age, max_age),
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Início antes do fim, quando os dois vêm
+pub fn time_range(start: Option<NaiveTime>, end: Option<NaiveTime>) -> Result<(), ValidationError> {
+    if let (Some(start), Some(end)) = (start, end) {
+        if start >= end {
+            return Err(invalid("time_range", format!("start_time {} is not before end_time {}", start, end)));
+        }
+    }
+    Ok(())
+}
+
+/// `from` no máximo igual a `until`, quando os dois vêm
+pub fn date_range(from: Option<NaiveDate>, until: Option<NaiveDate>) -> Result<(), ValidationError> {
+    if let (Some(from), Some(until)) = (from, until) {
+        if from > until {
+            return Err(invalid("date_range", format!("valid_from {} is after valid_until {}", from, until)));
+        }
+    }
+    Ok(())
+}
+
+/// Idade que quem nasceu em `birth_date` completa no ano `season` (categorias sub-N)
+pub fn season_age(birth_date: NaiveDate, season: i32) -> Option<i32> {
+    NaiveDate::from_ymd_opt(season, 12, 31).map(|end| age_on(birth_date, end))
+}
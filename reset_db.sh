@@ -8,6 +8,6 @@ docker exec -it db psql -U retesp -d postgres -c 'CREATE DATABASE "4linhas_db";'
 
 echo "🚀 Aplicando migrações..."
 cd backend
-sqlx migrate run
+cargo run -- migrate
 
 echo "🎉 Banco de dados resetado com sucesso!"
@@ -13,6 +13,6 @@ psql -U retesp -h db -d postgres -c "CREATE DATABASE 4linhas_db;"
 
 echo "🔄 Applying migrations..."
 export DATABASE_URL="postgres://retesp:senha123@db:5432/4linhas_db"
-/app/migrate
+/app/backend migrate
 
 echo "✅ Database ready!"
\ No newline at end of file
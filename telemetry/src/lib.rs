@@ -0,0 +1,93 @@
+// telemetry/src/lib.rs
+//! Logs e traces (`tracing`) compartilhados pelo backend, pelo producer e pelo
+//! transcoder, configurados pela seção `[telemetry]`:
+//!
+//! - `filter`: o mesmo formato do `RUST_LOG` (que continua valendo);
+//! - `format`: `text` no terminal ou `json`, um objeto por linha com os campos
+//!   dos spans abertos (ex.: `request_id`);
+//! - `otlp_endpoint`: quando definido, os spans também vão para um coletor
+//!   OTLP/gRPC (Jaeger, OpenTelemetry Collector...).
+//!
+//! Os registros do crate `log` (actix-web, sqlx, handlers antigos) passam pelo
+//! mesmo subscriber.
+//!
+//! Um upload é seguido de ponta a ponta pelo `request_id`: o backend aceita
+//! o `X-Request-Id` do cliente (ou gera um), e o repassa no cabeçalho AMQP
+//! [`REQUEST_ID_HEADER`] das mensagens lidas pelo transcoder.
+use app_config::{LogFormat, TelemetryConfig};
+use opentelemetry::{trace::TracerProvider as _, KeyValue};
+use opentelemetry_otlp::{SpanExporter, WithExportConfig};
+use opentelemetry_sdk::{runtime, trace::TracerProvider, Resource};
+use std::error::Error;
+use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt, EnvFilter, Layer};
+
+/// Cabeçalho HTTP e AMQP com o identificador da requisição que originou o trabalho
+pub const REQUEST_ID_HEADER: &str = "x-request-id";
+
+/// Novo identificador para requisições e mensagens que chegam sem um
+pub fn new_request_id() -> String {
+    uuid::Uuid::new_v4().to_string()
+}
+
+/// Mantém o exportador OTLP vivo; ao sair de escopo envia os spans pendentes.
+/// Guarde-o em uma variável do `main` até o processo terminar.
+#[must_use = "os spans pendentes só são enviados quando o guard é descartado"]
+pub struct Telemetry {
+    provider: Option<TracerProvider>,
+}
+
+impl Drop for Telemetry {
+    fn drop(&mut self) {
+        if let Some(provider) = self.provider.take() {
+            if let Err(error) = provider.shutdown() {
+                eprintln!("Failed to flush traces: {}", error);
+            }
+        }
+    }
+}
+
+/// Instala o subscriber global. `service_name` identifica o binário no coletor
+/// (`service.name`). Com OTLP, precisa ser chamado dentro de um runtime tokio.
+pub fn init(service_name: &'static str, config: &TelemetryConfig) -> Result<Telemetry, Box<dyn Error>> {
+    let filter = EnvFilter::try_new(&config.filter)
+        .map_err(|error| format!("invalid telemetry.filter {:?}: {}", config.filter, error))?;
+
+    let output = match config.format {
+        LogFormat::Text => tracing_subscriber::fmt::layer().boxed(),
+        LogFormat::Json => tracing_subscriber::fmt::layer()
+            .json()
+            .flatten_event(true)
+            .with_current_span(false)
+            .with_span_list(true)
+            .boxed(),
+    };
+
+    let provider = match &config.otlp_endpoint {
+        Some(endpoint) => {
+            let exporter = SpanExporter::builder()
+                .with_tonic()
+                .with_endpoint(endpoint)
+                .build()?;
+            let provider = TracerProvider::builder()
+                .with_batch_exporter(exporter, runtime::Tokio)
+                .with_resource(Resource::new([KeyValue::new("service.name", service_name)]))
+                .build();
+            Some(provider)
+        }
+        None => None,
+    };
+    let otlp = provider
+        .as_ref()
+        .map(|provider| tracing_opentelemetry::layer().with_tracer(provider.tracer(service_name)));
+
+    tracing_subscriber::registry()
+        .with(filter)
+        .with(output)
+        .with(otlp)
+        .try_init()?;
+
+    if let Some(endpoint) = &config.otlp_endpoint {
+        tracing::info!(endpoint = %endpoint, "Exporting traces over OTLP");
+    }
+    Ok(Telemetry { provider })
+}
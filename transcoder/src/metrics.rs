@@ -0,0 +1,85 @@
+// transcoder/src/metrics.rs
+//! Métricas Prometheus do transcoder, servidas em `GET /metrics` no endereço
+//! `transcoder.metrics_bind`:
+//!
+//! - `transcode_jobs_total{result="processed"|"requeued"|"failed"}`
+//! - `transcode_job_duration_seconds`
+//! - `transcode_jobs_in_flight`
+use prometheus::{Encoder, Histogram, HistogramOpts, IntCounterVec, IntGauge, Opts, Registry, TextEncoder};
+use std::error::Error;
+use std::sync::OnceLock;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+
+pub struct Metrics {
+    registry: Registry,
+    pub jobs: IntCounterVec,
+    pub job_duration: Histogram,
+    pub in_flight: IntGauge,
+}
+
+impl Metrics {
+    fn new() -> Self {
+        let registry = Registry::new();
+
+        let jobs = IntCounterVec::new(
+            Opts::new("transcode_jobs_total", "Transcode jobs by result"),
+            &["result"],
+        )
+        .expect("valid metric");
+        // Transcodificações levam de segundos a vários minutos
+        let job_duration = Histogram::with_opts(
+            HistogramOpts::new("transcode_job_duration_seconds", "Time spent running ffmpeg per job")
+                .buckets(vec![1.0, 5.0, 15.0, 30.0, 60.0, 120.0, 300.0, 600.0, 1800.0]),
+        )
+        .expect("valid metric");
+        let in_flight = IntGauge::new("transcode_jobs_in_flight", "Jobs currently being transcoded").expect("valid metric");
+
+        registry.register(Box::new(jobs.clone())).expect("unique metric");
+        registry.register(Box::new(job_duration.clone())).expect("unique metric");
+        registry.register(Box::new(in_flight.clone())).expect("unique metric");
+
+        Metrics { registry, jobs, job_duration, in_flight }
+    }
+
+    fn render(&self) -> (String, Vec<u8>) {
+        let encoder = TextEncoder::new();
+        let mut buffer = Vec::new();
+        if let Err(e) = encoder.encode(&self.registry.gather(), &mut buffer) {
+            tracing::error!("Erro ao gerar métricas: {}", e);
+        }
+        (encoder.format_type().to_string(), buffer)
+    }
+}
+
+/// Métricas do processo, criadas no primeiro uso
+pub fn metrics() -> &'static Metrics {
+    static METRICS: OnceLock<Metrics> = OnceLock::new();
+    METRICS.get_or_init(Metrics::new)
+}
+
+/// Servidor HTTP mínimo: responde as métricas para qualquer requisição
+pub async fn serve(bind: &str) -> Result<(), Box<dyn Error>> {
+    let listener = TcpListener::bind(bind).await?;
+    tracing::info!("Métricas disponíveis em http://{}/metrics", bind);
+
+    loop {
+        let (mut socket, _) = listener.accept().await?;
+        tokio::spawn(async move {
+            // Só o início da requisição interessa; o caminho não é verificado
+            let mut request = [0u8; 1024];
+            if socket.read(&mut request).await.is_err() {
+                return;
+            }
+
+            let (content_type, body) = metrics().render();
+            let header = format!(
+                "HTTP/1.1 200 OK\r\nContent-Type: {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+                content_type,
+                body.len()
+            );
+            let _ = socket.write_all(header.as_bytes()).await;
+            let _ = socket.write_all(&body).await;
+        });
+    }
+}